@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+
+use crate::parse::{Line, ParseError};
+use crate::solution::Answer;
+use crate::toml::{parse_line, TomlLine, TomlValue};
+
+/// The known answers for one puzzle input. Parts without an answer are skipped when checking.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub struct ExpectedAnswers {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl ExpectedAnswers {
+    pub fn get(&self, part: u32) -> Option<&Answer> {
+        return match part {
+            1 => self.part1.as_ref(),
+            2 => self.part2.as_ref(),
+            _ => None,
+        };
+    }
+}
+
+/// Parse an `answers.toml` file, which has a section per input file name without `.txt`:
+///
+/// ```toml
+/// [input]
+/// part1 = 24000
+/// part2 = "MCD"
+/// ```
+pub fn parse_answers(text: &str) -> Result<HashMap<String, ExpectedAnswers>, ParseError> {
+    let mut answers: HashMap<String, ExpectedAnswers> = HashMap::new();
+    let mut section: Option<String> = None;
+
+    for (index, text) in text.lines().enumerate() {
+        let line = Line::new(index, text);
+        let (key, value, value_start) = match parse_line(&line)? {
+            None => continue,
+            Some(TomlLine::Section(name)) => {
+                answers.entry(name.to_string()).or_default();
+                section = Some(name.to_string());
+                continue;
+            }
+            Some(TomlLine::Entry { key, value, value_start }) => (key, value, value_start),
+        };
+
+        let section = section.as_ref().ok_or_else(|| line.error(0, "a [section] before the answers"))?;
+        let value = match value {
+            TomlValue::String(text) => Answer::Text(text),
+            TomlValue::Bare(number) => {
+                let number = number.replace('_', "").parse::<i128>().map_err(|_| line.error(value_start, "a number or a string"))?;
+                Answer::Number(number)
+            }
+        };
+
+        let expected = answers.get_mut(section).unwrap();
+        match key {
+            "part1" => expected.part1 = Some(value),
+            "part2" => expected.part2 = Some(value),
+            _ => return Err(line.error(0, "'part1' or 'part2'")),
+        }
+    }
+    return Ok(answers);
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{parse_answers, ExpectedAnswers};
+    use crate::parse::ParseError;
+    use crate::solution::Answer;
+
+    #[test]
+    fn test_parse_answers() {
+        let text = "# Answers\n[input]\npart1 = 13_220 # checked\npart2 = \"\\n#.\\n.#\" # CRT\n\n[test]\npart2 = \"a \\\"b\\\"\"\n";
+        let answers = parse_answers(text).unwrap();
+        assert_eq!(answers["input"], ExpectedAnswers {
+            part1: Some(Answer::Number(13220)),
+            part2: Some(Answer::Text("\n#.\n.#".to_string())),
+        });
+        assert_eq!(answers["test"].get(1), None);
+        assert_eq!(answers["test"].get(2), Some(&Answer::Text("a \"b\"".to_string())));
+    }
+
+    #[test]
+    fn test_invalid_answers() {
+        assert_eq!(parse_answers("part1 = 1").unwrap_err(), ParseError::new(1, 1, "a [section] before the answers", "part1"));
+        assert_eq!(parse_answers("[input]\npart3 = 1").unwrap_err(), ParseError::new(2, 1, "'part1' or 'part2'", "part3"));
+        assert_eq!(parse_answers("[input]\npart1 = 1x").unwrap_err(), ParseError::new(2, 9, "a number or a string", "1x"));
+        assert!(parse_answers("[input]\npart1 = \"open").is_err());
+    }
+}
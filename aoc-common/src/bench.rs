@@ -0,0 +1,119 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use crate::parse::ParseError;
+use crate::solution::Solution;
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct BenchOptions {
+    /// Runs before measuring starts, to warm up caches and the allocator
+    pub warmup: u32,
+    pub iterations: u32,
+}
+
+impl Default for BenchOptions {
+    fn default() -> BenchOptions {
+        return BenchOptions { warmup: 1, iterations: 10 };
+    }
+}
+
+/// Summary of the measured run times, in microseconds.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct Stats {
+    pub mean: f64,
+    pub median: f64,
+    pub std_dev: f64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Stats {
+        let mut micros = samples.iter().map(|sample| sample.as_secs_f64() * 1_000_000.0).collect::<Vec<f64>>();
+        micros.sort_by(|first, second| first.total_cmp(second));
+        if micros.is_empty() {
+            return Stats { mean: 0.0, median: 0.0, std_dev: 0.0 };
+        }
+
+        let count = micros.len();
+        let mean = micros.iter().sum::<f64>() / count as f64;
+        let median = if count.is_multiple_of(2) {
+            (micros[count / 2 - 1] + micros[count / 2]) / 2.0
+        } else {
+            micros[count / 2]
+        };
+        // Sample standard deviation, which is 0 for a single run
+        let std_dev = if count > 1 {
+            let squared_error = micros.iter().map(|sample| (sample - mean).powi(2)).sum::<f64>();
+            (squared_error / (count - 1) as f64).sqrt()
+        } else {
+            0.0
+        };
+        return Stats { mean, median, std_dev };
+    }
+}
+
+/// The stats of one phase of a solution: "parse", "part1" or "part2".
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Measurement {
+    pub phase: &'static str,
+    pub stats: Stats,
+}
+
+/// Time `run` for the configured number of iterations, after the warmup runs.
+pub fn measure<T>(options: &BenchOptions, mut run: impl FnMut() -> T) -> Stats {
+    for _ in 0..options.warmup {
+        black_box(run());
+    }
+    let mut samples = Vec::new();
+    for _ in 0..options.iterations {
+        let now = Instant::now();
+        black_box(run());
+        samples.push(now.elapsed());
+    }
+    return Stats::from_samples(&samples);
+}
+
+/// Benchmark parsing and solving separately. Both parts are solved from the same parsed input.
+pub fn benchmark<S: Solution>(text: &str, part: Option<u32>, options: &BenchOptions, config: &S::Config) -> Result<Vec<Measurement>, ParseError> {
+    let data = S::parse(text)?;
+
+    let mut measurements = vec![Measurement { phase: "parse", stats: measure(options, || S::parse(black_box(text))) }];
+    if part != Some(2) {
+        measurements.push(Measurement { phase: "part1", stats: measure(options, || S::part1(&data, config)) });
+    }
+    if part != Some(1) {
+        measurements.push(Measurement { phase: "part2", stats: measure(options, || S::part2(&data, config)) });
+    }
+    return Ok(measurements);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::bench::{measure, BenchOptions, Stats};
+
+    #[test]
+    fn test_stats() {
+        let samples = [4, 1, 3, 2].map(Duration::from_micros);
+        let stats = Stats::from_samples(&samples);
+        assert!((stats.mean - 2.5).abs() < 1e-9);
+        assert!((stats.median - 2.5).abs() < 1e-9);
+        assert!((stats.std_dev - (5.0f64 / 3.0).sqrt()).abs() < 1e-9);
+
+        let single = Stats::from_samples(&[Duration::from_micros(7)]);
+        assert_eq!(single, Stats { mean: 7.0, median: 7.0, std_dev: 0.0 });
+    }
+
+    #[test]
+    fn test_measure() {
+        let mut run_count = 0;
+        measure(&BenchOptions { warmup: 2, iterations: 5 }, || run_count += 1);
+        assert_eq!(run_count, 7);
+    }
+}
@@ -0,0 +1,125 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::exit;
+
+use crate::error::Error;
+use crate::input::{self, InputSource};
+use crate::params::{ParamArgs, Params};
+use crate::report;
+use crate::solution::{load, Answer, Solution};
+
+/// Find the value following a command line flag, e.g. "2" in "--part 2".
+pub fn get_arg(name: &str) -> Option<String> {
+    let args = env::args().collect::<Vec<String>>();
+    let position = args.iter().position(|arg| arg == name)?;
+    return args.get(position + 1).cloned();
+}
+
+/// Find the values following every occurrence of a repeatable flag, e.g. "--param".
+pub fn get_args(name: &str) -> Vec<String> {
+    let args = env::args().collect::<Vec<String>>();
+    return args.windows(2).filter(|pair| pair[0] == name).map(|pair| pair[1].to_owned()).collect();
+}
+
+fn print_part(part: u32, solve: impl FnOnce() -> Answer) {
+    println!("---Task {}---", part);
+    println!("Solution: {}", solve());
+}
+
+/// Load the input of a solution and print the answers to the requested part, or both parts.
+pub fn solve<S: Solution>(source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<(), Error> {
+    let config = params.resolve::<S::Config>()?;
+    let data = load::<S>(source)?;
+
+    if part != Some(2) {
+        print_part(1, || S::part1(&data, &config).into());
+    }
+    if part.is_none() {
+        println!();
+    }
+    if part != Some(1) {
+        print_part(2, || S::part2(&data, &config).into());
+    }
+    return Ok(());
+}
+
+/// Print a line of JSON for the requested part, or both parts, see `PartReport::to_json`.
+pub fn print_json<S: Solution>(source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<(), Error> {
+    let config = params.resolve::<S::Config>()?;
+    let text = input::read_to_string(source)?;
+    let reports = report::report::<S>(&text, part, &config).map_err(|error| Error::Parse(source.clone(), error))?;
+    for report in reports {
+        println!("{}", report.to_json());
+    }
+    return Ok(());
+}
+
+/// Print the parameters of a solution with the values they would have in this run.
+pub fn print_params<S: Solution>(params: &ParamArgs) -> Result<(), Error> {
+    let config = params.resolve::<S::Config>()?;
+    let infos = config.describe();
+    if infos.is_empty() {
+        println!("Day {} has no parameters", S::DAY);
+    }
+    for info in infos {
+        println!("{}", info);
+    }
+    return Ok(());
+}
+
+/// The input given with `--input <FILE>`, or `default_input`.
+pub fn input_source(default_input: InputSource) -> InputSource {
+    return match get_arg("--input") {
+        Some(input_file) => InputSource::from_arg(&input_file),
+        None => default_input,
+    };
+}
+
+/// The parameters given with `--param <NAME=VALUE>` and `--config <FILE>`, which defaults to `default_config`
+/// if that exists. Exits if a parameter is not `NAME=VALUE`.
+pub fn param_args(default_config: PathBuf) -> ParamArgs {
+    let mut params = ParamArgs {
+        config_files: get_arg("--config").map(PathBuf::from).or(Some(default_config).filter(|path| path.exists()))
+            .into_iter().collect(),
+        overrides: Vec::new(),
+    };
+    for arg in get_args("--param") {
+        if let Err(error) = params.add_override(&arg) {
+            eprintln!("{}", error);
+            exit(2);
+        }
+    }
+    return params;
+}
+
+/// Entry point for the binary of a day, accepting `--part <PART>`, `--input <FILE>`, `--format <text|json>`,
+/// `--param <NAME=VALUE>` (repeatable), `--config <FILE>` and `--params` to list the parameters.
+/// The config file defaults to `default_config` if that exists, see `day_config!`.
+pub fn run<S: Solution>(default_input: InputSource, default_config: PathBuf) {
+    let part = match get_arg("--part").as_deref() {
+        None => None,
+        Some("1") => Some(1),
+        Some("2") => Some(2),
+        Some(part) => {
+            eprintln!("--part must be 1 or 2, got '{}'", part);
+            exit(2);
+        }
+    };
+    let source = input_source(default_input);
+    let params = param_args(default_config);
+
+    let list_params = env::args().any(|arg| arg == "--params");
+    let result = match get_arg("--format").as_deref() {
+        _ if list_params => print_params::<S>(&params),
+        None | Some("text") => solve::<S>(&source, part, &params),
+        Some("json") => print_json::<S>(&source, part, &params),
+        Some(format) => {
+            eprintln!("--format must be text or json, got '{}'", format);
+            exit(2);
+        }
+    };
+    if let Err(error) = result {
+        eprintln!("{}", error);
+        exit(1);
+    }
+}
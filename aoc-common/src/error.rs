@@ -0,0 +1,48 @@
+use std::any::Any;
+use std::fmt;
+
+use crate::input::{InputError, InputSource};
+use crate::params::ParamError;
+use crate::parse::ParseError;
+
+#[derive(Debug)]
+pub enum Error {
+    Input(InputError),
+    Parse(InputSource, ParseError),
+    Param(ParamError),
+}
+
+impl From<InputError> for Error {
+    fn from(error: InputError) -> Error {
+        return Error::Input(error);
+    }
+}
+
+impl From<ParamError> for Error {
+    fn from(error: ParamError) -> Error {
+        return Error::Param(error);
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Input(error) => write!(f, "{}", error),
+            Error::Parse(source, error) => write!(f, "Invalid input in {}: {}", source, error),
+            Error::Param(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The message a panic was raised with, from the payload `catch_unwind` returns.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.to_owned();
+    }
+    return "unknown panic".to_string();
+}
@@ -0,0 +1,250 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+use crate::error::{panic_message, Error};
+use crate::input::{self, InputSource};
+use crate::params::{ParamArgs, Params};
+use crate::solution::{Answer, Solution};
+
+/// A command of a day in the REPL, e.g. `size <PATH>`, with what it does for `help`.
+pub struct Command {
+    pub usage: &'static str,
+    pub description: &'static str,
+}
+
+impl Command {
+    /// The name the command is called with, the first word of its usage.
+    pub fn name(&self) -> &'static str {
+        return self.usage.split_whitespace().next().unwrap_or("");
+    }
+}
+
+/// A solution whose parsed input can be explored in the REPL, beyond solving the parts.
+pub trait Explore: Solution {
+    /// The parsed input, for the `show` command.
+    fn show(input: &Self::Input) -> String;
+    /// The day's own commands, such as querying an entity of the input or stepping a simulation.
+    fn commands() -> Vec<Command>;
+    /// Run one of the day's own commands with its arguments. Commands that simulate may change the input.
+    fn run(input: &mut Self::Input, config: &Self::Config, name: &str, args: &[&str]) -> Result<String, String>;
+}
+
+/// A parsed input and its parameters, changed and queried one command line at a time.
+pub trait Session {
+    /// Run a command line and return what to print, or why the command failed.
+    fn execute(&mut self, line: &str) -> Result<String, String>;
+}
+
+const COMMANDS: [Command; 6] = [
+    Command { usage: "help", description: "List the commands" },
+    Command { usage: "show", description: "Print the parsed input" },
+    Command { usage: "part <PART> [NAME=VALUE]...", description: "Solve part 1 or 2 of the input, with parameters overridden for this run only" },
+    Command { usage: "params", description: "List the parameters with their values" },
+    Command { usage: "set <NAME=VALUE>...", description: "Override parameters for the rest of the session" },
+    Command { usage: "reset", description: "Parse the input again, undoing the commands that changed it" },
+];
+
+type RunFn<S> = fn(&mut <S as Solution>::Input, &<S as Solution>::Config, &str, &[&str]) -> Result<String, String>;
+
+struct ExploreFns<S: Solution> {
+    show: fn(&S::Input) -> String,
+    commands: fn() -> Vec<Command>,
+    run: RunFn<S>,
+}
+
+struct SolutionSession<S: Solution> {
+    text: String,
+    input: S::Input,
+    params: ParamArgs,
+    explore: Option<ExploreFns<S>>,
+}
+
+impl<S: Solution> SolutionSession<S> {
+    fn new(source: &InputSource, params: &ParamArgs, explore: Option<ExploreFns<S>>) -> Result<SolutionSession<S>, Error> {
+        params.resolve::<S::Config>()?;
+        let text = input::read_to_string(source)?;
+        let input = S::parse(&text).map_err(|error| Error::Parse(source.clone(), error))?;
+        return Ok(SolutionSession { text, input, params: params.clone(), explore });
+    }
+
+    fn with_overrides(&self, overrides: &[&str]) -> Result<ParamArgs, String> {
+        let mut params = self.params.clone();
+        for name_value in overrides {
+            params.add_override(name_value).map_err(|error| error.to_string())?;
+        }
+        params.resolve::<S::Config>().map_err(|error| error.to_string())?;
+        return Ok(params);
+    }
+
+    fn config(&self) -> Result<S::Config, String> {
+        return self.params.resolve::<S::Config>().map_err(|error| error.to_string());
+    }
+
+    fn help(&self) -> String {
+        let mut commands = COMMANDS.into_iter().collect::<Vec<Command>>();
+        match &self.explore {
+            Some(explore) => commands.extend((explore.commands)()),
+            None => commands.retain(|command| command.name() != "show"),
+        }
+        let width = commands.iter().map(|command| command.usage.len()).max().unwrap_or(0);
+        let mut lines = commands.iter()
+            .map(|command| format!("{:width$}  {}", command.usage, command.description, width = width))
+            .collect::<Vec<String>>();
+        lines.push(format!("{:width$}  Leave the REPL", "quit", width = width));
+        return lines.join("\n");
+    }
+
+    fn solve(&self, args: &[&str]) -> Result<String, String> {
+        let part = match args.first() {
+            Some(&"1") => 1,
+            Some(&"2") => 2,
+            _ => return Err("Usage: part <PART> [NAME=VALUE]..., where PART is 1 or 2".to_string()),
+        };
+        let config = self.with_overrides(&args[1..])?.resolve::<S::Config>().map_err(|error| error.to_string())?;
+        let start = Instant::now();
+        let answer = panic::catch_unwind(AssertUnwindSafe(|| -> Answer {
+            return if part == 1 { S::part1(&self.input, &config).into() } else { S::part2(&self.input, &config).into() };
+        }));
+        let elapsed = start.elapsed();
+        return match answer {
+            Ok(answer) => Ok(format!("{} ({:.3} ms)", answer, elapsed.as_secs_f64() * 1000.0)),
+            Err(payload) => Err(format!("Part {} panicked: {}", part, panic_message(payload.as_ref()))),
+        };
+    }
+}
+
+impl<S: Solution> Session for SolutionSession<S> {
+    fn execute(&mut self, line: &str) -> Result<String, String> {
+        let words = line.split_whitespace().collect::<Vec<&str>>();
+        let Some((&name, args)) = words.split_first() else {
+            return Ok(String::new());
+        };
+        match name {
+            "help" => return Ok(self.help()),
+            "show" => {
+                let explore = self.explore.as_ref().ok_or(format!("Day {} cannot show its parsed input", S::DAY))?;
+                return Ok((explore.show)(&self.input));
+            }
+            "part" => return self.solve(args),
+            "params" => {
+                let infos = self.config()?.describe();
+                if infos.is_empty() {
+                    return Ok(format!("Day {} has no parameters", S::DAY));
+                }
+                return Ok(infos.iter().map(|info| info.to_string()).collect::<Vec<String>>().join("\n"));
+            }
+            "set" => {
+                if args.is_empty() {
+                    return Err("Usage: set <NAME=VALUE>...".to_string());
+                }
+                self.params = self.with_overrides(args)?;
+                return Ok(String::new());
+            }
+            "reset" => {
+                self.input = S::parse(&self.text).expect("The input was parsed before");
+                return Ok(String::new());
+            }
+            _ => (),
+        }
+
+        if let Some(explore) = &self.explore {
+            if (explore.commands)().iter().any(|command| command.name() == name) {
+                let config = self.config()?;
+                return (explore.run)(&mut self.input, &config, name, args);
+            }
+        }
+        return Err(format!("Unknown command '{}', see help", name));
+    }
+}
+
+/// Parse the input in `source` for a session that solves the parts with changed parameters.
+pub fn session<S: Solution + 'static>(source: &InputSource, params: &ParamArgs) -> Result<Box<dyn Session>, Error> {
+    return Ok(Box::new(SolutionSession::<S>::new(source, params, None)?));
+}
+
+/// Parse the input in `source` for a session that also has the `show` command and the day's own commands.
+pub fn explore_session<S: Explore + 'static>(source: &InputSource, params: &ParamArgs) -> Result<Box<dyn Session>, Error> {
+    let explore = ExploreFns { show: S::show, commands: S::commands, run: S::run };
+    return Ok(Box::new(SolutionSession::<S>::new(source, params, Some(explore))?));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::explore::{explore_session, Command, Explore};
+    use crate::input::InputSource;
+    use crate::params::ParamArgs;
+    use crate::parse::ParseError;
+    use crate::solution::Solution;
+
+    crate::params! {
+        struct Config {
+            factor: i64 = 1,
+        }
+    }
+
+    struct Counter;
+
+    impl Solution for Counter {
+        const DAY: u32 = 0;
+        type Input = Vec<i64>;
+        type Config = Config;
+        type Output1 = i64;
+        type Output2 = i64;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            return Ok(input.lines().map(|line| line.parse().unwrap()).collect());
+        }
+
+        fn part1(numbers: &Vec<i64>, config: &Config) -> i64 {
+            return numbers.iter().sum::<i64>() * config.factor;
+        }
+
+        fn part2(numbers: &Vec<i64>, _: &Config) -> i64 {
+            return numbers.len() as i64;
+        }
+    }
+
+    impl Explore for Counter {
+        fn show(numbers: &Vec<i64>) -> String {
+            return format!("{:?}", numbers);
+        }
+
+        fn commands() -> Vec<Command> {
+            return vec![Command { usage: "push <NUMBER>", description: "Add a number" }];
+        }
+
+        fn run(numbers: &mut Vec<i64>, _: &Config, _: &str, args: &[&str]) -> Result<String, String> {
+            let number = args.first().and_then(|number| number.parse().ok()).ok_or("Usage: push <NUMBER>")?;
+            numbers.push(number);
+            return Ok(String::new());
+        }
+    }
+
+    fn answer(output: Result<String, String>) -> String {
+        return output.unwrap().split(" (").next().unwrap().to_string();
+    }
+
+    #[test]
+    fn test_session() {
+        let source = InputSource::Text("1\n2\n".to_string());
+        let mut session = explore_session::<Counter>(&source, &ParamArgs::default()).unwrap();
+        assert_eq!(answer(session.execute("part 1 factor=10")), "30");
+        assert_eq!(answer(session.execute("part 1")), "3");
+        assert_eq!(session.execute("set factor=2"), Ok(String::new()));
+        assert_eq!(session.execute("params"), Ok("factor: i64 = 2".to_string()));
+        assert_eq!(session.execute("push 4"), Ok(String::new()));
+        assert_eq!(session.execute("show"), Ok("[1, 2, 4]".to_string()));
+        assert_eq!(answer(session.execute("part 2")), "3");
+        assert_eq!(session.execute("reset"), Ok(String::new()));
+        assert_eq!(answer(session.execute("part 1")), "6");
+        assert!(session.execute("help").unwrap().contains("push <NUMBER>"));
+
+        assert_eq!(session.execute("set factor=x"), Err("Parameter factor must be a i64, got 'x'".to_string()));
+        assert_eq!(session.execute("part 3").unwrap_err(), "Usage: part <PART> [NAME=VALUE]..., where PART is 1 or 2");
+        assert_eq!(session.execute("pop"), Err("Unknown command 'pop', see help".to_string()));
+
+        let mut session = crate::explore::session::<Counter>(&source, &ParamArgs::default()).unwrap();
+        assert_eq!(session.execute("show"), Err("Day 0 cannot show its parsed input".to_string()));
+        assert!(!session.execute("help").unwrap().contains("show"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::ops::{Bound, RangeBounds, RangeInclusive};
+
+use crate::params::Params;
+use crate::solution::{Answer, Solution};
+
+/// A small seeded random number generator (SplitMix64), so that a generated input can be
+/// reproduced from its seed on any platform.
+#[derive(Debug)]
+#[derive(Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+/// An integer type that `Rng::range` can pick numbers of.
+pub trait SampleNumber: Copy {
+    fn to_i128(self) -> i128;
+    fn from_i128(number: i128) -> Self;
+}
+
+macro_rules! impl_sample_number {
+    ($($number_type:ty),*) => {
+        $(
+            impl SampleNumber for $number_type {
+                fn to_i128(self) -> i128 {
+                    return self as i128;
+                }
+
+                fn from_i128(number: i128) -> $number_type {
+                    return number as $number_type;
+                }
+            }
+        )*
+    };
+}
+
+impl_sample_number!(i32, i64, u32, u64, usize);
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut value = self.state;
+        value = (value ^ (value >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        value = (value ^ (value >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return value ^ (value >> 31);
+    }
+
+    /// A random number in `range`, e.g. `rng.range(1..=6)`. The range must not be empty.
+    pub fn range<T: SampleNumber, R: RangeBounds<T>>(&mut self, range: R) -> T {
+        let start = match range.start_bound() {
+            Bound::Included(start) => start.to_i128(),
+            Bound::Excluded(start) => start.to_i128() + 1,
+            Bound::Unbounded => panic!("A range to pick a number from needs a start"),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.to_i128(),
+            Bound::Excluded(end) => end.to_i128() - 1,
+            Bound::Unbounded => panic!("A range to pick a number from needs an end"),
+        };
+        assert!(start <= end, "Cannot pick a number from the empty range {}..={}", start, end);
+        let span = (end - start + 1) as u128;
+        return T::from_i128(start + (self.next_u64() as u128 % span) as i128);
+    }
+
+    /// True with the given probability between 0 and 1.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        return ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability;
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.range(0..items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.range(0..=index));
+        }
+    }
+
+    /// A random lowercase word with a length in `length`.
+    pub fn word(&mut self, length: RangeInclusive<usize>) -> String {
+        let length = self.range(length);
+        return (0..length).map(|_| (b'a' + self.range(0..26u32) as u8) as char).collect();
+    }
+}
+
+/// Random puzzle inputs for stress testing a solution.
+pub trait Generator: Solution {
+    /// A random valid input of about `size` items, such as lines, monkeys or valves, for which both
+    /// parts have an answer. Returns the config to solve it with, e.g. a smaller search area.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Self::Config);
+}
+
+/// A generated input, with the parameters that differ from the defaults as `name=value` overrides.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct GeneratedInput {
+    pub text: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl GeneratedInput {
+    pub fn new<P: Params>(text: String, config: &P) -> GeneratedInput {
+        let defaults = P::default().describe();
+        let params = config.describe().into_iter()
+            .filter(|info| !defaults.contains(info))
+            .map(|info| (info.name.to_string(), info.value))
+            .collect();
+        return GeneratedInput { text, params };
+    }
+
+    /// The config to solve the input with.
+    pub fn config<P: Params>(&self) -> P {
+        let mut config = P::default();
+        for (name, value) in &self.params {
+            config.set(name, value).expect("Generated parameters are valid");
+        }
+        config.validate().expect("Generated parameters are valid");
+        return config;
+    }
+}
+
+/// Generate the input for `seed` and `size`. The same arguments always give the same input.
+pub fn generate<S: Generator>(seed: u64, size: usize) -> GeneratedInput {
+    let (text, config) = S::generate(&mut Rng::new(seed), size);
+    return GeneratedInput::new(text, &config);
+}
+
+/// Generate an input and solve both parts of it, to test a generator. Panics if the input cannot be parsed.
+pub fn solve_generated<S: Generator>(seed: u64, size: usize) -> (Answer, Answer) {
+    let input = generate::<S>(seed, size);
+    let config = input.config::<S::Config>();
+    let parsed = S::parse(&input.text)
+        .unwrap_or_else(|error| panic!("The input generated for seed {} and size {} is invalid: {}", seed, size, error));
+    return (S::part1(&parsed, &config).into(), S::part2(&parsed, &config).into());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::Rng;
+
+    #[test]
+    fn test_rng() {
+        let mut rng = Rng::new(7);
+        let numbers = (0..1000).map(|_| rng.range(-3..=3)).collect::<Vec<i32>>();
+        assert!((-3..=3).all(|number| numbers.contains(&number)));
+        assert!(numbers.iter().all(|number| (-3..=3).contains(number)));
+        assert_eq!(Rng::new(7).next_u64(), Rng::new(7).next_u64());
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+        assert_eq!(Rng::new(0).range(5..6u64), 5);
+
+        let mut items = (0..10).collect::<Vec<u32>>();
+        rng.shuffle(&mut items);
+        items.sort();
+        assert_eq!(items, (0..10).collect::<Vec<u32>>());
+        assert!(rng.word(2..=4).chars().all(|char| char.is_ascii_lowercase()));
+    }
+}
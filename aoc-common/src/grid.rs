@@ -0,0 +1,218 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{Line, ParseError};
+
+/// The offsets of the neighbours above, to the right, below and to the left.
+pub const DIRECTIONS_4: [(i64, i64); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+/// The offsets of all eight neighbours, clockwise from the one above.
+pub const DIRECTIONS_8: [(i64, i64); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// A rectangle of cells stored row by row. Positions are `(x, y)` with x to the right and y downwards,
+/// starting at `(0, 0)` in the top left corner.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Grid<T> where T: Clone {
+        return Grid { width, height, cells: vec![value; width * height] };
+    }
+
+    /// A grid from its rows, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Grid<T> {
+        let width = rows.first().map_or(0, |row| row.len());
+        let height = rows.len();
+        assert!(rows.iter().all(|row| row.len() == width), "Every row of a grid must have the same length");
+        return Grid { width, height, cells: rows.into_iter().flatten().collect() };
+    }
+
+    /// Parse a character map with a cell per character, e.g. a heightmap. `parse_cell` returns `None` for
+    /// characters that are not a cell, which are reported as `expected_cell`. Rows of another length than
+    /// the first are reported as "a row of N `cells`".
+    pub fn parse<F: Fn(char) -> Option<T>>(lines: &Vec<String>, cells: &str, expected_cell: &str, parse_cell: F) -> Result<Grid<T>, ParseError> {
+        let mut rows: Vec<Vec<T>> = Vec::new();
+        for (row, text) in lines.iter().enumerate() {
+            let line = Line::new(row, text);
+            let mut cells_in_row = Vec::new();
+            for (position, char) in text.char_indices() {
+                let cell = parse_cell(char)
+                    .ok_or_else(|| line.error_span(position, position + char.len_utf8(), expected_cell))?;
+                cells_in_row.push(cell);
+            }
+            let width = rows.first().map_or(cells_in_row.len(), |first_row| first_row.len());
+            if cells_in_row.is_empty() || cells_in_row.len() != width {
+                return Err(line.error(0, &format!("a row of {} {}", width.max(1), cells)));
+            }
+            rows.push(cells_in_row);
+        }
+        if rows.is_empty() {
+            return Err(ParseError::new(1, 1, &format!("a row of {}", cells), ""));
+        }
+        return Ok(Grid::from_rows(rows));
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    /// Add a row at the bottom, e.g. to grow a grid as a simulation goes on.
+    pub fn push_row(&mut self, row: Vec<T>) {
+        assert_eq!(row.len(), self.width, "A row of a grid {} wide must have {} cells", self.width, self.width);
+        self.cells.extend(row);
+        self.height += 1;
+    }
+
+    pub fn contains(&self, position: (usize, usize)) -> bool {
+        return position.0 < self.width && position.1 < self.height;
+    }
+
+    /// The cell at `position`, or `None` outside the grid.
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        return if self.contains(position) { Some(&self.cells[position.1 * self.width + position.0]) } else { None };
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        return if self.contains(position) { Some(&mut self.cells[position.1 * self.width + position.0]) } else { None };
+    }
+
+    /// The position one step in `direction` from `position`, or `None` if that is outside the grid.
+    pub fn step(&self, position: (usize, usize), direction: (i64, i64)) -> Option<(usize, usize)> {
+        let x = usize::try_from(position.0 as i64 + direction.0).ok()?;
+        let y = usize::try_from(position.1 as i64 + direction.1).ok()?;
+        return if self.contains((x, y)) { Some((x, y)) } else { None };
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        return (0..self.width * self.height).map(move |index| (index % width, index / width));
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        return self.positions().zip(self.cells.iter());
+    }
+
+    /// The first position, row by row, whose cell matches `predicate`.
+    pub fn find<P: Fn(&T) -> bool>(&self, predicate: P) -> Option<(usize, usize)> {
+        return self.iter().find(|(_, cell)| predicate(cell)).map(|(position, _)| position);
+    }
+
+    /// The neighbours above, to the right, below and to the left that are inside the grid.
+    pub fn neighbours_4(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return DIRECTIONS_4.iter().filter_map(move |direction| self.step(position, *direction));
+    }
+
+    /// The neighbours including the diagonal ones that are inside the grid.
+    pub fn neighbours_8(&self, position: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        return DIRECTIONS_8.iter().filter_map(move |direction| self.step(position, *direction));
+    }
+
+    /// The cells of row `y` from left to right.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        return self.cells[y * self.width..(y + 1) * self.width].iter();
+    }
+
+    /// The cells of column `x` from top to bottom.
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        return self.cells.iter().skip(x).step_by(self.width.max(1)).take(self.height);
+    }
+
+    /// The positions from `position` in `direction` up to the edge of the grid, without `position` itself.
+    pub fn ray(&self, position: (usize, usize), direction: (i64, i64)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut current = position;
+        return std::iter::from_fn(move || {
+            current = self.step(current, direction)?;
+            return Some(current);
+        });
+    }
+
+    /// Draw the grid with a character per cell and a line per row.
+    pub fn render<F: Fn(&T) -> char>(&self, draw_cell: F) -> String {
+        let mut text = String::with_capacity((self.width + 1) * self.height);
+        for y in 0..self.height {
+            text.extend(self.row(y).map(&draw_cell));
+            text.push('\n');
+        }
+        return text;
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        let (width, height) = (self.width, self.height);
+        return self.get(position)
+            .unwrap_or_else(|| panic!("Position {:?} is outside the grid of {}x{}", position, width, height));
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        return self.get_mut(position)
+            .unwrap_or_else(|| panic!("Position {:?} is outside the grid of {}x{}", position, width, height));
+    }
+}
+
+/// Every cell as it is displayed, without separators, and a line per row.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::Grid;
+    use crate::input::lines;
+    use crate::parse::ParseError;
+
+    #[test]
+    fn test_parse() {
+        let grid = Grid::parse(&lines("123\n456\n"), "digits", "a digit", |char| char.to_digit(10)).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456\n");
+        assert_eq!(grid.render(|digit| if digit % 2 == 0 { '#' } else { '.' }), ".#.\n#.#\n");
+
+        let error = Grid::parse(&lines("123\n4x6\n"), "digits", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 2, "a digit", "x"));
+        let error = Grid::parse(&lines("123\n45\n"), "digits", "a digit", |char| char.to_digit(10)).unwrap_err();
+        assert_eq!(error, ParseError::new(2, 1, "a row of 3 digits", "45"));
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = Grid::from_rows(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+        assert_eq!(grid.neighbours_4((0, 0)).collect::<Vec<(usize, usize)>>(), vec![(1, 0), (0, 1)]);
+        assert_eq!(grid.neighbours_8((1, 1)).map(|position| grid[position]).collect::<Vec<i32>>(), vec![2, 3, 6, 9, 8, 7, 4, 1]);
+        assert_eq!(grid.row(1).copied().collect::<Vec<i32>>(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<i32>>(), vec![3, 6, 9]);
+        assert_eq!(grid.ray((0, 2), (1, -1)).collect::<Vec<(usize, usize)>>(), vec![(1, 1), (2, 0)]);
+        assert_eq!(grid.find(|cell| *cell > 4), Some((1, 1)));
+
+        let mut grid = grid;
+        grid.push_row(vec![0, 0, 0]);
+        grid[(1, 3)] = 10;
+        assert_eq!(grid.to_string(), "123\n456\n789\n0100\n");
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+
+/// Where the puzzle input is read from.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Text(String),
+}
+
+impl InputSource {
+    /// Interpret a command line value, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            return InputSource::Stdin;
+        }
+        return InputSource::File(PathBuf::from(arg));
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+            InputSource::Text(_) => write!(f, "<text>"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: InputSource,
+    pub error: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Could not read {}: {}", self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Path of a file in the `input` directory of a day crate. Absolute paths are kept as they are.
+pub fn day_path(manifest_dir: &str, input_file: &str) -> PathBuf {
+    let root = Path::new(manifest_dir);
+    return root.join("input").join(input_file);
+}
+
+/// Input file from the `input` directory of the crate calling the macro, e.g. `day_input!("test.txt")`.
+#[macro_export]
+macro_rules! day_input {
+    ($input_file:expr) => {
+        $crate::input::InputSource::File($crate::input::day_path(env!("CARGO_MANIFEST_DIR"), $input_file))
+    };
+}
+
+/// Path of the optional `config.toml` with the puzzle parameters of the crate calling the macro.
+#[macro_export]
+macro_rules! day_config {
+    () => {
+        ::std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("config.toml")
+    };
+}
+
+pub fn read_lines(source: &InputSource) -> Result<Vec<String>, InputError> {
+    let to_error = |error: io::Error| InputError { source: source.clone(), error };
+
+    return match source {
+        InputSource::File(path) => {
+            let file = fs::File::open(path).map_err(to_error)?;
+            BufReader::new(file).lines().collect::<Result<Vec<String>, io::Error>>().map_err(to_error)
+        }
+        InputSource::Stdin => {
+            io::stdin().lock().lines().collect::<Result<Vec<String>, io::Error>>().map_err(to_error)
+        }
+        InputSource::Text(text) => Ok(lines(text)),
+    };
+}
+
+pub fn read_to_string(source: &InputSource) -> Result<String, InputError> {
+    let to_error = |error: io::Error| InputError { source: source.clone(), error };
+
+    return match source {
+        InputSource::File(path) => fs::read_to_string(path).map_err(to_error),
+        InputSource::Stdin => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text).map_err(to_error)?;
+            Ok(text)
+        }
+        InputSource::Text(text) => Ok(text.to_owned()),
+    };
+}
+
+/// Split puzzle input text into lines, accepting both `\n` and `\r\n` line endings.
+pub fn lines(text: &str) -> Vec<String> {
+    return text.lines().map(|line| line.to_string()).collect::<Vec<String>>();
+}
+
+/// A puzzle input file in the `input` directory of a day, named by its path there without `.txt`,
+/// e.g. `test` for `input/test.txt` or `alice/input` for `input/alice/input.txt`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct NamedInput {
+    pub name: String,
+    pub path: PathBuf,
+}
+
+impl NamedInput {
+    pub fn source(&self) -> InputSource {
+        return InputSource::File(self.path.to_owned());
+    }
+
+    /// The parameters for this input only, in a `.toml` file next to it, e.g. `input/test.toml`.
+    pub fn config_file(&self) -> Option<PathBuf> {
+        return Some(self.path.with_extension("toml")).filter(|path| path.exists());
+    }
+}
+
+fn text_files(dir: &Path) -> Result<Vec<PathBuf>, InputError> {
+    let to_error = |error| InputError { source: InputSource::File(dir.to_owned()), error };
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(dir).map_err(to_error)? {
+        let path = entry.map_err(to_error)?.path();
+        if path.is_dir() || path.extension() == Some("txt".as_ref()) {
+            paths.push(path);
+        }
+    }
+    return Ok(paths);
+}
+
+/// Find the `.txt` files in `input_dir` and in its direct subdirectories, sorted by name.
+/// A missing directory has no inputs.
+pub fn discover_inputs(input_dir: &Path) -> Result<Vec<NamedInput>, InputError> {
+    if !input_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut inputs = Vec::new();
+    for path in text_files(input_dir)? {
+        let files = if path.is_dir() { text_files(&path)? } else { vec![path] };
+        for file in files.into_iter().filter(|file| file.is_file()) {
+            let name = file.strip_prefix(input_dir).unwrap().with_extension("");
+            let name = name.components()
+                .map(|component| component.as_os_str().to_string_lossy().to_string())
+                .collect::<Vec<String>>()
+                .join("/");
+            inputs.push(NamedInput { name, path: file });
+        }
+    }
+    inputs.sort_by(|first, second| first.name.cmp(&second.name));
+    return Ok(inputs);
+}
+
+/// FNV-1a hash of the input text as 16 hex digits, to tell which input an answer belongs to.
+pub fn hash(text: &str) -> String {
+    let mut hash: u64 = 0xcbf29ce484222325;
+    for byte in text.bytes() {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    return format!("{:016x}", hash);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crate::input::{day_path, discover_inputs, hash, read_lines, read_to_string, InputSource};
+
+    #[test]
+    fn test_read_text() {
+        let source = InputSource::Text("1000\r\n2000\n\n3000\n".to_string());
+        assert_eq!(read_lines(&source).unwrap(), vec!["1000", "2000", "", "3000"]);
+        assert_eq!(read_to_string(&source).unwrap(), "1000\r\n2000\n\n3000\n");
+        assert_eq!(hash(""), "cbf29ce484222325");
+        assert_eq!(hash("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_read_file() {
+        let missing = InputSource::File(day_path(env!("CARGO_MANIFEST_DIR"), "missing.txt"));
+        let error = read_lines(&missing).unwrap_err();
+        assert_eq!(error.source, missing);
+        assert_eq!(day_path("/aoc/day01", "test.txt"), PathBuf::from("/aoc/day01/input/test.txt"));
+        assert_eq!(day_path("/aoc/day01", "/tmp/input.txt"), PathBuf::from("/tmp/input.txt"));
+
+        let source = InputSource::File(PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml"));
+        assert_eq!(read_lines(&source).unwrap()[0], "[package]");
+    }
+
+    #[test]
+    fn test_discover_inputs() {
+        let input_dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(input_dir.join("alice")).unwrap();
+        for file in ["test.txt", "test.toml", "input.txt", "alice/input.txt", "alice/notes.md"] {
+            fs::write(input_dir.join(file), "").unwrap();
+        }
+
+        let inputs = discover_inputs(&input_dir).unwrap();
+        let names = inputs.iter().map(|input| input.name.as_str()).collect::<Vec<&str>>();
+        assert_eq!(names, vec!["alice/input", "input", "test"]);
+        assert_eq!(inputs[0].path, input_dir.join("alice").join("input.txt"));
+        assert_eq!(inputs[0].config_file(), None);
+        assert_eq!(inputs[2].config_file(), Some(input_dir.join("test.toml")));
+        assert!(discover_inputs(&input_dir.join("missing")).unwrap().is_empty());
+
+        fs::remove_dir_all(&input_dir).unwrap();
+    }
+}
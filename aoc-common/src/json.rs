@@ -0,0 +1,70 @@
+use std::fmt;
+
+/// Quote a string for JSON, escaping quotes, backslashes and control characters.
+pub fn quote(text: &str) -> String {
+    let mut quoted = String::from("\"");
+    for char in text.chars() {
+        match char {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            char if (char as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", char as u32)),
+            char => quoted.push(char),
+        }
+    }
+    quoted.push('"');
+    return quoted;
+}
+
+/// A flat JSON object that keeps its fields in the order they were added.
+#[derive(Debug)]
+#[derive(Default)]
+pub struct JsonObject {
+    fields: Vec<(String, String)>,
+}
+
+impl JsonObject {
+    pub fn new() -> JsonObject {
+        return JsonObject::default();
+    }
+
+    pub fn string(&mut self, key: &str, value: &str) -> &mut JsonObject {
+        self.fields.push((key.to_string(), quote(value)));
+        return self;
+    }
+
+    /// Add a number, which must be finite for the output to be valid JSON.
+    pub fn number(&mut self, key: &str, value: impl fmt::Display) -> &mut JsonObject {
+        self.fields.push((key.to_string(), value.to_string()));
+        return self;
+    }
+}
+
+impl fmt::Display for JsonObject {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fields = self.fields.iter()
+            .map(|(key, value)| format!("{}:{}", quote(key), value))
+            .collect::<Vec<String>>();
+        write!(f, "{{{}}}", fields.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::json::{quote, JsonObject};
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("say \"hi\"\n\\"), "\"say \\\"hi\\\"\\n\\\\\"");
+        assert_eq!(quote("\u{1b}[0m"), "\"\\u001b[0m\"");
+    }
+
+    #[test]
+    fn test_object() {
+        let mut object = JsonObject::new();
+        object.number("day", 10).string("answer", "\n##").number("solve_us", format!("{:.1}", 12.34));
+        assert_eq!(object.to_string(), "{\"day\":10,\"answer\":\"\\n##\",\"solve_us\":12.3}");
+    }
+}
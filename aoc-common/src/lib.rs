@@ -0,0 +1,18 @@
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod explore;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod params;
+pub mod parse;
+pub mod reference;
+pub mod search;
+pub mod report;
+pub mod solution;
+pub mod stream;
+pub mod toml;
+pub mod visualize;
@@ -0,0 +1,276 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use crate::error::Error;
+use crate::input::{self, InputSource};
+use crate::parse::{Line, ParseError};
+use crate::toml::{parse_line, TomlLine, TomlValue};
+
+/// A named puzzle parameter with its current value, as listed by `--params`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    pub type_name: &'static str,
+    pub value: String,
+    pub doc: &'static str,
+}
+
+impl fmt::Display for ParamInfo {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} = {}", self.name, self.type_name, self.value)?;
+        if !self.doc.trim().is_empty() {
+            write!(f, "  # {}", self.doc.trim())?;
+        }
+        return Ok(());
+    }
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum ParamError {
+    Unknown(String),
+    InvalidValue { name: String, type_name: &'static str, value: String },
+    /// A value of the right type that the solution cannot handle, e.g. a chamber narrower than a rock
+    OutOfRange { name: String, requirement: String, value: String },
+    /// A `--param` argument that is not of the form `name=value`
+    Syntax(String),
+}
+
+impl fmt::Display for ParamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ParamError::Unknown(name) => write!(f, "Unknown parameter '{}'", name),
+            ParamError::InvalidValue { name, type_name, value } => {
+                write!(f, "Parameter {} must be a {}, got '{}'", name, type_name, value)
+            }
+            ParamError::OutOfRange { name, requirement, value } => {
+                write!(f, "Parameter {} must be {}, got '{}'", name, requirement, value)
+            }
+            ParamError::Syntax(arg) => write!(f, "Parameters must be given as name=value, got '{}'", arg),
+        }
+    }
+}
+
+impl std::error::Error for ParamError {}
+
+/// Puzzle parameters that can be set by name, e.g. from the command line. Implement it with `params!`.
+pub trait Params: Default {
+    fn describe(&self) -> Vec<ParamInfo>;
+    fn set(&mut self, name: &str, value: &str) -> Result<(), ParamError>;
+
+    /// Check that the solution can handle the values, after all of them are set.
+    fn validate(&self) -> Result<(), ParamError> {
+        return Ok(());
+    }
+}
+
+/// Days without parameters.
+impl Params for () {
+    fn describe(&self) -> Vec<ParamInfo> {
+        return Vec::new();
+    }
+
+    fn set(&mut self, name: &str, _: &str) -> Result<(), ParamError> {
+        return Err(ParamError::Unknown(name.to_string()));
+    }
+}
+
+/// Parse the value of a parameter. Numbers may use `_` as a separator, like in Rust.
+pub fn parse_value<T: FromStr>(name: &str, type_name: &'static str, value: &str) -> Result<T, ParamError> {
+    return value.parse::<T>()
+        .or_else(|_| value.replace('_', "").parse::<T>())
+        .map_err(|_| ParamError::InvalidValue { name: name.to_string(), type_name, value: value.to_string() });
+}
+
+/// Check a requirement of a parameter for `Params::validate`, e.g. `check_param("rounds", rounds > 0, "positive", rounds)`.
+pub fn check_param<T: fmt::Display>(name: &str, requirement_met: bool, requirement: &str, value: T) -> Result<(), ParamError> {
+    if requirement_met {
+        return Ok(());
+    }
+    return Err(ParamError::OutOfRange { name: name.to_string(), requirement: requirement.to_string(), value: value.to_string() });
+}
+
+/// Define a config struct with a default for every field, and implement `Params` for it. An optional
+/// `validate` block checks the values once they are all set:
+///
+/// ```
+/// aoc_common::params! {
+///     pub struct Config {
+///         /// The number of rounds in task 1
+///         rounds: u32 = 20,
+///     }
+///
+///     validate(config) {
+///         aoc_common::params::check_param("rounds", config.rounds > 0, "positive", config.rounds)
+///     }
+/// }
+/// ```
+#[macro_export]
+macro_rules! params {
+    ($(#[$meta:meta])* $vis:vis struct $name:ident {
+        $($(#[doc = $doc:literal])* $field:ident: $type:ty = $default:expr,)*
+    }) => {
+        $crate::params! {
+            $(#[$meta])* $vis struct $name {
+                $($(#[doc = $doc])* $field: $type = $default,)*
+            }
+
+            validate(_config) {
+                Ok(())
+            }
+        }
+    };
+    ($(#[$meta:meta])* $vis:vis struct $name:ident {
+        $($(#[doc = $doc:literal])* $field:ident: $type:ty = $default:expr,)*
+    }
+
+    validate($config:ident) $validate:block) => {
+        $(#[$meta])*
+        #[derive(Debug)]
+        #[derive(Clone)]
+        #[derive(PartialEq)]
+        $vis struct $name {
+            $($(#[doc = $doc])* pub $field: $type,)*
+        }
+
+        impl Default for $name {
+            fn default() -> $name {
+                return $name { $($field: $default,)* };
+            }
+        }
+
+        impl $crate::params::Params for $name {
+            fn describe(&self) -> Vec<$crate::params::ParamInfo> {
+                return vec![$($crate::params::ParamInfo {
+                    name: stringify!($field),
+                    type_name: stringify!($type),
+                    value: self.$field.to_string(),
+                    doc: concat!($($doc),*),
+                },)*];
+            }
+
+            fn set(&mut self, name: &str, value: &str) -> Result<(), $crate::params::ParamError> {
+                match name {
+                    $(stringify!($field) => self.$field = $crate::params::parse_value(name, stringify!($type), value)?,)*
+                    _ => return Err($crate::params::ParamError::Unknown(name.to_string())),
+                }
+                return Ok(());
+            }
+
+            fn validate(&self) -> Result<(), $crate::params::ParamError> {
+                let $config = self;
+                return $validate;
+            }
+        }
+    };
+}
+
+/// Set the parameters from a config file with a `name = value` line per parameter.
+/// Values are written as in TOML: strings are quoted, and `#` starts a comment.
+pub fn apply_config<P: Params>(params: &mut P, text: &str) -> Result<(), ParseError> {
+    for (index, text) in text.lines().enumerate() {
+        let line = Line::new(index, text);
+        let (name, value, value_start) = match parse_line(&line)? {
+            None => continue,
+            Some(TomlLine::Section(_)) => return Err(line.error(0, "a parameter")),
+            Some(TomlLine::Entry { key, value: TomlValue::String(value), value_start }) => (key, value, value_start),
+            Some(TomlLine::Entry { key, value: TomlValue::Bare(value), value_start }) => (key, value.to_string(), value_start),
+        };
+
+        match params.set(name, &value) {
+            Ok(()) => (),
+            Err(ParamError::InvalidValue { type_name, .. }) => return Err(line.error(value_start, &format!("a {}", type_name))),
+            Err(_) => return Err(line.error(0, "the name of a parameter")),
+        }
+    }
+    return Ok(());
+}
+
+/// Where the parameters of a run come from. The defaults are overridden by the config files in
+/// order, and then by the `name=value` overrides from the command line.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(Default)]
+#[derive(PartialEq)]
+pub struct ParamArgs {
+    pub config_files: Vec<PathBuf>,
+    pub overrides: Vec<(String, String)>,
+}
+
+impl ParamArgs {
+    /// Add a `name=value` override from the command line.
+    pub fn add_override(&mut self, arg: &str) -> Result<(), ParamError> {
+        let (name, value) = arg.split_once('=').ok_or_else(|| ParamError::Syntax(arg.to_string()))?;
+        self.overrides.push((name.trim().to_string(), value.trim().to_string()));
+        return Ok(());
+    }
+
+    pub fn resolve<P: Params>(&self) -> Result<P, Error> {
+        let mut params = P::default();
+        for config_file in &self.config_files {
+            let source = InputSource::File(config_file.to_owned());
+            let text = input::read_to_string(&source)?;
+            apply_config(&mut params, &text).map_err(|error| Error::Parse(source, error))?;
+        }
+        for (name, value) in &self.overrides {
+            params.set(name, value)?;
+        }
+        params.validate()?;
+        return Ok(params);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::{apply_config, check_param, ParamArgs, ParamError, Params};
+    use crate::parse::ParseError;
+
+    crate::params! {
+        struct Config {
+            /// The number of rounds
+            rounds: u64 = 1_000,
+            start: String = "AA".to_string(),
+        }
+
+        validate(config) {
+            check_param("rounds", config.rounds > 0, "positive", config.rounds)
+        }
+    }
+
+    #[test]
+    fn test_set() {
+        let mut config = Config::default();
+        config.set("rounds", "1_000_000").unwrap();
+        config.set("start", "BB").unwrap();
+        assert_eq!(config, Config { rounds: 1_000_000, start: "BB".to_string() });
+        assert_eq!(config.set("rounds", "-1").unwrap_err().to_string(), "Parameter rounds must be a u64, got '-1'");
+        assert_eq!(config.set("round", "1"), Err(ParamError::Unknown("round".to_string())));
+        assert_eq!(config.describe()[0].to_string(), "rounds: u64 = 1000000  # The number of rounds");
+        assert!(().set("rounds", "1").is_err());
+    }
+
+    #[test]
+    fn test_config_file() {
+        let mut config = Config::default();
+        apply_config(&mut config, "# Variant\nrounds = 20 # fewer\n\nstart = \"ZZ\"\n").unwrap();
+        assert_eq!(config, Config { rounds: 20, start: "ZZ".to_string() });
+        assert_eq!(apply_config(&mut config, "rounds = many").unwrap_err(), ParseError::new(1, 10, "a u64", "many"));
+        assert_eq!(apply_config(&mut config, "speed = 1").unwrap_err(), ParseError::new(1, 1, "the name of a parameter", "speed"));
+
+        let mut args = ParamArgs::default();
+        args.add_override("rounds=5").unwrap();
+        assert!(args.add_override("rounds").is_err());
+        assert_eq!(args.resolve::<Config>().unwrap().rounds, 5);
+        args.add_override("rounds=0").unwrap();
+        assert_eq!(args.resolve::<Config>().unwrap_err().to_string(), "Parameter rounds must be positive, got '0'");
+        assert_eq!(Config { rounds: 0, start: "AA".to_string() }.validate(), Err(ParamError::OutOfRange {
+            name: "rounds".to_string(),
+            requirement: "positive".to_string(),
+            value: "0".to_string(),
+        }));
+    }
+}
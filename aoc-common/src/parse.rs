@@ -0,0 +1,185 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A problem in the puzzle input, located by its 1-based line and column.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub found: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: &str, found: &str) -> ParseError {
+        return ParseError { line, column, expected: expected.to_string(), found: found.to_string() };
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let found = if self.found.is_empty() {
+            "nothing".to_string()
+        } else {
+            format!("'{}'", self.found)
+        };
+        write!(f, "line {}, column {}: expected {}, found {}", self.line, self.column, self.expected, found)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// The line at `index`, or an error pointing past the last line if the input ends early.
+pub fn get_line<'a>(lines: &'a [String], index: usize, expected: &str) -> Result<Line<'a>, ParseError> {
+    return match lines.get(index) {
+        Some(text) => Ok(Line::new(index, text)),
+        None => Err(ParseError::new(index + 1, 1, expected, "")),
+    };
+}
+
+/// A whitespace separated word and the byte offset it starts at.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct Word<'a> {
+    pub start: usize,
+    pub text: &'a str,
+}
+
+/// A line of puzzle input that reports errors with its position.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Create a line from its 0-based index in the input.
+    pub fn new(index: usize, text: &'a str) -> Line<'a> {
+        return Line { number: index + 1, text };
+    }
+
+    /// Error at the byte offset `start`, reporting the rest of the word found there.
+    pub fn error(&self, start: usize, expected: &str) -> ParseError {
+        let start = start.min(self.text.len());
+        let rest = &self.text[start..];
+        let found = rest.split_whitespace().next().unwrap_or("");
+        return ParseError::new(self.number, start + 1, expected, found);
+    }
+
+    /// Error covering the text between the byte offsets `start` and `end`.
+    pub fn error_span(&self, start: usize, end: usize, expected: &str) -> ParseError {
+        return ParseError::new(self.number, start + 1, expected, &self.text[start..end]);
+    }
+
+    /// Byte offset of the first `pattern` at or after `from`.
+    pub fn find(&self, pattern: &str, from: usize) -> Result<usize, ParseError> {
+        return match self.text.get(from..).and_then(|rest| rest.find(pattern)) {
+            Some(index) => Ok(from + index),
+            None => Err(self.error(from, &format!("'{}'", pattern))),
+        };
+    }
+
+    /// Byte offset of the last `pattern` in the line.
+    pub fn rfind(&self, pattern: &str) -> Result<usize, ParseError> {
+        return self.text.rfind(pattern).ok_or_else(|| self.error(0, &format!("'{}'", pattern)));
+    }
+
+    /// Check that the line starts with `prefix` and return the offset after it.
+    pub fn expect_prefix(&self, prefix: &str) -> Result<usize, ParseError> {
+        if !self.text.starts_with(prefix) {
+            return Err(self.error(0, &format!("'{}'", prefix.trim())));
+        }
+        return Ok(prefix.len());
+    }
+
+    /// Parse the text between the byte offsets `start` and `end`.
+    pub fn parse<T: FromStr>(&self, start: usize, end: usize, expected: &str) -> Result<T, ParseError> {
+        let end = end.min(self.text.len());
+        return match self.text.get(start..end).map(|text| text.parse::<T>()) {
+            Some(Ok(value)) => Ok(value),
+            Some(Err(_)) => Err(self.error_span(start, end, expected)),
+            None => Err(self.error(start, expected)),
+        };
+    }
+
+    /// Parse everything from the byte offset `start` to the end of the line.
+    pub fn parse_rest<T: FromStr>(&self, start: usize, expected: &str) -> Result<T, ParseError> {
+        return self.parse(start, self.text.len(), expected);
+    }
+
+    pub fn words(&self) -> Vec<Word<'a>> {
+        let mut words = Vec::new();
+        let mut word_start = None;
+        for (index, char) in self.text.char_indices() {
+            match (char.is_whitespace(), word_start) {
+                (true, Some(start)) => {
+                    words.push(Word { start, text: &self.text[start..index] });
+                    word_start = None;
+                }
+                (false, None) => word_start = Some(index),
+                _ => ()
+            }
+        }
+        if let Some(start) = word_start {
+            words.push(Word { start, text: &self.text[start..] });
+        }
+        return words;
+    }
+
+    /// The word at `index`, or an error at the end of the line if there are too few words.
+    pub fn word(&self, index: usize, expected: &str) -> Result<Word<'a>, ParseError> {
+        return self.words().get(index).copied().ok_or_else(|| self.error(self.text.len(), expected));
+    }
+
+    /// Check that the word at `index` is exactly `expected_word`.
+    pub fn expect_word(&self, index: usize, expected_word: &str) -> Result<Word<'a>, ParseError> {
+        let expected = format!("'{}'", expected_word);
+        let word = self.word(index, &expected)?;
+        if word.text != expected_word {
+            return Err(self.error(word.start, &expected));
+        }
+        return Ok(word);
+    }
+
+    /// Parse the word at `index`.
+    pub fn parse_word<T: FromStr>(&self, index: usize, expected: &str) -> Result<T, ParseError> {
+        let word = self.word(index, expected)?;
+        return self.parse(word.start, word.start + word.text.len(), expected);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{get_line, Line, ParseError, Word};
+
+    #[test]
+    fn test_parse_positions() {
+        let line = Line::new(2, "move 3 from x to 2");
+        assert_eq!(line.parse_word::<u32>(1, "a number"), Ok(3));
+        assert_eq!(line.parse_word::<u32>(3, "a stack number"), Err(ParseError::new(3, 13, "a stack number", "x")));
+        assert_eq!(line.word(6, "a stack number"), Err(ParseError::new(3, 19, "a stack number", "")));
+        assert_eq!(line.words()[2], Word { start: 7, text: "from" });
+        assert_eq!(line.find("to", 0), Ok(14));
+        assert_eq!(line.find("=", 5), Err(ParseError::new(3, 6, "'='", "3")));
+        assert_eq!(line.expect_word(2, "from").map(|word| word.start), Ok(7));
+        assert!(line.expect_word(4, "from").is_err());
+    }
+
+    #[test]
+    fn test_error_message() {
+        let line = Line::new(0, "Sensor at x=2, y=1a");
+        let y_start = line.find("y=", 0).unwrap() + 2;
+        let error = line.parse_rest::<i32>(y_start, "a number").unwrap_err();
+        assert_eq!(error.to_string(), "line 1, column 18: expected a number, found '1a'");
+        assert_eq!(line.expect_prefix("Beacon").unwrap_err().to_string(),
+                   "line 1, column 1: expected 'Beacon', found 'Sensor'");
+
+        let lines = vec!["Monkey 0:".to_string()];
+        assert_eq!(get_line(&lines, 1, "starting items").unwrap_err().to_string(),
+                   "line 2, column 1: expected starting items, found nothing");
+    }
+}
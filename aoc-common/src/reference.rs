@@ -0,0 +1,150 @@
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::error::panic_message;
+use crate::generate::{GeneratedInput, Generator, Rng};
+use crate::solution::Answer;
+
+/// Simple, obviously correct solutions to check the real ones against. They may be slow, so they are
+/// only run on small generated inputs.
+pub trait Reference: Generator {
+    /// Limit the config of a generated input to what the reference solutions can solve, e.g. fewer rounds.
+    fn reference_config(config: Self::Config) -> Self::Config {
+        return config;
+    }
+
+    fn reference_part1(input: &Self::Input, config: &Self::Config) -> Self::Output1;
+    fn reference_part2(input: &Self::Input, config: &Self::Config) -> Self::Output2;
+}
+
+/// A generated input on which a part of the solution gives another answer than the reference solution.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Disagreement {
+    pub size: usize,
+    pub seed: u64,
+    pub part: u32,
+    pub input: GeneratedInput,
+    /// The answer of the reference solution, or why it failed
+    pub expected: Result<Answer, String>,
+    /// The answer of the solution, or why it failed
+    pub actual: Result<Answer, String>,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let describe = |outcome: &Result<Answer, String>| match outcome {
+            Ok(answer) => answer.to_string(),
+            Err(message) => format!("<{}>", message),
+        };
+        write!(f, "Part {} of the input of size {} and seed {}: the reference answer is {}, the solution answered {}",
+               self.part, self.size, self.seed, describe(&self.expected), describe(&self.actual))
+    }
+}
+
+fn run<A: Into<Answer>>(solve: impl FnOnce() -> A) -> Result<Answer, String> {
+    return panic::catch_unwind(AssertUnwindSafe(|| solve().into()))
+        .map_err(|payload| format!("panicked: {}", panic_message(payload.as_ref())));
+}
+
+/// Solve both parts of the input for `seed` and `size` with the solution and the reference solution,
+/// and return the parts they disagree on.
+pub fn compare<S: Reference>(seed: u64, size: usize) -> Vec<Disagreement> {
+    let (text, config) = S::generate(&mut Rng::new(seed), size);
+    let config = S::reference_config(config);
+    let input = GeneratedInput::new(text, &config);
+    let parsed = match S::parse(&input.text) {
+        Ok(parsed) => parsed,
+        Err(error) => {
+            let expected = Err(format!("the generated input is invalid: {}", error));
+            return (1..=2).map(|part| Disagreement { size, seed, part, input: input.clone(), expected: expected.clone(), actual: expected.clone() }).collect();
+        }
+    };
+
+    let mut disagreements = Vec::new();
+    let outcomes = [
+        (run(|| S::reference_part1(&parsed, &config)), run(|| S::part1(&parsed, &config))),
+        (run(|| S::reference_part2(&parsed, &config)), run(|| S::part2(&parsed, &config))),
+    ];
+    for (part, (expected, actual)) in (1..=2).zip(outcomes) {
+        if expected.is_err() || expected != actual {
+            disagreements.push(Disagreement { size, seed, part, input: input.clone(), expected, actual });
+        }
+    }
+    return disagreements;
+}
+
+/// Compare the solutions on `count` inputs of every size, from small to large, and return the smallest
+/// input they disagree on: the one with the shortest text of the smallest size with a disagreement.
+pub fn find_smallest_disagreement<S: Reference>(sizes: &[usize], seed: u64, count: u32) -> Option<Disagreement> {
+    let mut sizes = sizes.to_vec();
+    sizes.sort();
+    for size in sizes {
+        let smallest = (seed..seed + count as u64)
+            .flat_map(|seed| compare::<S>(seed, size))
+            .min_by_key(|disagreement| (disagreement.input.text.len(), disagreement.part));
+        if smallest.is_some() {
+            return smallest;
+        }
+    }
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::{Generator, Rng};
+    use crate::parse::ParseError;
+    use crate::reference::{find_smallest_disagreement, Reference};
+    use crate::solution::{Answer, Solution};
+
+    // Its largest number only looks at the first three numbers
+    struct Largest;
+
+    impl Solution for Largest {
+        const DAY: u32 = 0;
+        type Input = Vec<u32>;
+        type Config = ();
+        type Output1 = u32;
+        type Output2 = u32;
+
+        fn parse(input: &str) -> Result<Vec<u32>, ParseError> {
+            return Ok(input.split_whitespace().map(|number| number.parse().unwrap()).collect());
+        }
+
+        fn part1(numbers: &Vec<u32>, _: &()) -> u32 {
+            return numbers.iter().take(3).copied().max().unwrap();
+        }
+
+        fn part2(numbers: &Vec<u32>, _: &()) -> u32 {
+            return numbers.iter().sum();
+        }
+    }
+
+    impl Generator for Largest {
+        fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+            return ((0..size).map(|_| format!("{} ", rng.range(0..100))).collect(), ());
+        }
+    }
+
+    impl Reference for Largest {
+        fn reference_part1(numbers: &Vec<u32>, _: &()) -> u32 {
+            return *numbers.iter().max().unwrap();
+        }
+
+        fn reference_part2(numbers: &Vec<u32>, _: &()) -> u32 {
+            return numbers.iter().sum();
+        }
+    }
+
+    #[test]
+    fn test_find_smallest_disagreement() {
+        assert_eq!(find_smallest_disagreement::<Largest>(&[1, 2, 3], 0, 10), None);
+
+        let disagreement = find_smallest_disagreement::<Largest>(&[50, 4, 10], 0, 20).unwrap();
+        assert_eq!((disagreement.size, disagreement.part), (4, 1));
+        let numbers = Largest::parse(&disagreement.input.text).unwrap();
+        assert_eq!(disagreement.expected, Ok(Answer::Number(numbers[3] as i128)));
+        assert!(disagreement.to_string().starts_with(&format!("Part 1 of the input of size 4 and seed {}: the reference answer is", disagreement.seed)));
+    }
+}
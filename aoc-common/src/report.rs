@@ -0,0 +1,91 @@
+use std::time::{Duration, Instant};
+
+use crate::input;
+use crate::json::JsonObject;
+use crate::parse::ParseError;
+use crate::solution::{Answer, Solution};
+
+/// The answer to one part of a day, with how long it took and which input it was computed for.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct PartReport {
+    pub day: u32,
+    pub part: u32,
+    pub answer: Answer,
+    /// Parsing is shared by both parts, so both reports of a run have the same parse time
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    pub input_hash: String,
+}
+
+impl PartReport {
+    /// One line of JSON. The answer is always a string, so large numbers keep their precision.
+    pub fn to_json(&self) -> String {
+        let answer_type = match self.answer {
+            Answer::Number(_) => "number",
+            Answer::Text(_) => "text",
+        };
+        let mut object = JsonObject::new();
+        object.number("day", self.day)
+            .number("part", self.part)
+            .string("answer", &self.answer.to_string())
+            .string("answer_type", answer_type)
+            .number("parse_us", format!("{:.1}", self.parse_time.as_secs_f64() * 1_000_000.0))
+            .number("solve_us", format!("{:.1}", self.solve_time.as_secs_f64() * 1_000_000.0))
+            .string("input_hash", &self.input_hash);
+        return object.to_string();
+    }
+}
+
+fn timed(solve: impl FnOnce() -> Answer) -> (Answer, Duration) {
+    let now = Instant::now();
+    let answer = solve();
+    return (answer, now.elapsed());
+}
+
+/// Parse the input text once and solve the requested part, or both parts, timing every step.
+pub fn report<S: Solution>(text: &str, part: Option<u32>, config: &S::Config) -> Result<Vec<PartReport>, ParseError> {
+    let input_hash = input::hash(text);
+    let now = Instant::now();
+    let data = S::parse(text)?;
+    let parse_time = now.elapsed();
+
+    let mut answers = Vec::new();
+    if part != Some(2) {
+        answers.push((1, timed(|| S::part1(&data, config).into())));
+    }
+    if part != Some(1) {
+        answers.push((2, timed(|| S::part2(&data, config).into())));
+    }
+    return Ok(answers.into_iter().map(|(part, (answer, solve_time))| PartReport {
+        day: S::DAY,
+        part,
+        answer,
+        parse_time,
+        solve_time,
+        input_hash: input_hash.clone(),
+    }).collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::report::PartReport;
+    use crate::solution::Answer;
+
+    #[test]
+    fn test_to_json() {
+        let report = PartReport {
+            day: 10,
+            part: 2,
+            answer: Answer::Text("\n#.".to_string()),
+            parse_time: Duration::from_micros(1500),
+            solve_time: Duration::from_nanos(2_340),
+            input_hash: "cbf29ce484222325".to_string(),
+        };
+        assert_eq!(report.to_json(), "{\"day\":10,\"part\":2,\"answer\":\"\\n#.\",\"answer_type\":\"text\",\
+            \"parse_us\":1500.0,\"solve_us\":2.3,\"input_hash\":\"cbf29ce484222325\"}");
+    }
+}
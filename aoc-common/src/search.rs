@@ -0,0 +1,280 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// A graph to search, given by the neighbours of every state, e.g. squares of a heightmap or the
+/// resources of a robot factory after every minute.
+pub trait SearchSpace {
+    type State: Clone + Eq + Hash;
+
+    /// The states one step away from `state`, with the cost of the step.
+    fn neighbours(&self, state: &Self::State) -> Vec<(Self::State, u64)>;
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    /// A lower bound of the cost from `state` to the nearest goal, which guides `astar`. It must never
+    /// be too high, or A* may miss the cheapest path.
+    fn heuristic(&self, _state: &Self::State) -> u64 {
+        return 0;
+    }
+}
+
+/// The states from a start to a goal, both included, and the total cost of the steps between them.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct SearchResult<S> {
+    /// The path that was found, or `None` if no goal can be reached.
+    pub path: Option<Path<S>>,
+    /// The number of states whose neighbours were looked at, to compare how much work searches do.
+    pub expanded: usize,
+}
+
+impl<S> SearchResult<S> {
+    pub fn cost(&self) -> Option<u64> {
+        return self.path.as_ref().map(|path| path.cost);
+    }
+}
+
+// Every state seen so far by index, with the cheapest known cost to reach it and the state it was reached from
+struct SearchTree<S> {
+    states: Vec<S>,
+    costs: Vec<u64>,
+    parents: Vec<Option<usize>>,
+    indices: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> SearchTree<S> {
+    fn new() -> SearchTree<S> {
+        return SearchTree { states: Vec::new(), costs: Vec::new(), parents: Vec::new(), indices: HashMap::new() };
+    }
+
+    // Remember the state if it is new or reached cheaper than before, returning its index if so
+    fn reach(&mut self, state: S, cost: u64, parent: Option<usize>) -> Option<usize> {
+        if let Some(index) = self.indices.get(&state).copied() {
+            if cost >= self.costs[index] {
+                return None;
+            }
+            self.costs[index] = cost;
+            self.parents[index] = parent;
+            return Some(index);
+        }
+        let index = self.states.len();
+        self.indices.insert(state.clone(), index);
+        self.states.push(state);
+        self.costs.push(cost);
+        self.parents.push(parent);
+        return Some(index);
+    }
+
+    fn path(&self, index: usize) -> Path<S> {
+        let mut states = vec![self.states[index].clone()];
+        let mut current = index;
+        while let Some(parent) = self.parents[current] {
+            states.push(self.states[parent].clone());
+            current = parent;
+        }
+        states.reverse();
+        return Path { cost: self.costs[index], states };
+    }
+}
+
+/// Breadth-first search for the goal the fewest steps away from any of the starts. The cost of the path
+/// is the sum of its step costs, which is the cheapest only if every step costs the same.
+pub fn bfs<G: SearchSpace>(space: &G, starts: impl IntoIterator<Item = G::State>) -> SearchResult<G::State> {
+    let mut tree = SearchTree::new();
+    let mut queue: VecDeque<usize> = starts.into_iter().filter_map(|start| tree.reach(start, 0, None)).collect();
+    let mut expanded = 0;
+
+    while let Some(index) = queue.pop_front() {
+        if space.is_goal(&tree.states[index]) {
+            return SearchResult { path: Some(tree.path(index)), expanded };
+        }
+        expanded += 1;
+        for (neighbour, step_cost) in space.neighbours(&tree.states[index]) {
+            if !tree.indices.contains_key(&neighbour) {
+                let neighbour_index = tree.reach(neighbour, tree.costs[index] + step_cost, Some(index)).unwrap();
+                queue.push_back(neighbour_index);
+            }
+        }
+    }
+    return SearchResult { path: None, expanded };
+}
+
+/// Depth-first search for any goal reachable from the starts, which is not necessarily the closest. It needs
+/// less memory than `bfs` to find out whether a goal can be reached at all.
+pub fn dfs<G: SearchSpace>(space: &G, starts: impl IntoIterator<Item = G::State>) -> SearchResult<G::State> {
+    let mut tree = SearchTree::new();
+    let mut stack: Vec<usize> = starts.into_iter().filter_map(|start| tree.reach(start, 0, None)).collect();
+    stack.reverse();
+    let mut expanded = 0;
+
+    while let Some(index) = stack.pop() {
+        if space.is_goal(&tree.states[index]) {
+            return SearchResult { path: Some(tree.path(index)), expanded };
+        }
+        expanded += 1;
+        let neighbours = space.neighbours(&tree.states[index]);
+        for (neighbour, step_cost) in neighbours.into_iter().rev() {
+            if !tree.indices.contains_key(&neighbour) {
+                let neighbour_index = tree.reach(neighbour, tree.costs[index] + step_cost, Some(index)).unwrap();
+                stack.push(neighbour_index);
+            }
+        }
+    }
+    return SearchResult { path: None, expanded };
+}
+
+// Dijkstra's algorithm, and A* when the heuristic is used to pick the next state
+fn best_first<G: SearchSpace>(space: &G, starts: impl IntoIterator<Item = G::State>, use_heuristic: bool) -> SearchResult<G::State> {
+    let priority = |state: &G::State, cost: u64| if use_heuristic { cost + space.heuristic(state) } else { cost };
+    let mut tree = SearchTree::new();
+    let mut heap: BinaryHeap<Reverse<(u64, u64, usize)>> = BinaryHeap::new();
+    for start in starts {
+        if let Some(index) = tree.reach(start, 0, None) {
+            heap.push(Reverse((priority(&tree.states[index], 0), 0, index)));
+        }
+    }
+    let mut expanded = 0;
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        // The state was reached cheaper after this entry was added
+        if cost > tree.costs[index] {
+            continue;
+        }
+        if space.is_goal(&tree.states[index]) {
+            return SearchResult { path: Some(tree.path(index)), expanded };
+        }
+        expanded += 1;
+        for (neighbour, step_cost) in space.neighbours(&tree.states[index]) {
+            let neighbour_cost = cost + step_cost;
+            if let Some(neighbour_index) = tree.reach(neighbour, neighbour_cost, Some(index)) {
+                heap.push(Reverse((priority(&tree.states[neighbour_index], neighbour_cost), neighbour_cost, neighbour_index)));
+            }
+        }
+    }
+    return SearchResult { path: None, expanded };
+}
+
+/// Dijkstra's algorithm for the cheapest path from any of the starts to a goal.
+pub fn dijkstra<G: SearchSpace>(space: &G, starts: impl IntoIterator<Item = G::State>) -> SearchResult<G::State> {
+    return best_first(space, starts, false);
+}
+
+/// A* search for the cheapest path from any of the starts to a goal, which looks at the states that
+/// `SearchSpace::heuristic` estimates closest to a goal first.
+pub fn astar<G: SearchSpace>(space: &G, starts: impl IntoIterator<Item = G::State>) -> SearchResult<G::State> {
+    return best_first(space, starts, true);
+}
+
+/// Depth-first search through every path from `start` for the goal reached at the highest cost, e.g. the
+/// most geodes opened by the last minute. States are not remembered, so the neighbours must not lead back.
+/// Paths are pruned when `upper_bound`, the most cost that can still be added after a state, cannot beat
+/// the best goal so far. It may be too high, which only prunes less, but never too low.
+pub fn dfs_with_pruning<G: SearchSpace, F: Fn(&G::State) -> u64>(space: &G, start: G::State, upper_bound: F) -> SearchResult<G::State> {
+    let mut best: Option<Path<G::State>> = None;
+    // The current path, and the states still to visit with their cost and their depth in the path
+    let mut path: Vec<G::State> = Vec::new();
+    let mut stack: Vec<(G::State, u64, usize)> = vec![(start, 0, 0)];
+    let mut expanded = 0;
+
+    while let Some((state, cost, depth)) = stack.pop() {
+        if best.as_ref().is_some_and(|best| cost.saturating_add(upper_bound(&state)) <= best.cost) {
+            continue;
+        }
+        path.truncate(depth);
+        path.push(state.clone());
+        if space.is_goal(&state) {
+            if best.as_ref().is_none_or(|best| cost > best.cost) {
+                best = Some(Path { cost, states: path.to_vec() });
+            }
+            continue;
+        }
+        expanded += 1;
+        let neighbours = space.neighbours(&state);
+        for (neighbour, step_cost) in neighbours.into_iter().rev() {
+            stack.push((neighbour, cost + step_cost, depth + 1));
+        }
+    }
+    return SearchResult { path: best, expanded };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::{astar, bfs, dfs, dfs_with_pruning, dijkstra, SearchSpace};
+
+    // Walking along a line of numbers, where a step of one costs 1 and a jump of ten costs 5
+    struct NumberLine {
+        goal: i64,
+    }
+
+    impl SearchSpace for NumberLine {
+        type State = i64;
+
+        fn neighbours(&self, number: &i64) -> Vec<(i64, u64)> {
+            return vec![(number + 1, 1), (number - 1, 1), (number + 10, 5)]
+                .into_iter().filter(|(next, _)| (-20..=40).contains(next)).collect();
+        }
+
+        fn is_goal(&self, number: &i64) -> bool {
+            return *number == self.goal;
+        }
+
+        fn heuristic(&self, number: &i64) -> u64 {
+            return (self.goal - number).unsigned_abs().div_ceil(2);
+        }
+    }
+
+    #[test]
+    fn test_shortest_paths() {
+        let line = NumberLine { goal: 21 };
+        let fewest_steps = bfs(&line, [0]);
+        assert_eq!(fewest_steps.path.unwrap().states, vec![0, 1, 11, 21]);
+
+        let cheapest = dijkstra(&line, [0]);
+        assert_eq!(cheapest.cost(), Some(11));
+        let guided = astar(&line, [0]);
+        assert_eq!(guided.cost(), Some(11));
+        assert!(guided.expanded < cheapest.expanded);
+
+        assert_eq!(dijkstra(&line, [30, 0]).path.unwrap().states, vec![30, 29, 28, 27, 26, 25, 24, 23, 22, 21]);
+        assert_eq!(bfs(&NumberLine { goal: 50 }, [0]).path, None);
+        assert!(dfs(&line, [0]).path.unwrap().states.ends_with(&[21]));
+    }
+
+    // Counting down from a number by taking one or three, where taking three scores a point
+    struct Countdown;
+
+    impl SearchSpace for Countdown {
+        type State = u32;
+
+        fn neighbours(&self, number: &u32) -> Vec<(u32, u64)> {
+            let mut next = vec![(number - 1, 0)];
+            if *number >= 3 {
+                next.push((number - 3, 1));
+            }
+            return next;
+        }
+
+        fn is_goal(&self, number: &u32) -> bool {
+            return *number == 0;
+        }
+    }
+
+    #[test]
+    fn test_dfs_with_pruning() {
+        let unpruned = dfs_with_pruning(&Countdown, 10, |_| u64::MAX);
+        let pruned = dfs_with_pruning(&Countdown, 10, |number| *number as u64 / 3);
+        assert_eq!(unpruned.cost(), Some(3));
+        assert_eq!(pruned.path, unpruned.path);
+        assert_eq!(pruned.path.unwrap().states, vec![10, 9, 6, 3, 0]);
+        assert!(pruned.expanded < unpruned.expanded);
+    }
+}
@@ -0,0 +1,106 @@
+use std::fmt;
+
+use crate::error::Error;
+use crate::input::{self, InputSource};
+use crate::params::Params;
+use crate::parse::ParseError;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_number_answer {
+    ($($number_type:ty),*) => {
+        $(
+            impl From<$number_type> for Answer {
+                fn from(number: $number_type) -> Answer {
+                    return Answer::Number(number as i128);
+                }
+            }
+        )*
+    };
+}
+
+impl_number_answer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        return Answer::Text(text);
+    }
+}
+
+/// A puzzle solution, split into parsing and the two parts.
+pub trait Solution {
+    /// The day of the puzzle, or 0 for the template.
+    const DAY: u32;
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    /// Puzzle parameters, such as the number of rounds to simulate, defined with `params!`.
+    /// Use `()` if there are none.
+    type Config: Params;
+    type Output1: Into<Answer>;
+    type Output2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input, config: &Self::Config) -> Self::Output1;
+    fn part2(input: &Self::Input, config: &Self::Config) -> Self::Output2;
+}
+
+/// Read and parse the puzzle input for a solution.
+pub fn load<S: Solution>(source: &InputSource) -> Result<S::Input, Error> {
+    let text = input::read_to_string(source)?;
+    return S::parse(&text).map_err(|error| Error::Parse(source.clone(), error));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::input::InputSource;
+    use crate::parse::ParseError;
+    use crate::solution::{load, Answer, Solution};
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const DAY: u32 = 0;
+        type Input = Vec<i64>;
+        type Config = ();
+        type Output1 = i64;
+        type Output2 = String;
+
+        fn parse(input: &str) -> Result<Vec<i64>, ParseError> {
+            return input.lines().enumerate()
+                .map(|(index, line)| line.parse::<i64>().map_err(|_| ParseError::new(index + 1, 1, "a number", line)))
+                .collect::<Result<Vec<i64>, ParseError>>();
+        }
+
+        fn part1(numbers: &Vec<i64>, _: &()) -> i64 {
+            return numbers.iter().sum();
+        }
+
+        fn part2(numbers: &Vec<i64>, _: &()) -> String {
+            return format!("{} numbers", numbers.len());
+        }
+    }
+
+    #[test]
+    fn test_load() {
+        let numbers = load::<Sum>(&InputSource::Text("1\n2\n3".to_string())).unwrap();
+        assert_eq!(Answer::from(Sum::part1(&numbers, &())), Answer::Number(6));
+        assert_eq!(Answer::from(Sum::part2(&numbers, &())), Answer::Text("3 numbers".to_string()));
+        assert!(load::<Sum>(&InputSource::Text("1\nx".to_string())).is_err());
+    }
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Cursor};
+
+use crate::error::Error;
+use crate::input::{InputError, InputSource};
+use crate::params::ParamArgs;
+use crate::parse::{Line, ParseError};
+use crate::solution::{Answer, Solution};
+
+/// Why a streamed input could not be solved: it could not be read, or it is invalid.
+#[derive(Debug)]
+pub enum StreamError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl StreamError {
+    /// The error for the input read from `source`.
+    pub fn with_source(self, source: &InputSource) -> Error {
+        return match self {
+            StreamError::Io(error) => Error::Input(InputError { source: source.clone(), error }),
+            StreamError::Parse(error) => Error::Parse(source.clone(), error),
+        };
+    }
+}
+
+impl From<io::Error> for StreamError {
+    fn from(error: io::Error) -> StreamError {
+        return StreamError::Io(error);
+    }
+}
+
+impl From<ParseError> for StreamError {
+    fn from(error: ParseError) -> StreamError {
+        return StreamError::Parse(error);
+    }
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Io(error) => write!(f, "{}", error),
+            StreamError::Parse(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+/// The lines of a streamed input, read one at a time into the same buffer. Like `input::lines`,
+/// it accepts both `\n` and `\r\n` line endings.
+pub struct Lines<R: BufRead> {
+    reader: R,
+    buffer: String,
+    count: usize,
+}
+
+impl<R: BufRead> Lines<R> {
+    pub fn new(reader: R) -> Lines<R> {
+        return Lines { reader, buffer: String::new(), count: 0 };
+    }
+
+    /// The next line, or `None` at the end of the input. The line is only valid until the next call.
+    pub fn next_line(&mut self) -> Result<Option<Line<'_>>, io::Error> {
+        self.buffer.clear();
+        if self.reader.read_line(&mut self.buffer)? == 0 {
+            return Ok(None);
+        }
+        let text = self.buffer.strip_suffix('\n').unwrap_or(&self.buffer);
+        let text = text.strip_suffix('\r').unwrap_or(text);
+        self.count += 1;
+        return Ok(Some(Line::new(self.count - 1, text)));
+    }
+
+    /// The number of lines read so far.
+    pub fn count(&self) -> usize {
+        return self.count;
+    }
+}
+
+/// A solution that solves both parts in a single pass over the input, keeping only what the algorithm
+/// needs in memory instead of the whole input.
+pub trait Streaming: Solution {
+    /// Read the input and solve both parts, reporting invalid input with the same errors as `parse`.
+    fn solve_stream(reader: &mut dyn BufRead, config: &Self::Config) -> Result<(Self::Output1, Self::Output2), StreamError>;
+}
+
+/// A buffered reader of the input, without reading it into memory.
+pub fn open(source: &InputSource) -> Result<Box<dyn BufRead>, InputError> {
+    return match source {
+        InputSource::File(path) => {
+            let file = fs::File::open(path).map_err(|error| InputError { source: source.clone(), error })?;
+            Ok(Box::new(BufReader::new(file)))
+        }
+        InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+        InputSource::Text(text) => Ok(Box::new(Cursor::new(text.to_owned().into_bytes()))),
+    };
+}
+
+/// Solve both parts of the input in `source` in a single pass.
+pub fn solve_source<S: Streaming>(source: &InputSource, config: &S::Config) -> Result<(Answer, Answer), Error> {
+    let mut reader = open(source)?;
+    let (answer1, answer2) = S::solve_stream(&mut reader, config).map_err(|error| error.with_source(source))?;
+    return Ok((answer1.into(), answer2.into()));
+}
+
+/// Solve both parts of an input given as text in a single pass, e.g. to compare with `Solution`.
+pub fn solve_text<S: Streaming>(text: &str, config: &S::Config) -> Result<(Answer, Answer), StreamError> {
+    let (answer1, answer2) = S::solve_stream(&mut text.as_bytes(), config)?;
+    return Ok((answer1.into(), answer2.into()));
+}
+
+/// Stream the input and print the answers to the requested part, or both parts, like `cli::solve`.
+pub fn solve<S: Streaming>(source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<(), Error> {
+    let config = params.resolve::<S::Config>()?;
+    let (answer1, answer2) = solve_source::<S>(source, &config)?;
+
+    if part != Some(2) {
+        println!("---Task 1---");
+        println!("Solution: {}", answer1);
+    }
+    if part.is_none() {
+        println!();
+    }
+    if part != Some(1) {
+        println!("---Task 2---");
+        println!("Solution: {}", answer2);
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufReader, Read};
+
+    use crate::parse::ParseError;
+    use crate::stream::Lines;
+
+    // A text repeated up to a number of bytes, without keeping them in memory
+    struct Repeat {
+        text: Vec<u8>,
+        position: usize,
+        remaining: usize,
+    }
+
+    impl Read for Repeat {
+        fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+            let mut written = 0;
+            while written < buffer.len() && self.remaining > 0 {
+                buffer[written] = self.text[self.position];
+                self.position = (self.position + 1) % self.text.len();
+                self.remaining -= 1;
+                written += 1;
+            }
+            return Ok(written);
+        }
+    }
+
+    #[test]
+    fn test_lines() {
+        let mut lines = Lines::new("12\r\n\nx 3".as_bytes());
+        assert_eq!(lines.next_line().unwrap().unwrap().text, "12");
+        assert_eq!(lines.next_line().unwrap().unwrap().text, "");
+        let line = lines.next_line().unwrap().unwrap();
+        assert_eq!(line.parse_word::<u32>(0, "a number"), Err(ParseError::new(3, 1, "a number", "x")));
+        assert!(lines.next_line().unwrap().is_none());
+        assert_eq!(lines.count(), 3);
+
+        // Ten megabytes of lines are read through a buffer of a few kilobytes
+        let repeat = Repeat { text: b"1000\n".to_vec(), position: 0, remaining: 10_000_000 };
+        let mut lines = Lines::new(BufReader::new(repeat));
+        let mut sum: u64 = 0;
+        while let Some(line) = lines.next_line().unwrap() {
+            sum += line.parse_word::<u64>(0, "a number").unwrap();
+        }
+        assert_eq!(sum, 2_000_000 * 1000);
+    }
+}
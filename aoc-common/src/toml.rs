@@ -0,0 +1,94 @@
+use crate::parse::{Line, ParseError};
+
+/// The value of a `key = value` line: a quoted string with its escapes resolved, or the bare text of anything else.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum TomlValue<'a> {
+    String(String),
+    Bare(&'a str),
+}
+
+/// A line of the small subset of TOML used by the config and answers files.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum TomlLine<'a> {
+    Section(&'a str),
+    /// `value_start` is the byte offset of the value, to report errors in it
+    Entry { key: &'a str, value: TomlValue<'a>, value_start: usize },
+}
+
+// Parse a TOML basic string starting with the quote at `start`
+fn parse_string(line: &Line, start: usize) -> Result<String, ParseError> {
+    let mut text = String::new();
+    let mut chars = line.text[start + 1..].char_indices();
+    while let Some((index, char)) = chars.next() {
+        match char {
+            '"' => {
+                let rest = line.text[start + index + 2..].trim();
+                if !rest.is_empty() && !rest.starts_with('#') {
+                    return Err(line.error(start + index + 2, "the end of the line"));
+                }
+                return Ok(text);
+            }
+            '\\' => {
+                let escaped = match chars.next() {
+                    Some((_, 'n')) => '\n',
+                    Some((_, 't')) => '\t',
+                    Some((_, '"')) => '"',
+                    Some((_, '\\')) => '\\',
+                    _ => return Err(line.error(start + index + 1, "one of the escapes \\n, \\t, \\\" or \\\\")),
+                };
+                text.push(escaped);
+            }
+            _ => text.push(char),
+        }
+    }
+    return Err(line.error(line.text.len(), "a closing '\"'"));
+}
+
+/// Parse a `[section]` or `key = value` line, where `#` starts a comment. Blank and comment lines give `None`.
+pub fn parse_line<'a>(line: &Line<'a>) -> Result<Option<TomlLine<'a>>, ParseError> {
+    let text = line.text;
+    let content = text.split(" #").next().unwrap().trim_end();
+    if content.trim_start().is_empty() || content.trim_start().starts_with('#') {
+        return Ok(None);
+    }
+
+    if let Some(name) = content.strip_prefix('[') {
+        let name = name.strip_suffix(']').ok_or_else(|| line.error(content.len(), "']'"))?.trim();
+        if name.is_empty() {
+            return Err(line.error(1, "a section name"));
+        }
+        return Ok(Some(TomlLine::Section(name)));
+    }
+
+    let equals = line.find("=", 0)?;
+    let key = text[..equals].trim();
+    let value_start = equals + 1 + (text[equals + 1..].len() - text[equals + 1..].trim_start().len());
+    let value = if text[value_start..].starts_with('"') {
+        TomlValue::String(parse_string(line, value_start)?)
+    } else {
+        TomlValue::Bare(content.get(value_start..).unwrap_or(""))
+    };
+    return Ok(Some(TomlLine::Entry { key, value, value_start }));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{Line, ParseError};
+    use crate::toml::{parse_line, TomlLine, TomlValue};
+
+    #[test]
+    fn test_parse_line() {
+        assert_eq!(parse_line(&Line::new(0, "  # comment")).unwrap(), None);
+        assert_eq!(parse_line(&Line::new(0, "[ input ]")).unwrap(), Some(TomlLine::Section("input")));
+        assert_eq!(parse_line(&Line::new(0, "rounds =  20 # more")).unwrap(),
+                   Some(TomlLine::Entry { key: "rounds", value: TomlValue::Bare("20"), value_start: 10 }));
+        assert_eq!(parse_line(&Line::new(0, "start = \"a \\\"#\\\"\" # quoted")).unwrap(),
+                   Some(TomlLine::Entry { key: "start", value: TomlValue::String("a \"#\"".to_string()), value_start: 8 }));
+        assert_eq!(parse_line(&Line::new(1, "start = \"AA\" BB")).unwrap_err(), ParseError::new(2, 13, "the end of the line", "BB"));
+        assert_eq!(parse_line(&Line::new(0, "[]")).unwrap_err(), ParseError::new(1, 2, "a section name", "]"));
+    }
+}
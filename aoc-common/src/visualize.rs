@@ -0,0 +1,215 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::Error;
+use crate::input::InputSource;
+use crate::params::ParamArgs;
+use crate::solution::{load, Solution};
+
+/// A picture of a simulation at one step, in characters, with x to the right and y downwards.
+pub trait Scene {
+    /// The top left and the bottom right corner of the picture, both included.
+    fn bounds(&self) -> ((i64, i64), (i64, i64));
+    /// Where the action is, which the viewport follows.
+    fn focus(&self) -> (i64, i64);
+    fn cell(&self, x: i64, y: i64) -> char;
+    /// A line above the picture about the step, e.g. the instruction being run.
+    fn caption(&self) -> String;
+}
+
+/// A solution that can show the steps of a part as frames.
+pub trait Visualize: Solution {
+    /// Run the requested part and draw a frame after every step. Stop as soon as `Animation::frame` returns false.
+    fn visualize(input: &Self::Input, config: &Self::Config, part: u32, animation: &mut Animation);
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum FrameStyle {
+    /// Every frame drawn over the previous one with ANSI escape codes, at the frame rate.
+    Terminal,
+    /// Every frame below the previous one as plain text, without waiting, e.g. to review in a file.
+    Text,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct AnimationOptions {
+    /// Frames per second in the terminal, or 0 to draw them as fast as possible
+    pub fps: f64,
+    /// The width and height of the part of the picture that is drawn
+    pub viewport: (usize, usize),
+    /// Stop after this many frames, e.g. for simulations with billions of steps
+    pub max_frames: Option<u64>,
+}
+
+impl Default for AnimationOptions {
+    fn default() -> AnimationOptions {
+        return AnimationOptions { fps: 10.0, viewport: (80, 24), max_frames: None };
+    }
+}
+
+/// Frames written to an output, through a viewport that follows the focus of the scenes.
+pub struct Animation<'a> {
+    output: &'a mut dyn Write,
+    style: FrameStyle,
+    options: AnimationOptions,
+    frame_count: u64,
+    // The top left corner of the viewport
+    position: Option<(i64, i64)>,
+    last_frame: Option<Instant>,
+    error: Option<io::Error>,
+}
+
+// The start of the viewport along one axis: it only moves when the focus comes within a quarter of its size of
+// an edge, and stays within the picture
+fn follow(start: Option<i64>, focus: i64, size: i64, min: i64, max: i64) -> i64 {
+    if max - min < size {
+        return min;
+    }
+    let margin = size / 4;
+    let mut start = start.unwrap_or(focus - size / 2);
+    if focus < start + margin {
+        start = focus - margin;
+    }
+    if focus > start + size - 1 - margin {
+        start = focus - (size - 1 - margin);
+    }
+    return start.clamp(min, max - size + 1);
+}
+
+impl<'a> Animation<'a> {
+    pub fn new(output: &'a mut dyn Write, style: FrameStyle, options: AnimationOptions) -> Animation<'a> {
+        return Animation { output, style, options, frame_count: 0, position: None, last_frame: None, error: None };
+    }
+
+    /// Draw the scene as the next frame. False if the animation is over, because it has drawn
+    /// the most frames it may or could not write them.
+    pub fn frame(&mut self, scene: &dyn Scene) -> bool {
+        if self.error.is_some() || self.options.max_frames.is_some_and(|max_frames| self.frame_count >= max_frames) {
+            return false;
+        }
+        let text = self.render(scene);
+
+        if let (FrameStyle::Terminal, Some(last_frame)) = (self.style, self.last_frame) {
+            if self.options.fps > 0.0 {
+                let wait = Duration::from_secs_f64(1.0 / self.options.fps).saturating_sub(last_frame.elapsed());
+                thread::sleep(wait);
+            }
+        }
+        if let Err(error) = self.output.write_all(text.as_bytes()).and_then(|_| self.output.flush()) {
+            self.error = Some(error);
+            return false;
+        }
+        self.last_frame = Some(Instant::now());
+        self.frame_count += 1;
+        return true;
+    }
+
+    fn render(&mut self, scene: &dyn Scene) -> String {
+        let ((min_x, min_y), (max_x, max_y)) = scene.bounds();
+        let (focus_x, focus_y) = scene.focus();
+        let (width, height) = (self.options.viewport.0 as i64, self.options.viewport.1 as i64);
+        let left = follow(self.position.map(|position| position.0), focus_x, width, min_x, max_x);
+        let top = follow(self.position.map(|position| position.1), focus_y, height, min_y, max_y);
+        self.position = Some((left, top));
+
+        let rows = (top..=max_y.min(top + height - 1)).map(|y| {
+            return (left..=max_x.min(left + width - 1)).map(|x| {
+                let cell = scene.cell(x, y);
+                return if self.style == FrameStyle::Terminal && (x, y) == (focus_x, focus_y) {
+                    format!("\x1b[1;33m{}\x1b[0m", cell)
+                } else {
+                    cell.to_string()
+                };
+            }).collect::<String>();
+        }).collect::<Vec<String>>();
+
+        return match self.style {
+            FrameStyle::Terminal => {
+                // Go to the top left corner and overwrite the previous frame, clearing what is left of it
+                let clear = if self.frame_count == 0 { "\x1b[2J" } else { "" };
+                let lines = rows.iter().map(|row| format!("{}\x1b[K\n", row)).collect::<String>();
+                format!("{}\x1b[H{}\x1b[K\n{}\x1b[J", clear, scene.caption(), lines)
+            }
+            FrameStyle::Text => {
+                let lines = rows.iter().map(|row| format!("{}\n", row.trim_end())).collect::<String>();
+                format!("Frame {}: {}\n{}\n", self.frame_count + 1, scene.caption(), lines)
+            }
+        };
+    }
+
+    pub fn frame_count(&self) -> u64 {
+        return self.frame_count;
+    }
+
+    /// The number of frames drawn, or why they could not be written.
+    pub fn finish(self) -> io::Result<u64> {
+        return match self.error {
+            Some(error) => Err(error),
+            None => Ok(self.frame_count),
+        };
+    }
+}
+
+/// Load the input and draw the frames of a part, see `Visualize`.
+pub fn visualize<S: Visualize>(source: &InputSource, part: u32, params: &ParamArgs, animation: &mut Animation) -> Result<(), Error> {
+    let config = params.resolve::<S::Config>()?;
+    let input = load::<S>(source)?;
+    S::visualize(&input, &config, part, animation);
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::visualize::{Animation, AnimationOptions, FrameStyle, Scene};
+
+    // A dot moving right along a line
+    struct Line {
+        position: i64,
+    }
+
+    impl Scene for Line {
+        fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+            return ((0, 0), (9, 0));
+        }
+
+        fn focus(&self) -> (i64, i64) {
+            return (self.position, 0);
+        }
+
+        fn cell(&self, x: i64, _: i64) -> char {
+            return if x == self.position { 'o' } else { '.' };
+        }
+
+        fn caption(&self) -> String {
+            return format!("x={}", self.position);
+        }
+    }
+
+    #[test]
+    fn test_animation() {
+        let mut output = Vec::new();
+        let options = AnimationOptions { fps: 0.0, viewport: (4, 3), max_frames: Some(4) };
+        let mut animation = Animation::new(&mut output, FrameStyle::Text, options);
+        let mut position = 0;
+        while animation.frame(&Line { position }) {
+            position += 3;
+        }
+        assert_eq!(animation.finish().unwrap(), 4);
+
+        // The viewport moves along when the dot comes near its right edge, and stops at the end of the line
+        let frames = String::from_utf8(output).unwrap();
+        let pictures = frames.lines().filter(|line| !line.starts_with("Frame") && !line.is_empty()).collect::<Vec<&str>>();
+        assert_eq!(pictures, vec!["o...", "..o.", "..o.", "...o"]);
+        assert!(frames.starts_with("Frame 1: x=0\no...\n\nFrame 2: x=3\n"));
+
+        let mut output = Vec::new();
+        let mut animation = Animation::new(&mut output, FrameStyle::Terminal, AnimationOptions { fps: 0.0, ..AnimationOptions::default() });
+        animation.frame(&Line { position: 3 });
+        assert_eq!(String::from_utf8(output).unwrap(), "\x1b[2J\x1b[Hx=3\x1b[K\n...\x1b[1;33mo\x1b[0m......\x1b[K\n\x1b[J");
+    }
+}
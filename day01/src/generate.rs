@@ -0,0 +1,35 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day01};
+
+impl Generator for Day01 {
+    /// `size` elves carrying 1 to 8 snacks each.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let elves = (0..size.max(3))
+            .map(|_| {
+                let snack_count = rng.range(1..=8);
+                (0..snack_count).map(|_| rng.range(1000..=60000u32).to_string()).collect::<Vec<String>>().join("\n")
+            })
+            .collect::<Vec<String>>();
+        return (elves.join("\n\n") + "\n", Config::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day01;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day01>(1, 100);
+        assert_eq!(input, generate::<Day01>(1, 100));
+        assert_eq!(input.text.split("\n\n").count(), 100);
+        for seed in 0..20 {
+            let (most, top_three) = solve_generated::<Day01>(seed, 10);
+            assert!(matches!((most, top_three), (Answer::Number(most), Answer::Number(top_three)) if most < top_three));
+        }
+    }
+}
@@ -0,0 +1,202 @@
+mod generate;
+mod stream;
+
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use aoc_common::{input, params};
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+/// The calories of the snacks an elf is carrying, in the order of the input.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Elf {
+    pub snacks: Vec<u64>,
+}
+
+impl Elf {
+    /// The calories of all snacks. Parsing checks that the calories of all elves together fit in 64 bits.
+    pub fn total_calories(&self) -> u64 {
+        return self.snacks.iter().fold(0u64, |total, calories| total.checked_add(*calories).expect("The calories of an elf do not fit in 64 bits"));
+    }
+}
+
+/// An elf among those carrying the most calories, with its 0-based index in the input.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct RankedElf {
+    pub index: usize,
+    pub calories: u64,
+}
+
+/// The `k` elves carrying the most calories of those added so far, kept in a heap of at most `k` elves
+/// so that the elves do not have to be sorted or even kept in memory. The heap grows with the elves
+/// added, so `k` can be far larger than their number.
+pub struct TopK {
+    k: usize,
+    // The lowest ranked elf is on top, and of equal calories the one that came last
+    heap: BinaryHeap<Reverse<(u64, Reverse<usize>)>>,
+}
+
+impl TopK {
+    pub fn new(k: usize) -> TopK {
+        return TopK { k, heap: BinaryHeap::new() };
+    }
+
+    pub fn add(&mut self, index: usize, calories: u64) {
+        self.heap.push(Reverse((calories, Reverse(index))));
+        if self.heap.len() > self.k {
+            self.heap.pop();
+        }
+    }
+
+    /// The elves from the most calories to the least, and of equal calories in the order of the input.
+    pub fn into_ranking(self) -> Vec<RankedElf> {
+        return self.heap.into_sorted_vec().into_iter()
+            .map(|Reverse((calories, Reverse(index)))| RankedElf { index, calories })
+            .collect();
+    }
+}
+
+// The calories of a snack, or None for the blank line between two elves. Whitespace around the number,
+// such as the '\r' of Windows line endings, is ignored. The calories of all snacks so far are added to
+// `total`, which must stay within 64 bits so that adding up any elves cannot overflow.
+fn parse_calories(line: &Line, total: &mut u64) -> Result<Option<u64>, ParseError> {
+    let start = line.text.len() - line.text.trim_start().len();
+    let end = line.text.trim_end().len();
+    if start >= end {
+        return Ok(None);
+    }
+    if line.text[start..end].starts_with('-') {
+        return Err(line.error_span(start, end, "a calorie count of at least 0"));
+    }
+    let calories = line.parse::<u64>(start, end, "a calorie count below 2^64")?;
+    *total = total.checked_add(calories)
+        .ok_or_else(|| line.error_span(start, end, "a calorie count that keeps the total of all elves below 2^64"))?;
+    return Ok(Some(calories));
+}
+
+/// The elves separated by blank lines, which may also be lines of whitespace. Elves without snacks, e.g.
+/// between two blank lines or after the last one, are left out.
+pub fn parse(lines: &Vec<String>) -> Result<Vec<Elf>, ParseError> {
+    let mut elves = Vec::new();
+    let mut snacks = Vec::new();
+    let mut total = 0;
+    for (line_index, line) in lines.iter().enumerate() {
+        match parse_calories(&Line::new(line_index, line), &mut total)? {
+            Some(calories) => snacks.push(calories),
+            None if !snacks.is_empty() => elves.push(Elf { snacks: std::mem::take(&mut snacks) }),
+            None => (),
+        }
+    }
+    if !snacks.is_empty() {
+        elves.push(Elf { snacks });
+    }
+    return Ok(elves);
+}
+
+/// The `k` elves carrying the most calories, from the most to the least.
+pub fn top_k(elves: &Vec<Elf>, k: usize) -> Vec<RankedElf> {
+    let mut top = TopK::new(k);
+    for (index, elf) in elves.iter().enumerate() {
+        top.add(index, elf.total_calories());
+    }
+    return top.into_ranking();
+}
+
+/// A table of the `k` elves carrying the most calories, numbered from 1 like in the input, and their total.
+pub fn ranked_report(elves: &Vec<Elf>, k: usize) -> String {
+    let ranking = top_k(elves, k);
+    let mut report = format!("{:>4}  {:>5}  {:>6}  {:>8}\n", "Rank", "Elf", "Snacks", "Calories");
+    for (rank, elf) in ranking.iter().enumerate() {
+        report += &format!("{:>4}  {:>5}  {:>6}  {:>8}\n", rank + 1, elf.index + 1, elves[elf.index].snacks.len(), elf.calories);
+    }
+    let total = ranking.iter().map(|elf| elf.calories).sum::<u64>();
+    report += &format!("The top {} of {} elves carry {} calories", ranking.len(), elves.len(), total);
+    return report;
+}
+
+pub fn solve_1(elves: &Vec<Elf>) -> u64 {
+    return top_k(elves, 1).first().map_or(0, |elf| elf.calories);
+}
+
+pub fn solve_2(elves: &Vec<Elf>, top_count: usize) -> u64 {
+    return top_k(elves, top_count).iter().map(|elf| elf.calories).sum();
+}
+
+params! {
+    pub struct Config {
+        /// The number of elves carrying the most calories that are added up in task 2
+        top_count: usize = 3,
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u32 = 1;
+    type Input = Vec<Elf>;
+    type Config = Config;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Elf>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(elves: &Vec<Elf>, _: &Config) -> u64 {
+        return solve_1(elves);
+    }
+
+    fn part2(elves: &Vec<Elf>, config: &Config) -> u64 {
+        return solve_2(elves, config.top_count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::ParseError;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day01, Elf, RankedElf, ranked_report, solve_1, solve_2, top_k};
+
+    #[test]
+    fn test_1() {
+        let elves = load::<Day01>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&elves), 24000);
+    }
+
+    #[test]
+    fn test_2() {
+        let elves = load::<Day01>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&elves, 3), 45000);
+        assert_eq!(solve_2(&elves, 10), 55000);
+        assert_eq!(solve_2(&elves, usize::MAX), 55000);
+    }
+
+    #[test]
+    fn test_top_k() {
+        let elves = load::<Day01>(&day_input!("test.txt")).unwrap();
+        assert_eq!(top_k(&elves, 2), vec![RankedElf { index: 3, calories: 24000 }, RankedElf { index: 2, calories: 11000 }]);
+        assert_eq!(top_k(&elves, 0), vec![]);
+        assert_eq!(ranked_report(&elves, 3), "Rank    Elf  Snacks  Calories\n   1      4       3     24000\n   2      3       2     11000\n\
+            \x20  3      5       1     10000\nThe top 3 of 5 elves carry 45000 calories");
+    }
+
+    #[test]
+    fn test_parse() {
+        let elves = Day01::parse("1000\r\n 2000 \r\n\r\n\r\n\t3000\r\n\n  \n").unwrap();
+        assert_eq!(elves, vec![Elf { snacks: vec![1000, 2000] }, Elf { snacks: vec![3000] }]);
+        let elves = Day01::parse("18446744073709551000\n\n615\n").unwrap();
+        assert_eq!(solve_2(&elves, 2), u64::MAX);
+
+        assert_eq!(Day01::parse("1000\n\n -200\n").unwrap_err(), ParseError::new(3, 2, "a calorie count of at least 0", "-200"));
+        assert_eq!(Day01::parse("1000\n20 00\n").unwrap_err(), ParseError::new(2, 1, "a calorie count below 2^64", "20 00"));
+        let error = Day01::parse("18446744073709551000\n\n616\n").unwrap_err();
+        assert_eq!(error, ParseError::new(3, 1, "a calorie count that keeps the total of all elves below 2^64", "616"));
+    }
+}
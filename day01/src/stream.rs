@@ -0,0 +1,61 @@
+use std::io::BufRead;
+
+use aoc_common::stream::{Lines, StreamError, Streaming};
+
+use crate::{parse_calories, Config, Day01, TopK};
+
+impl Streaming for Day01 {
+    // Only the elves with the most calories are kept, the others are forgotten after their last snack
+    fn solve_stream(reader: &mut dyn BufRead, config: &Config) -> Result<(u64, u64), StreamError> {
+        let mut lines = Lines::new(reader);
+        let mut top = TopK::new(config.top_count.max(1));
+        let mut elf_count = 0;
+        let mut current_calories = None;
+        let mut total = 0;
+
+        while let Some(line) = lines.next_line()? {
+            match (parse_calories(&line, &mut total)?, current_calories) {
+                (Some(calories), _) => current_calories = Some(current_calories.unwrap_or(0) + calories),
+                (None, Some(calories)) => {
+                    top.add(elf_count, calories);
+                    elf_count += 1;
+                    current_calories = None;
+                }
+                (None, None) => (),
+            }
+        }
+        if let Some(calories) = current_calories {
+            top.add(elf_count, calories);
+        }
+
+        let ranking = top.into_ranking();
+        let most_calories = ranking.first().map_or(0, |elf| elf.calories);
+        return Ok((most_calories, ranking.iter().take(config.top_count).map(|elf| elf.calories).sum()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::{Config, Day01};
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_text::<Day01>(&text, &Config::default()).unwrap(), (Answer::Number(24000), Answer::Number(45000)));
+        assert_eq!(solve_text::<Day01>(&text, &Config { top_count: 4 }).unwrap(), (Answer::Number(24000), Answer::Number(51000)));
+        assert_eq!(solve_text::<Day01>(&text, &Config { top_count: usize::MAX }).unwrap(), (Answer::Number(24000), Answer::Number(55000)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day01>(&generate::<Day01>(seed, 50).text, &Config::default()).unwrap(), solve_generated::<Day01>(seed, 50));
+        }
+        for invalid_input in ["1000\n\n2x00\n", "18446744073709551000\r\n\r\n616\r\n"] {
+            let error = solve_text::<Day01>(invalid_input, &Config::default()).unwrap_err();
+            assert_eq!(error.to_string(), Day01::parse(invalid_input).unwrap_err().to_string());
+        }
+    }
+}
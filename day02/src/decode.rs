@@ -0,0 +1,163 @@
+use std::cmp::Reverse;
+
+use aoc_common::parse::Line;
+
+use crate::{parse_columns, GuideError, GuideErrorKind, Hand, Outcome, Round};
+
+/// What the second column of the strategy guide is read as.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum Meaning {
+    Hands,
+    Outcomes,
+}
+
+/// The total score of the strategy guide when X, Y and Z are read as a permutation of the hands or outcomes.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Decoding {
+    pub meaning: Meaning,
+    /// What X, Y and Z stand for, as indices into `Hand::ALL` or `Outcome::ALL`
+    pub targets: [usize; 3],
+    pub total_score: u64,
+}
+
+impl Decoding {
+    /// Whether this is how the puzzle reads the guide, X, Y and Z in the order of `Hand::ALL` or `Outcome::ALL`.
+    pub fn is_puzzle(&self) -> bool {
+        return self.targets == [0, 1, 2];
+    }
+
+    /// The name of what the letter at `index` of X, Y and Z stands for.
+    pub fn target(&self, index: usize) -> String {
+        return match self.meaning {
+            Meaning::Hands => format!("{:?}", Hand::ALL[self.targets[index]]),
+            Meaning::Outcomes => format!("{:?}", Outcome::ALL[self.targets[index]]),
+        };
+    }
+}
+
+/// The best, worst and average total score over every permutation of one meaning.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Summary {
+    pub meaning: Meaning,
+    pub best: Decoding,
+    pub worst: Decoding,
+    /// The total score when the permutation is picked at random
+    pub expected: f64,
+}
+
+/// The six ways to assign three things to X, Y and Z, in lexicographic order, so the puzzle's `[0, 1, 2]` comes first.
+pub fn permutations() -> Vec<[usize; 3]> {
+    let mut permutations = Vec::new();
+    for x in 0..3 {
+        for y in (0..3).filter(|y| *y != x) {
+            permutations.push([x, y, 3 - x - y]);
+        }
+    }
+    return permutations;
+}
+
+// How often every opponent hand is in the guide with every letter of the second column
+fn count_rounds(lines: &Vec<String>) -> Result<[[u64; 3]; 3], GuideError> {
+    let mut counts = [[0; 3]; 3];
+    for (line_index, text) in lines.iter().enumerate() {
+        let line = Line::new(line_index, text);
+        let (opponent, letter) = parse_columns(&line, "ABC", "XYZ", GuideErrorKind::SecondColumn)?;
+        counts[opponent][letter] += 1;
+    }
+    return Ok(counts);
+}
+
+/// Score the guide under every reading of X, Y and Z as hands and as outcomes, from the highest total score
+/// to the lowest. Equal totals keep hands before outcomes and the puzzle's order first.
+pub fn decode(lines: &Vec<String>) -> Result<Vec<Decoding>, GuideError> {
+    let counts = count_rounds(lines)?;
+    let mut decodings = Vec::new();
+    for meaning in [Meaning::Hands, Meaning::Outcomes] {
+        for targets in permutations() {
+            let mut total_score = 0;
+            for (opponent, letter_counts) in Hand::ALL.iter().zip(counts) {
+                for (letter, count) in letter_counts.iter().enumerate() {
+                    let yours = match meaning {
+                        Meaning::Hands => Hand::ALL[targets[letter]],
+                        Meaning::Outcomes => Outcome::ALL[targets[letter]].hand_against(*opponent),
+                    };
+                    total_score += count * Round { opponent: *opponent, yours }.score() as u64;
+                }
+            }
+            decodings.push(Decoding { meaning, targets, total_score });
+        }
+    }
+    decodings.sort_by_key(|decoding| Reverse(decoding.total_score));
+    return Ok(decodings);
+}
+
+/// The best, worst and expected total score of `meaning`. When permutations tie for the best or the worst, the
+/// one that comes first in `permutations()` is picked, as in the order of `decode`.
+pub fn summarize(decodings: &Vec<Decoding>, meaning: Meaning) -> Summary {
+    let of_meaning = decodings.iter().filter(|decoding| decoding.meaning == meaning).collect::<Vec<&Decoding>>();
+    let best = of_meaning.iter().max_by_key(|decoding| (decoding.total_score, Reverse(decoding.targets))).expect("Every meaning has permutations");
+    let worst = of_meaning.iter().min_by_key(|decoding| (decoding.total_score, decoding.targets)).unwrap();
+    let expected = of_meaning.iter().map(|decoding| decoding.total_score as f64).sum::<f64>() / of_meaning.len() as f64;
+    return Summary { meaning, best: (*best).clone(), worst: (*worst).clone(), expected };
+}
+
+/// A table of every reading of the guide by total score, and the best, worst and expected total score of each meaning.
+pub fn decode_report(decodings: &Vec<Decoding>) -> String {
+    let mut report = format!("{:<9} {:<9} {:<9} {:<9} {:>6}\n", "Meaning", "X", "Y", "Z", "Score");
+    for decoding in decodings {
+        let puzzle = if decoding.is_puzzle() { "  (puzzle)" } else { "" };
+        report += &format!("{:<9} {:<9} {:<9} {:<9} {:>6}{}\n", format!("{:?}", decoding.meaning), decoding.target(0),
+                           decoding.target(1), decoding.target(2), decoding.total_score, puzzle);
+    }
+    for meaning in [Meaning::Hands, Meaning::Outcomes] {
+        let summary = summarize(decodings, meaning);
+        report += &format!("\nAs {:?}: best {}, worst {}, expected {:.1}", meaning, summary.best.total_score,
+                           summary.worst.total_score, summary.expected);
+    }
+    return report;
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input::lines;
+
+    use crate::decode::{decode, decode_report, permutations, summarize, Meaning};
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(permutations(), vec![[0, 1, 2], [0, 2, 1], [1, 0, 2], [1, 2, 0], [2, 0, 1], [2, 1, 0]]);
+
+        let decodings = decode(&lines("A Y\nB X\nC Z\n")).unwrap();
+        assert_eq!(decodings.len(), 12);
+        let puzzle = decodings.iter().filter(|decoding| decoding.is_puzzle()).map(|decoding| decoding.total_score).collect::<Vec<u64>>();
+        assert_eq!(puzzle, vec![15, 12]);
+
+        let hands = summarize(&decodings, Meaning::Hands);
+        assert_eq!((hands.best.total_score, hands.worst.total_score, hands.expected), (24, 6, 15.0));
+        // Scissors, paper and rock win every round
+        assert_eq!(hands.best.targets, [2, 1, 0]);
+        let outcomes = summarize(&decodings, Meaning::Outcomes);
+        assert_eq!((outcomes.best.total_score, outcomes.worst.total_score, outcomes.expected), (18, 12, 15.0));
+        assert!(outcomes.worst.is_puzzle());
+
+        let report = decode_report(&decodings);
+        assert!(report.starts_with("Meaning   X         Y         Z          Score\nHands     Scissors  Paper     Rock          24\n"));
+        assert!(report.ends_with("As Hands: best 24, worst 6, expected 15.0\nAs Outcomes: best 18, worst 12, expected 15.0"));
+
+        // Rock against rock ties two permutations for the best and two for the worst, and the first of each is picked
+        let decodings = decode(&lines("A X\n")).unwrap();
+        let hands = summarize(&decodings, Meaning::Hands);
+        assert_eq!((hands.best.targets, hands.worst.targets), ([1, 0, 2], [2, 0, 1]));
+        let outcomes = summarize(&decodings, Meaning::Outcomes);
+        assert_eq!((outcomes.best.targets, outcomes.worst.targets), ([2, 0, 1], [0, 1, 2]));
+
+        let error = decode(&lines("A Y\nB W\n")).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 3: expected the second column, X, Y or Z, found 'W'");
+    }
+}
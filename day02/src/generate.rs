@@ -0,0 +1,35 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day02;
+
+impl Generator for Day02 {
+    /// `size` rounds of the strategy guide.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut text = String::new();
+        for _ in 0..size {
+            text += &format!("{} {}\n", rng.choose(&['A', 'B', 'C']), rng.choose(&['X', 'Y', 'Z']));
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day02;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day02>(1, 100);
+        assert_eq!(input, generate::<Day02>(1, 100));
+        assert_eq!(input.text.lines().count(), 100);
+        for seed in 0..20 {
+            // Every round scores between 1 and 9 points
+            let (score_1, score_2) = solve_generated::<Day02>(seed, 50);
+            assert!(matches!(score_1, Answer::Number(50..=450)));
+            assert!(matches!(score_2, Answer::Number(50..=450)));
+        }
+    }
+}
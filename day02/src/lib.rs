@@ -0,0 +1,313 @@
+mod generate;
+mod stream;
+
+pub mod decode;
+pub mod rules;
+
+use std::fmt;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum Hand {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl Hand {
+    pub const ALL: [Hand; 3] = [Hand::Rock, Hand::Paper, Hand::Scissors];
+
+    /// The hand that this hand beats.
+    pub fn beats(self) -> Hand {
+        return match self {
+            Hand::Rock => Hand::Scissors,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissors => Hand::Paper,
+        };
+    }
+
+    /// The hand that beats this hand.
+    pub fn loses_to(self) -> Hand {
+        return match self {
+            Hand::Rock => Hand::Paper,
+            Hand::Paper => Hand::Scissors,
+            Hand::Scissors => Hand::Rock,
+        };
+    }
+
+    pub fn score(self) -> u32 {
+        return match self {
+            Hand::Rock => 1,
+            Hand::Paper => 2,
+            Hand::Scissors => 3,
+        };
+    }
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    /// The outcome of playing `yours` against `opponent`.
+    pub fn of(yours: Hand, opponent: Hand) -> Outcome {
+        if yours.beats() == opponent {
+            return Outcome::Win;
+        }
+        return if yours == opponent { Outcome::Draw } else { Outcome::Loss };
+    }
+
+    /// The hand to play against `opponent` to get this outcome.
+    pub fn hand_against(self, opponent: Hand) -> Hand {
+        return match self {
+            Outcome::Loss => opponent.beats(),
+            Outcome::Draw => opponent,
+            Outcome::Win => opponent.loses_to(),
+        };
+    }
+
+    pub fn score(self) -> u32 {
+        return match self {
+            Outcome::Loss => 0,
+            Outcome::Draw => 3,
+            Outcome::Win => 6,
+        };
+    }
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub struct Round {
+    pub opponent: Hand,
+    pub yours: Hand,
+}
+
+impl Round {
+    pub fn outcome(&self) -> Outcome {
+        return Outcome::of(self.yours, self.opponent);
+    }
+
+    /// The score of your hand and the outcome.
+    pub fn score(&self) -> u32 {
+        return self.yours.score() + self.outcome().score();
+    }
+}
+
+/// The rounds of the strategy guide, read the way of each part.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct StrategyGuide {
+    /// Task 1: the second column is your hand
+    pub by_hand: Vec<Round>,
+    /// Task 2: the second column is the outcome to get
+    pub by_outcome: Vec<Round>,
+}
+
+/// What is wrong with a line of the strategy guide.
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum GuideErrorKind {
+    /// The first column is not A, B or C
+    OpponentHand,
+    /// The second column is not X, Y or Z, read as your hand
+    YourHand,
+    /// The second column is not X, Y or Z, read as the outcome
+    Outcome,
+    /// The second column is not X, Y or Z, before deciding what it means
+    SecondColumn,
+    /// There is more on the line than the two columns
+    TrailingText,
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct GuideError {
+    pub kind: GuideErrorKind,
+    /// The 1-based line and column of the problem
+    pub line: usize,
+    pub column: usize,
+    pub found: String,
+    /// The letters that the column can have, e.g. "ABC"
+    pub letters: String,
+}
+
+impl GuideError {
+    pub fn expected(&self) -> String {
+        let letters = self.letters.chars().map(|letter| letter.to_string()).collect::<Vec<String>>();
+        let choices = match letters.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => letters.join(""),
+        };
+        return match self.kind {
+            GuideErrorKind::OpponentHand => format!("the opponent's hand, {}", choices),
+            GuideErrorKind::YourHand => format!("your hand, {}", choices),
+            GuideErrorKind::Outcome => format!("the outcome, {}", choices),
+            GuideErrorKind::SecondColumn => format!("the second column, {}", choices),
+            GuideErrorKind::TrailingText => "the end of the line".to_string(),
+        };
+    }
+}
+
+impl fmt::Display for GuideError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{}", ParseError::from(self.clone()));
+    }
+}
+
+impl std::error::Error for GuideError {}
+
+impl From<GuideError> for ParseError {
+    fn from(error: GuideError) -> ParseError {
+        return ParseError::new(error.line, error.column, &error.expected(), &error.found);
+    }
+}
+
+// The letter in the word at `index` of the line, which must be one of `letters`, as its index in `letters`
+fn parse_letter(line: &Line, index: usize, letters: &str, kind: GuideErrorKind) -> Result<usize, GuideError> {
+    let words = line.words();
+    let error = |column: usize, found: &str| GuideError { kind, line: line.number, column, found: found.to_string(), letters: letters.to_string() };
+    let Some(word) = words.get(index) else {
+        return Err(error(line.text.len() + 1, ""));
+    };
+    let mut chars = word.text.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(letter), None) if letters.contains(letter) => Ok(letters.find(letter).unwrap()),
+        _ => Err(error(word.start + 1, word.text)),
+    };
+}
+
+// The indices of the letters of both columns in `opponent_letters` and `second_letters`
+fn parse_columns(line: &Line, opponent_letters: &str, second_letters: &str, second_column: GuideErrorKind) -> Result<(usize, usize), GuideError> {
+    let opponent = parse_letter(line, 0, opponent_letters, GuideErrorKind::OpponentHand)?;
+    let second = parse_letter(line, 1, second_letters, second_column)?;
+    if let Some(word) = line.words().get(2) {
+        let found = line.text[word.start..].to_string();
+        return Err(GuideError { kind: GuideErrorKind::TrailingText, line: line.number, column: word.start + 1, found, letters: String::new() });
+    }
+    return Ok((opponent, second));
+}
+
+/// Read a round for task 1, where X, Y and Z are rock, paper and scissors.
+pub fn parse_round_by_hand(line: &Line) -> Result<Round, GuideError> {
+    let (opponent, second) = parse_columns(line, "ABC", "XYZ", GuideErrorKind::YourHand)?;
+    return Ok(Round { opponent: Hand::ALL[opponent], yours: Hand::ALL[second] });
+}
+
+/// Read a round for task 2, where X, Y and Z mean that you need to lose, draw or win.
+pub fn parse_round_by_outcome(line: &Line) -> Result<Round, GuideError> {
+    let (opponent, second) = parse_columns(line, "ABC", "XYZ", GuideErrorKind::Outcome)?;
+    let opponent = Hand::ALL[opponent];
+    return Ok(Round { opponent, yours: Outcome::ALL[second].hand_against(opponent) });
+}
+
+/// Read a round for both tasks, so a wrong letter in the second column is reported as neither a hand nor an outcome.
+pub fn parse_round_both_ways(line: &Line) -> Result<(Round, Round), GuideError> {
+    let (opponent, second) = parse_columns(line, "ABC", "XYZ", GuideErrorKind::SecondColumn)?;
+    let opponent = Hand::ALL[opponent];
+    let by_hand = Round { opponent, yours: Hand::ALL[second] };
+    let by_outcome = Round { opponent, yours: Outcome::ALL[second].hand_against(opponent) };
+    return Ok((by_hand, by_outcome));
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<StrategyGuide, GuideError> {
+    let mut guide = StrategyGuide { by_hand: Vec::new(), by_outcome: Vec::new() };
+    for (line_index, text) in lines.iter().enumerate() {
+        let (by_hand, by_outcome) = parse_round_both_ways(&Line::new(line_index, text))?;
+        guide.by_hand.push(by_hand);
+        guide.by_outcome.push(by_outcome);
+    }
+    return Ok(guide);
+}
+
+/// The total score of the rounds, in 64 bits as the guides read by streaming can be gigabytes long.
+pub fn total_score(rounds: &Vec<Round>) -> u64 {
+    return rounds.iter().map(|round| round.score() as u64).sum();
+}
+
+pub fn solve_1(guide: &StrategyGuide) -> u64 {
+    return total_score(&guide.by_hand);
+}
+
+pub fn solve_2(guide: &StrategyGuide) -> u64 {
+    return total_score(&guide.by_outcome);
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u32 = 2;
+    type Input = StrategyGuide;
+    type Config = ();
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<StrategyGuide, ParseError> {
+        return Ok(parse(&input::lines(input))?);
+    }
+
+    fn part1(guide: &StrategyGuide, _: &()) -> u64 {
+        return solve_1(guide);
+    }
+
+    fn part2(guide: &StrategyGuide, _: &()) -> u64 {
+        return solve_2(guide);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::Line;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day02, GuideError, GuideErrorKind, Hand, Outcome, parse_round_by_hand, parse_round_by_outcome, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let rounds = load::<Day02>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&rounds), 15);
+    }
+
+    #[test]
+    fn test_2() {
+        let rounds = load::<Day02>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&rounds), 12);
+    }
+
+    #[test]
+    fn test_rules() {
+        for hand in Hand::ALL {
+            assert_eq!(hand.beats().loses_to(), hand);
+            for outcome in Outcome::ALL {
+                assert_eq!(Outcome::of(outcome.hand_against(hand), hand), outcome);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let line = Line::new(1, "B W");
+        let error = GuideError { kind: GuideErrorKind::YourHand, line: 2, column: 3, found: "W".to_string(), letters: "XYZ".to_string() };
+        assert_eq!(parse_round_by_hand(&line), Err(error));
+        assert_eq!(parse_round_by_outcome(&line).unwrap_err().to_string(), "line 2, column 3: expected the outcome, X, Y or Z, found 'W'");
+        assert_eq!(parse_round_by_hand(&Line::new(0, "A")).unwrap_err().column, 2);
+        assert_eq!(parse_round_by_hand(&Line::new(0, "A Y Z")).unwrap_err().kind, GuideErrorKind::TrailingText);
+        assert_eq!(Day02::parse("A Y\nB W\n").unwrap_err().to_string(), "line 2, column 3: expected the second column, X, Y or Z, found 'W'");
+    }
+}
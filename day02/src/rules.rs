@@ -0,0 +1,328 @@
+use std::cmp::Reverse;
+use std::fmt;
+
+use aoc_common::parse::{Line, ParseError, Word};
+
+use crate::{parse_columns, GuideError, GuideErrorKind, Hand, Outcome};
+
+/// A shape of the game, with its letters in the strategy guide and the score for playing it.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Shape {
+    pub name: String,
+    pub opponent_letter: char,
+    pub your_letter: char,
+    pub score: u32,
+}
+
+/// The rules of a game like rock paper scissors: its shapes, which shape beats which, and the letter and
+/// score of every outcome. Shapes are referred to by their index in `shapes`.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct RuleTable {
+    pub shapes: Vec<Shape>,
+    // The outcome of playing the first shape against the second
+    outcomes: Vec<Vec<Outcome>>,
+    /// The letters and scores of a loss, a draw and a win
+    pub outcome_letters: [char; 3],
+    pub outcome_scores: [u32; 3],
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub enum RuleError {
+    /// A line that is not a shape, an outcome or a relation between two shapes
+    Syntax(ParseError),
+    /// A shape name or letter that is used twice
+    Duplicate { line: usize, name: String },
+    /// A pair of shapes that is given twice, e.g. both shapes beat each other, or a shape that beats itself
+    Conflict { first: String, second: String },
+    /// A pair of shapes with neither a winner nor a draw
+    Undecided { first: String, second: String },
+    MissingOutcome(Outcome),
+    /// No shape gets the outcome against a shape, so the strategy guide cannot ask for it
+    Unreachable { opponent: String, outcome: Outcome },
+}
+
+impl fmt::Display for RuleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RuleError::Syntax(error) => write!(f, "{}", error),
+            RuleError::Duplicate { line, name } => write!(f, "line {}: {} is already used by another shape or outcome", line, name),
+            RuleError::Conflict { first, second } if first == second => write!(f, "{} always draws against itself", first),
+            RuleError::Conflict { first, second } => write!(f, "The outcome of {} against {} is given twice", first, second),
+            RuleError::Undecided { first, second } => write!(f, "Neither {} nor {} wins, and they do not draw", first, second),
+            RuleError::MissingOutcome(outcome) => write!(f, "The letter and score of a {:?} are missing", outcome),
+            RuleError::Unreachable { opponent, outcome } => write!(f, "No shape gets a {:?} against {}", outcome, opponent),
+        }
+    }
+}
+
+impl std::error::Error for RuleError {}
+
+impl From<ParseError> for RuleError {
+    fn from(error: ParseError) -> RuleError {
+        return RuleError::Syntax(error);
+    }
+}
+
+// A word of a single character
+fn parse_letter(line: &Line, index: usize, expected: &str) -> Result<char, ParseError> {
+    let word = line.word(index, expected)?;
+    let mut chars = word.text.chars();
+    return match (chars.next(), chars.next()) {
+        (Some(letter), None) => Ok(letter),
+        _ => Err(line.error(word.start, expected)),
+    };
+}
+
+fn parse_outcome(line: &Line, index: usize) -> Result<Outcome, ParseError> {
+    let word = line.word(index, "loss, draw or win")?;
+    return match word.text {
+        "loss" => Ok(Outcome::Loss),
+        "draw" => Ok(Outcome::Draw),
+        "win" => Ok(Outcome::Win),
+        _ => Err(line.error(word.start, "loss, draw or win")),
+    };
+}
+
+fn expect_end(line: &Line, index: usize) -> Result<(), ParseError> {
+    return match line.words().get(index) {
+        Some(word) => Err(line.error(word.start, "the end of the line")),
+        None => Ok(()),
+    };
+}
+
+impl RuleTable {
+    /// Rock, paper and scissors as in the puzzle.
+    pub fn classic() -> RuleTable {
+        let shapes = Hand::ALL.iter().zip("ABC".chars().zip("XYZ".chars()))
+            .map(|(hand, (opponent_letter, your_letter))| Shape { name: format!("{:?}", hand), opponent_letter, your_letter, score: hand.score() })
+            .collect();
+        let outcomes = Hand::ALL.iter().map(|yours| Hand::ALL.iter().map(|opponent| Outcome::of(*yours, *opponent)).collect()).collect();
+        let outcome_scores = Outcome::ALL.map(|outcome| outcome.score());
+        return RuleTable { shapes, outcomes, outcome_letters: ['X', 'Y', 'Z'], outcome_scores };
+    }
+
+    /// Read a rule table with a line per shape, outcome or relation between two shapes, e.g.
+    ///
+    /// ```text
+    /// # shape <NAME> <OPPONENT LETTER> <YOUR LETTER> <SCORE>
+    /// shape Rock A X 1
+    /// # outcome <loss|draw|win> <LETTER> <SCORE>
+    /// outcome win Z 6
+    /// # <NAME> beats <NAME>, or <NAME> draws <NAME> for different shapes that draw
+    /// Rock beats Scissors
+    /// ```
+    ///
+    /// Every pair of different shapes needs exactly one winner or a draw, and every outcome has to be
+    /// possible against every shape.
+    pub fn parse(lines: &Vec<String>) -> Result<RuleTable, RuleError> {
+        let mut shapes: Vec<Shape> = Vec::new();
+        let mut outcome_letters: [Option<char>; 3] = [None; 3];
+        let mut outcome_scores = [0; 3];
+        let mut relations = Vec::new();
+
+        for (line_index, text) in lines.iter().enumerate() {
+            let line = Line::new(line_index, text);
+            let Some(first_word) = line.words().first().copied() else {
+                continue;
+            };
+            match first_word.text {
+                _ if first_word.text.starts_with('#') => continue,
+                "shape" => {
+                    let name = line.word(1, "the name of the shape")?.text.to_string();
+                    let opponent_letter = parse_letter(&line, 2, "the letter of the opponent's hand")?;
+                    let your_letter = parse_letter(&line, 3, "the letter of your hand")?;
+                    let score = line.parse_word::<u32>(4, "the score of the shape")?;
+                    expect_end(&line, 5)?;
+                    let duplicate = if shapes.iter().any(|shape| shape.name == name) {
+                        Some(name.to_owned())
+                    } else if shapes.iter().any(|shape| shape.opponent_letter == opponent_letter) {
+                        Some(opponent_letter.to_string())
+                    } else if shapes.iter().any(|shape| shape.your_letter == your_letter) {
+                        Some(your_letter.to_string())
+                    } else {
+                        None
+                    };
+                    if let Some(name) = duplicate {
+                        return Err(RuleError::Duplicate { line: line.number, name });
+                    }
+                    shapes.push(Shape { name, opponent_letter, your_letter, score });
+                }
+                "outcome" => {
+                    let outcome = parse_outcome(&line, 1)?;
+                    let letter = parse_letter(&line, 2, "the letter of the outcome")?;
+                    let score = line.parse_word::<u32>(3, "the score of the outcome")?;
+                    expect_end(&line, 4)?;
+                    if outcome_letters[outcome as usize].is_some() || outcome_letters.contains(&Some(letter)) {
+                        return Err(RuleError::Duplicate { line: line.number, name: format!("{} ({:?})", letter, outcome) });
+                    }
+                    outcome_letters[outcome as usize] = Some(letter);
+                    outcome_scores[outcome as usize] = score;
+                }
+                _ => {
+                    let relation = line.word(1, "beats or draws")?;
+                    let outcome = match relation.text {
+                        "beats" => Outcome::Win,
+                        "draws" => Outcome::Draw,
+                        _ => return Err(line.error(relation.start, "beats or draws").into()),
+                    };
+                    let second_word = line.word(2, "the name of a shape")?;
+                    expect_end(&line, 3)?;
+                    relations.push((line, first_word, outcome, second_word));
+                }
+            }
+        }
+        if shapes.is_empty() {
+            return Err(ParseError::new(lines.len() + 1, 1, "a shape", "").into());
+        }
+
+        // Every shape draws against itself
+        let mut outcomes: Vec<Vec<Option<Outcome>>> = (0..shapes.len())
+            .map(|first| (0..shapes.len()).map(|second| if first == second { Some(Outcome::Draw) } else { None }).collect())
+            .collect();
+        for (line, first_word, outcome, second_word) in relations {
+            let find_shape = |word: Word| shapes.iter().position(|shape| shape.name == word.text)
+                .ok_or_else(|| line.error(word.start, "the name of a shape"));
+            let (first, second) = (find_shape(first_word)?, find_shape(second_word)?);
+            if outcomes[first][second].is_some() {
+                return Err(RuleError::Conflict { first: shapes[first].name.to_owned(), second: shapes[second].name.to_owned() });
+            }
+            let opposite = match outcome {
+                Outcome::Win => Outcome::Loss,
+                _ => outcome,
+            };
+            outcomes[first][second] = Some(outcome);
+            outcomes[second][first] = Some(opposite);
+        }
+
+        let mut decided_outcomes = Vec::new();
+        for (first, row) in outcomes.iter().enumerate() {
+            let decided = row.iter().enumerate().map(|(second, outcome)| outcome.ok_or_else(|| RuleError::Undecided {
+                first: shapes[first].name.to_owned(),
+                second: shapes[second].name.to_owned(),
+            }));
+            decided_outcomes.push(decided.collect::<Result<Vec<Outcome>, RuleError>>()?);
+        }
+        let mut letters = ['?'; 3];
+        for outcome in Outcome::ALL {
+            letters[outcome as usize] = outcome_letters[outcome as usize].ok_or(RuleError::MissingOutcome(outcome))?;
+        }
+
+        let table = RuleTable { shapes, outcomes: decided_outcomes, outcome_letters: letters, outcome_scores };
+        for (opponent, shape) in table.shapes.iter().enumerate() {
+            for outcome in Outcome::ALL {
+                if (0..table.shapes.len()).all(|yours| table.outcome(yours, opponent) != outcome) {
+                    return Err(RuleError::Unreachable { opponent: shape.name.to_owned(), outcome });
+                }
+            }
+        }
+        return Ok(table);
+    }
+
+    /// The outcome of playing the shape `yours` against `opponent`.
+    pub fn outcome(&self, yours: usize, opponent: usize) -> Outcome {
+        return self.outcomes[yours][opponent];
+    }
+
+    /// The score of your shape and the outcome, in 64 bits as both scores can be up to 2^32 - 1.
+    pub fn score_match(&self, opponent: usize, yours: usize) -> u64 {
+        return self.shapes[yours].score as u64 + self.outcome_scores[self.outcome(yours, opponent) as usize] as u64;
+    }
+
+    /// The shape to play against `opponent` to get `outcome`. If several shapes do, the one with the highest
+    /// score, and of shapes with the same score the one listed first in the rules.
+    pub fn find_hand(&self, opponent: usize, outcome: Outcome) -> usize {
+        return (0..self.shapes.len())
+            .filter(|yours| self.outcome(*yours, opponent) == outcome)
+            .max_by_key(|yours| (self.shapes[*yours].score, Reverse(*yours)))
+            .expect("Every outcome is possible against every shape");
+    }
+
+    pub fn opponent_letters(&self) -> String {
+        return self.shapes.iter().map(|shape| shape.opponent_letter).collect();
+    }
+
+    pub fn your_letters(&self) -> String {
+        return self.shapes.iter().map(|shape| shape.your_letter).collect();
+    }
+
+    /// The total score of the strategy guide for task 1, where the second column is your shape. A match
+    /// scores below 2^33, so the total cannot overflow 64 bits for a guide that fits in memory.
+    pub fn total_score_by_hand(&self, lines: &Vec<String>) -> Result<u64, GuideError> {
+        let (opponent_letters, your_letters) = (self.opponent_letters(), self.your_letters());
+        let mut total_score = 0;
+        for (line_index, text) in lines.iter().enumerate() {
+            let line = Line::new(line_index, text);
+            let (opponent, yours) = parse_columns(&line, &opponent_letters, &your_letters, GuideErrorKind::YourHand)?;
+            total_score += self.score_match(opponent, yours);
+        }
+        return Ok(total_score);
+    }
+
+    /// The total score of the strategy guide for task 2, where the second column is the outcome.
+    pub fn total_score_by_outcome(&self, lines: &Vec<String>) -> Result<u64, GuideError> {
+        let opponent_letters = self.opponent_letters();
+        let outcome_letters = self.outcome_letters.iter().collect::<String>();
+        let mut total_score = 0;
+        for (line_index, text) in lines.iter().enumerate() {
+            let line = Line::new(line_index, text);
+            let (opponent, outcome) = parse_columns(&line, &opponent_letters, &outcome_letters, GuideErrorKind::Outcome)?;
+            total_score += self.score_match(opponent, self.find_hand(opponent, Outcome::ALL[outcome]));
+        }
+        return Ok(total_score);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input::lines;
+
+    use crate::rules::{RuleError, RuleTable};
+    use crate::{Hand, Outcome, Round};
+
+    #[test]
+    fn test_classic() {
+        let table = RuleTable::classic();
+        for (opponent, opponent_hand) in Hand::ALL.iter().enumerate() {
+            for (yours, your_hand) in Hand::ALL.iter().enumerate() {
+                assert_eq!(table.score_match(opponent, yours), Round { opponent: *opponent_hand, yours: *your_hand }.score() as u64);
+            }
+            for outcome in Outcome::ALL {
+                assert_eq!(Hand::ALL[table.find_hand(opponent, outcome)], outcome.hand_against(*opponent_hand));
+            }
+        }
+        assert_eq!(table.total_score_by_hand(&lines("A Y\nB X\nC Z\n")), Ok(15));
+        assert_eq!(table.total_score_by_outcome(&lines("A Y\nB X\nC Z\n")), Ok(12));
+        assert_eq!(RuleTable::parse(&lines(include_str!("../rules/classic.txt"))), Ok(table));
+
+        let high_scores = include_str!("../rules/classic.txt").replace(" 6\n", " 4294967295\n").replace(" 3\n", " 4294967295\n");
+        let table = RuleTable::parse(&lines(&high_scores)).unwrap();
+        assert_eq!(table.total_score_by_hand(&lines("C Z\nC Z\n")), Ok(4 * 4294967295));
+    }
+
+    #[test]
+    fn test_rpsls() {
+        let table = RuleTable::parse(&lines(include_str!("../rules/rpsls.txt"))).unwrap();
+        assert_eq!(table.opponent_letters(), "ABCDE");
+        // Spock vaporizes rock, and lizard scores more than paper, which also beats Spock
+        assert_eq!(table.score_match(0, 4), 11);
+        assert_eq!(table.find_hand(4, Outcome::Win), 3);
+        assert_eq!(table.total_score_by_hand(&lines("A Y\nE X\nD Z\nA W\n")), Ok(18 + 5 + 6));
+        assert_eq!(table.total_score_by_outcome(&lines("A Y\nE X\nD Z\n")), Ok(16));
+        assert_eq!(table.total_score_by_hand(&lines("F X\n")).unwrap_err().to_string(), "line 1, column 1: expected the opponent's hand, A, B, C, D or E, found 'F'");
+
+        let rules = include_str!("../rules/rpsls.txt");
+        // Paper and Spock both beat rock, and with the same score the one listed first is played
+        let same_scores = RuleTable::parse(&lines(&rules.replace("Spock E W 5", "Spock E W 2"))).unwrap();
+        assert_eq!(same_scores.find_hand(0, Outcome::Win), 1);
+        let undecided = rules.replace("Lizard beats Paper\n", "");
+        assert_eq!(RuleTable::parse(&lines(&undecided)), Err(RuleError::Undecided { first: "Paper".to_string(), second: "Lizard".to_string() }));
+        let conflict = rules.to_string() + "Paper beats Lizard\n";
+        assert_eq!(RuleTable::parse(&lines(&conflict)), Err(RuleError::Conflict { first: "Paper".to_string(), second: "Lizard".to_string() }));
+    }
+}
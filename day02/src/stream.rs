@@ -0,0 +1,43 @@
+use std::io::BufRead;
+
+use aoc_common::parse::ParseError;
+use aoc_common::stream::{Lines, StreamError, Streaming};
+
+use crate::{parse_round_both_ways, Day02};
+
+impl Streaming for Day02 {
+    fn solve_stream(reader: &mut dyn BufRead, _: &()) -> Result<(u64, u64), StreamError> {
+        let mut lines = Lines::new(reader);
+        let mut total_score_1 = 0;
+        let mut total_score_2 = 0;
+
+        while let Some(line) = lines.next_line()? {
+            let (by_hand, by_outcome) = parse_round_both_ways(&line).map_err(ParseError::from)?;
+            total_score_1 += by_hand.score() as u64;
+            total_score_2 += by_outcome.score() as u64;
+        }
+        return Ok((total_score_1, total_score_2));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::Day02;
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_text::<Day02>(&text, &()).unwrap(), (Answer::Number(15), Answer::Number(12)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day02>(&generate::<Day02>(seed, 100).text, &()).unwrap(), solve_generated::<Day02>(seed, 100));
+        }
+        let error = solve_text::<Day02>("A Y\nB W\n", &()).unwrap_err();
+        assert_eq!(error.to_string(), Day02::parse("A Y\nB W\n").unwrap_err().to_string());
+    }
+}
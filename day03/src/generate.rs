@@ -0,0 +1,49 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day03;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generator for Day03 {
+    /// `size` groups of three rucksacks. The rucksacks of a group only share the badge, and the
+    /// compartments of a rucksack only share one item.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut text = String::new();
+        for _ in 0..size {
+            let mut items = ITEMS.chars().collect::<Vec<char>>();
+            rng.shuffle(&mut items);
+            let badge = items[0];
+            // Every rucksack picks its other items from its own 17 letters
+            for pool in items[1..].chunks(17) {
+                let shared = pool[0];
+                let length = rng.range(0..=7);
+                let mut first = [&[shared, badge], &pool[1..1 + length]].concat();
+                let mut second = [&[shared], &pool[9..10 + length]].concat();
+                rng.shuffle(&mut first);
+                rng.shuffle(&mut second);
+                if rng.chance(0.5) {
+                    (first, second) = (second, first);
+                }
+                text += &format!("{}{}\n", first.iter().collect::<String>(), second.iter().collect::<String>());
+            }
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+
+    use crate::Day03;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day03>(1, 10);
+        assert_eq!(input, generate::<Day03>(1, 10));
+        assert_eq!(input.text.lines().count(), 30);
+        for seed in 0..20 {
+            solve_generated::<Day03>(seed, 10);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+mod generate;
+mod stream;
+
+use std::collections::HashSet;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+fn check_rucksack(line: &Line) -> Result<(), ParseError> {
+    let invalid_item = line.text.char_indices().find(|(_, item)| !item.is_ascii_alphabetic());
+    if let Some((position, item)) = invalid_item {
+        return Err(line.error_span(position, position + item.len_utf8(), "an item letter"));
+    }
+    if !line.text.len().is_multiple_of(2) {
+        return Err(line.error(0, "an even number of items"));
+    }
+    return Ok(());
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    for (line_index, line) in lines.iter().enumerate() {
+        check_rucksack(&Line::new(line_index, line))?;
+    }
+    return Ok(lines.clone());
+}
+
+fn find_item_value(item: char) -> u32 {
+    let item_value = item as u32;
+    let uppercase_start = 'A' as u32 - 1;
+    let lowercase_start = 'a' as u32 - 1;
+
+    if item_value < lowercase_start {
+        return item_value - uppercase_start + 26;
+    }
+    return item_value - lowercase_start;
+}
+
+fn find_compartment_priority(first_items: &str, second_items: &str) -> u32 {
+    let mut first_items_set = HashSet::new();
+    for item in first_items.chars() {
+        first_items_set.insert(item);
+    }
+
+    for item in second_items.chars() {
+        if first_items_set.contains(&item) {
+            return find_item_value(item);
+        }
+    }
+
+    panic!("No matching items!")
+}
+
+pub fn solve_1(lines: &Vec<String>) -> u64 {
+    let mut priority_sum = 0;
+    for line in lines {
+        let middle_idx = line.len() / 2;
+        let (first_items, second_items) = line.split_at(middle_idx);
+        priority_sum += find_compartment_priority(first_items, second_items) as u64;
+    }
+    return priority_sum;
+}
+
+fn find_group_priority(first_items: &str, second_items: &str, third_items: &str) -> u32 {
+    let mut first_items_set = HashSet::new();
+    for item in first_items.chars() {
+        first_items_set.insert(item);
+    }
+
+    let mut second_items_set = HashSet::new();
+    for item in second_items.chars() {
+        if first_items_set.contains(&item) {
+            second_items_set.insert(item);
+        }
+    }
+
+    for item in third_items.chars() {
+        if second_items_set.contains(&item) {
+            return find_item_value(item);
+        }
+    }
+
+    panic!("No matching items!")
+}
+
+pub fn solve_2(lines: &Vec<String>) -> u64 {
+    let mut priority_sum = 0;
+
+    let mut first_items = "";
+    let mut second_items = "";
+    for (line_number, line) in lines.iter().enumerate() {
+        let group_turn = (line_number + 1) % 3;
+        match group_turn {
+            1 => first_items = line,
+            2 => second_items = line,
+            0 => {
+                priority_sum += find_group_priority(first_items, second_items, line) as u64;
+            }
+            _ => panic!("Not valid group")
+        }
+    }
+    return priority_sum;
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u32 = 3;
+    type Input = Vec<String>;
+    type Config = ();
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(lines: &Vec<String>, _: &()) -> u64 {
+        return solve_1(lines);
+    }
+
+    fn part2(lines: &Vec<String>, _: &()) -> u64 {
+        return solve_2(lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day03, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let lines = load::<Day03>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&lines), 157);
+    }
+
+    #[test]
+    fn test_2() {
+        let lines = load::<Day03>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&lines), 70);
+    }
+}
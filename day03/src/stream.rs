@@ -0,0 +1,54 @@
+use std::io::BufRead;
+
+use aoc_common::stream::{Lines, StreamError, Streaming};
+
+use crate::{check_rucksack, find_compartment_priority, find_group_priority, Day03};
+
+impl Streaming for Day03 {
+    fn solve_stream(reader: &mut dyn BufRead, _: &()) -> Result<(u64, u64), StreamError> {
+        let mut lines = Lines::new(reader);
+        // Priorities add up in 64 bits, as the inputs streamed can be many gigabytes long
+        let mut compartment_priority_sum = 0;
+        let mut group_priority_sum = 0;
+        // Only the first two rucksacks of the current group are kept
+        let mut group_items = [String::new(), String::new()];
+
+        while let Some(line) = lines.next_line()? {
+            check_rucksack(&line)?;
+            let (first_items, second_items) = line.text.split_at(line.text.len() / 2);
+            compartment_priority_sum += find_compartment_priority(first_items, second_items) as u64;
+
+            match line.number % 3 {
+                1 | 2 => {
+                    let items = &mut group_items[line.number % 3 - 1];
+                    items.clear();
+                    items.push_str(line.text);
+                }
+                _ => group_priority_sum += find_group_priority(&group_items[0], &group_items[1], line.text) as u64,
+            }
+        }
+        return Ok((compartment_priority_sum, group_priority_sum));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::Day03;
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_text::<Day03>(&text, &()).unwrap(), (Answer::Number(157), Answer::Number(70)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day03>(&generate::<Day03>(seed, 30).text, &()).unwrap(), solve_generated::<Day03>(seed, 30));
+        }
+        let error = solve_text::<Day03>("abca\nab1b\n", &()).unwrap_err();
+        assert_eq!(error.to_string(), Day03::parse("abca\nab1b\n").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day04;
+
+impl Generator for Day04 {
+    /// `size` pairs of section assignments between 1 and 99.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut text = String::new();
+        for _ in 0..size {
+            let first_min = rng.range(1..=99);
+            let first_max = rng.range(first_min..=99);
+            let second_min = rng.range(1..=99);
+            let second_max = rng.range(second_min..=99);
+            text += &format!("{}-{},{}-{}\n", first_min, first_max, second_min, second_max);
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day04;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day04>(1, 100);
+        assert_eq!(input, generate::<Day04>(1, 100));
+        assert_eq!(input.text.lines().count(), 100);
+        for seed in 0..20 {
+            // Pairs that contain each other also overlap
+            let (contained, overlapping) = solve_generated::<Day04>(seed, 50);
+            assert!(matches!((contained, overlapping), (Answer::Number(contained), Answer::Number(overlapping)) if contained <= overlapping));
+        }
+    }
+}
@@ -0,0 +1,121 @@
+mod generate;
+mod stream;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+pub struct SectionPair {
+    pub first_min: i32,
+    pub first_max: i32,
+    pub second_min: i32,
+    pub second_max: i32,
+}
+
+impl SectionPair {
+    pub fn is_fully_contained(&self) -> bool {
+        let first_contains_second = self.first_min <= self.second_min
+            && self.first_max >= self.second_max;
+
+        let second_contains_first = self.first_min >= self.second_min
+            && self.first_max <= self.second_max;
+
+        return first_contains_second || second_contains_first;
+    }
+
+    pub fn is_overlapping(&self) -> bool {
+        let first_contains_second_min = self.first_min <= self.second_min
+            && self.first_max >= self.second_min;
+
+        let first_contains_second_max = self.first_min <= self.second_max
+            && self.first_max >= self.second_max;
+
+        let second_contains_first_min = self.second_min <= self.first_min
+            && self.second_max >= self.first_min;
+
+        let second_contains_second_min = self.second_min <= self.first_max
+            && self.second_max >= self.first_max;
+
+        return first_contains_second_min || first_contains_second_max || second_contains_first_min || second_contains_second_min;
+    }
+}
+
+fn parse_section_pair(line: &Line) -> Result<SectionPair, ParseError> {
+    let first_dash = line.find("-", 0)?;
+    let comma = line.find(",", first_dash)?;
+    let second_dash = line.find("-", comma)?;
+    return Ok(SectionPair {
+        first_min: line.parse(0, first_dash, "a section number")?,
+        first_max: line.parse(first_dash + 1, comma, "a section number")?,
+        second_min: line.parse(comma + 1, second_dash, "a section number")?,
+        second_max: line.parse_rest(second_dash + 1, "a section number")?,
+    });
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<SectionPair>, ParseError> {
+    return lines.iter().enumerate()
+        .map(|(line_index, line)| parse_section_pair(&Line::new(line_index, line)))
+        .collect::<Result<Vec<SectionPair>, ParseError>>();
+}
+
+pub fn solve_1(section_pairs: &Vec<SectionPair>) -> i32 {
+    let mut fully_contained_pairs = 0;
+    for section_pair in section_pairs {
+        if section_pair.is_fully_contained() {
+            fully_contained_pairs += 1;
+        }
+    }
+    return fully_contained_pairs;
+}
+
+pub fn solve_2(section_pairs: &Vec<SectionPair>) -> i32 {
+    let mut overlapping_pairs = 0;
+    for section_pair in section_pairs {
+        if section_pair.is_overlapping() {
+            overlapping_pairs += 1;
+        }
+    }
+    return overlapping_pairs;
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u32 = 4;
+    type Input = Vec<SectionPair>;
+    type Config = ();
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<SectionPair>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(section_pairs: &Vec<SectionPair>, _: &()) -> i32 {
+        return solve_1(section_pairs);
+    }
+
+    fn part2(section_pairs: &Vec<SectionPair>, _: &()) -> i32 {
+        return solve_2(section_pairs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day04, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let section_pairs = load::<Day04>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&section_pairs), 2);
+    }
+
+    #[test]
+    fn test_2() {
+        let section_pairs = load::<Day04>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&section_pairs), 4);
+    }
+}
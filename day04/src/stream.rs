@@ -0,0 +1,46 @@
+use std::io::BufRead;
+
+use aoc_common::stream::{Lines, StreamError, Streaming};
+
+use crate::{parse_section_pair, Day04};
+
+impl Streaming for Day04 {
+    fn solve_stream(reader: &mut dyn BufRead, _: &()) -> Result<(i32, i32), StreamError> {
+        let mut lines = Lines::new(reader);
+        let mut fully_contained_pairs = 0;
+        let mut overlapping_pairs = 0;
+
+        while let Some(line) = lines.next_line()? {
+            let section_pair = parse_section_pair(&line)?;
+            if section_pair.is_fully_contained() {
+                fully_contained_pairs += 1;
+            }
+            if section_pair.is_overlapping() {
+                overlapping_pairs += 1;
+            }
+        }
+        return Ok((fully_contained_pairs, overlapping_pairs));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::Day04;
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_text::<Day04>(&text, &()).unwrap(), (Answer::Number(2), Answer::Number(4)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day04>(&generate::<Day04>(seed, 100).text, &()).unwrap(), solve_generated::<Day04>(seed, 100));
+        }
+        let error = solve_text::<Day04>("2-4,6-8\n2-4;6-8\n", &()).unwrap_err();
+        assert_eq!(error.to_string(), Day04::parse("2-4,6-8\n2-4;6-8\n").err().unwrap().to_string());
+    }
+}
@@ -0,0 +1,58 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day05;
+
+impl Generator for Day05 {
+    /// A drawing of 3 to 9 stacks followed by `size` moves. No move empties a stack, so every
+    /// stack has a crate on top at the end.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let stack_count = rng.range(3..=9);
+        let heights = (0..stack_count).map(|_| rng.range(1..=8)).collect::<Vec<usize>>();
+        let max_height = *heights.iter().max().unwrap();
+
+        let mut text = String::new();
+        for level in (0..max_height).rev() {
+            let row = heights.iter()
+                .map(|height| if level < *height { format!("[{}]", (b'A' + rng.range(0..26u32) as u8) as char) } else { "   ".to_string() })
+                .collect::<Vec<String>>();
+            text += &(row.join(" ") + "\n");
+        }
+        text += &((1..=stack_count).map(|number| format!(" {} ", number)).collect::<Vec<String>>().join(" ") + "\n\n");
+
+        let mut heights = heights;
+        for _ in 0..size {
+            // There is always a stack with two crates, because there are more crates than stacks
+            let from = loop {
+                let from = rng.range(0..stack_count);
+                if heights[from] > 1 {
+                    break from;
+                }
+            };
+            let to = (from + rng.range(1..stack_count)) % stack_count;
+            let amount = rng.range(1..heights[from]);
+            heights[from] -= amount;
+            heights[to] += amount;
+            text += &format!("move {} from {} to {}\n", amount, from + 1, to + 1);
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day05;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day05>(1, 100);
+        assert_eq!(input, generate::<Day05>(1, 100));
+        assert_eq!(input.text.lines().filter(|line| line.starts_with("move")).count(), 100);
+        for seed in 0..20 {
+            let (top_1, top_2) = solve_generated::<Day05>(seed, 50);
+            assert!(matches!((top_1, top_2), (Answer::Text(top_1), Answer::Text(top_2)) if top_1.len() == top_2.len()));
+        }
+    }
+}
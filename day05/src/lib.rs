@@ -0,0 +1,151 @@
+mod generate;
+mod visualize;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+pub struct Instruction {
+    pub amount: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+#[derive(Debug)]
+pub struct CargoCrane {
+    pub stacks: Vec<Vec<char>>,
+    pub instructions: Vec<Instruction>,
+}
+
+fn parse_stack_number(line: &Line, index: usize, crate_count: usize) -> Result<usize, ParseError> {
+    let expected = format!("a stack number between 1 and {}", crate_count);
+    let word = line.word(index, &expected)?;
+    return match word.text.parse::<usize>() {
+        Ok(stack_number) if (1..=crate_count).contains(&stack_number) => Ok(stack_number),
+        _ => Err(line.error(word.start, &expected))
+    };
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<CargoCrane, ParseError> {
+    let setup_end = match lines.iter().position(|line| line.is_empty()) {
+        Some(0) => return Err(ParseError::new(1, 1, "a drawing of the crate stacks", "")),
+        Some(line_number) => line_number,
+        None => return Err(ParseError::new(lines.len() + 1, 1, "an empty line after the crate stacks", "")),
+    };
+    let stack_number_line = Line::new(setup_end - 1, &lines[setup_end - 1]);
+    let last_stack_index = stack_number_line.words().len().saturating_sub(1);
+    let crate_count: usize = stack_number_line.parse_word(last_stack_index, "the number of the last stack")?;
+    let mut stacks = vec![Vec::<char>::new(); crate_count];
+
+    // Parse the initial state
+    for line_num in (0..setup_end - 1).rev() {
+        let line = Line::new(line_num, &lines[line_num]);
+        for (char_num, stack) in stacks.iter_mut().enumerate() {
+            let character_location = 1 + 4 * char_num;
+            if let Some((position, character)) = line.text.char_indices().nth(character_location) {
+                if character.is_ascii_alphabetic() {
+                    stack.push(character);
+                } else if !character.is_whitespace() {
+                    return Err(line.error_span(position, position + character.len_utf8(), "a crate letter"));
+                }
+            }
+        }
+    }
+
+    // Parse the instructions
+    let mut instructions = Vec::new();
+    for line_num in setup_end + 1..lines.len() {
+        let line = Line::new(line_num, &lines[line_num]);
+
+        line.expect_word(0, "move")?;
+        let amount: usize = line.parse_word(1, "a number of crates")?;
+        line.expect_word(2, "from")?;
+        let from = parse_stack_number(&line, 3, crate_count)?;
+        line.expect_word(4, "to")?;
+        let to = parse_stack_number(&line, 5, crate_count)?;
+
+        instructions.push(Instruction { from, to, amount });
+    }
+    return Ok(CargoCrane { stacks, instructions });
+}
+
+/// Move crates from the top of one stack to the top of another, keeping their order.
+pub fn move_crates(stacks: &mut Vec<Vec<char>>, from: usize, to: usize, amount: usize) {
+    let remaining = stacks[from - 1].len() - amount;
+    let moved_crates = stacks[from - 1].split_off(remaining);
+    stacks[to - 1].extend(moved_crates);
+}
+
+/// The crate on top of every stack.
+pub fn top_crates(stacks: &Vec<Vec<char>>) -> String {
+    return stacks.iter().map(|stack| *stack.last().unwrap()).collect();
+}
+
+pub fn solve_1(cargo_crane: &CargoCrane) -> String {
+    let mut stacks = cargo_crane.stacks.to_vec();
+    for instruction in &cargo_crane.instructions {
+        // The crane moves one crate at a time
+        for _ in 0..instruction.amount {
+            move_crates(&mut stacks, instruction.from, instruction.to, 1);
+        }
+    }
+    return top_crates(&stacks);
+}
+
+pub fn solve_2(cargo_crane: &CargoCrane) -> String {
+    let mut stacks = cargo_crane.stacks.to_vec();
+    for instruction in &cargo_crane.instructions {
+        move_crates(&mut stacks, instruction.from, instruction.to, instruction.amount);
+    }
+    return top_crates(&stacks);
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u32 = 5;
+    type Input = CargoCrane;
+    type Config = ();
+    type Output1 = String;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<CargoCrane, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(cargo_crane: &CargoCrane, _: &()) -> String {
+        return solve_1(cargo_crane);
+    }
+
+    fn part2(cargo_crane: &CargoCrane, _: &()) -> String {
+        return solve_2(cargo_crane);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::ParseError;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day05, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let lines = load::<Day05>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&lines), "CMZ");
+    }
+
+    #[test]
+    fn test_2() {
+        let lines = load::<Day05>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&lines), "MCD");
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day05::parse("    [D]\n[N] [C]\n 1   2\n\nmove 1 from 3 to 1").unwrap_err();
+        assert_eq!(error, ParseError::new(5, 13, "a stack number between 1 and 2", "3"));
+    }
+}
@@ -0,0 +1,92 @@
+use aoc_common::visualize::{Animation, Scene, Visualize};
+
+use crate::{move_crates, CargoCrane, Day05};
+
+// The stacks drawn like in the input, with the stack numbers at y = 0 and the crates above them
+struct Stacks<'a> {
+    stacks: &'a Vec<Vec<char>>,
+    highest_stack: usize,
+    focus_stack: usize,
+    caption: String,
+}
+
+impl Scene for Stacks<'_> {
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        return ((0, -(self.highest_stack as i64)), (4 * self.stacks.len() as i64 - 2, 0));
+    }
+
+    fn focus(&self) -> (i64, i64) {
+        return (4 * self.focus_stack as i64 + 1, -(self.stacks[self.focus_stack].len() as i64));
+    }
+
+    fn cell(&self, x: i64, y: i64) -> char {
+        let (stack, column) = ((x / 4) as usize, x % 4);
+        if y == 0 {
+            return if column == 1 { char::from_digit((stack as u32 + 1) % 10, 10).unwrap() } else { ' ' };
+        }
+        return match (self.stacks[stack].get((-y - 1) as usize), column) {
+            (Some(_), 0) => '[',
+            (Some(crate_letter), 1) => *crate_letter,
+            (Some(_), 2) => ']',
+            _ => ' ',
+        };
+    }
+
+    fn caption(&self) -> String {
+        return self.caption.to_owned();
+    }
+}
+
+// Stacks can be empty during the moves
+fn current_top_crates(stacks: &Vec<Vec<char>>) -> String {
+    return stacks.iter().map(|stack| stack.last().copied().unwrap_or(' ')).collect();
+}
+
+impl Visualize for Day05 {
+    // The crane of part 1 moves one crate at a time, so every crate is a step. The crane of part 2 moves them all at once.
+    fn visualize(cargo_crane: &CargoCrane, _: &(), part: u32, animation: &mut Animation) {
+        let mut stacks = cargo_crane.stacks.to_vec();
+        let mut highest_stack = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+        let start = Stacks { stacks: &stacks, highest_stack, focus_stack: 0, caption: "Before the first move".to_string() };
+        if !animation.frame(&start) {
+            return;
+        }
+
+        let instruction_count = cargo_crane.instructions.len();
+        for (instruction_number, instruction) in cargo_crane.instructions.iter().enumerate() {
+            let steps = if part == 1 { vec![1; instruction.amount] } else { vec![instruction.amount] };
+            for (step, amount) in steps.iter().enumerate() {
+                move_crates(&mut stacks, instruction.from, instruction.to, *amount);
+                highest_stack = highest_stack.max(stacks[instruction.to - 1].len());
+
+                let crate_number = if part == 1 { format!(", crate {} of {}", step + 1, steps.len()) } else { String::new() };
+                let caption = format!("Move {}/{}: move {} from {} to {}{}, top crates {}", instruction_number + 1, instruction_count,
+                                      instruction.amount, instruction.from, instruction.to, crate_number, current_top_crates(&stacks));
+                if !animation.frame(&Stacks { stacks: &stacks, highest_stack, focus_stack: instruction.to - 1, caption }) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::params::ParamArgs;
+    use aoc_common::visualize::{visualize, Animation, AnimationOptions, FrameStyle};
+
+    use crate::Day05;
+
+    #[test]
+    fn test_visualize() {
+        let mut output = Vec::new();
+        let mut animation = Animation::new(&mut output, FrameStyle::Text, AnimationOptions::default());
+        visualize::<Day05>(&day_input!("test.txt"), 2, &ParamArgs::default(), &mut animation).unwrap();
+        assert_eq!(animation.finish().unwrap(), 5);
+
+        let frames = String::from_utf8(output).unwrap();
+        assert!(frames.starts_with("Frame 1: Before the first move\n    [D]\n[N] [C]\n[Z] [M] [P]\n 1   2   3\n\n"));
+        assert!(frames.ends_with("Frame 5: Move 4/4: move 1 from 1 to 2, top crates MCD\n        [D]\n        [N]\n        [Z]\n[M] [C] [P]\n 1   2   3\n\n"));
+    }
+}
@@ -0,0 +1,38 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day06;
+
+impl Generator for Day06 {
+    /// A datastream of `size` letters. Up to the start-of-message marker, it only uses three letters,
+    /// so both markers are found close to it.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut letters = ('a'..='z').collect::<Vec<char>>();
+        rng.shuffle(&mut letters);
+        let size = size.max(15);
+        let marker_start = rng.range(1..=size - 14);
+
+        let mut text = (0..marker_start).map(|_| *rng.choose(&letters[..3])).collect::<String>();
+        text.extend(&letters[3..17]);
+        text.extend((marker_start + 14..size).map(|_| *rng.choose(&letters)));
+        return (text + "\n", ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day06;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day06>(1, 1000);
+        assert_eq!(input, generate::<Day06>(1, 1000));
+        assert_eq!(input.text.trim_end().len(), 1000);
+        for seed in 0..20 {
+            let (packet_marker, message_marker) = solve_generated::<Day06>(seed, 100);
+            assert!(matches!((packet_marker, message_marker), (Answer::Number(packet), Answer::Number(message)) if packet < message && message <= 100));
+        }
+    }
+}
@@ -0,0 +1,99 @@
+mod generate;
+mod stream;
+
+use std::collections::HashSet;
+
+use aoc_common::input;
+use aoc_common::parse::{get_line, ParseError};
+use aoc_common::solution::Solution;
+
+fn find_signal_marker(signal: &Vec<char>, lookback: usize) -> Option<usize> {
+    for current_pos in lookback - 1..signal.len() {
+        let mut found_repeated_letter = false;
+        let mut seen_letters = HashSet::new();
+        for pos in current_pos - (lookback - 1)..current_pos + 1 {
+            let character = signal[pos];
+            if seen_letters.contains(&character){
+                found_repeated_letter = true;
+            }
+            seen_letters.insert(character);
+        }
+        if !found_repeated_letter {
+            return Some(current_pos + 1)
+        }
+    }
+    return None;
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    let line = get_line(lines, 0, "a datastream")?;
+    if line.text.is_empty() {
+        return Err(line.error(0, "a datastream"));
+    }
+    if let Some((position, character)) = line.text.char_indices().find(|(_, character)| !character.is_ascii_lowercase()) {
+        return Err(line.error_span(position, position + character.len_utf8(), "a lowercase letter"));
+    }
+    // Both parts are solved from the same datastream, so it needs both markers
+    let signal = line.text.chars().collect::<Vec<char>>();
+    if find_signal_marker(&signal, 4).is_none() {
+        return Err(line.error(line.text.len(), "a start-of-packet marker"));
+    }
+    if find_signal_marker(&signal, 14).is_none() {
+        return Err(line.error(line.text.len(), "a start-of-message marker"));
+    }
+    return Ok(lines.clone());
+}
+
+pub fn solve_1(lines: &Vec<String>) -> usize {
+    let line = lines.iter().next().unwrap();
+    let signal = line.chars().collect::<Vec<char>>();
+    return find_signal_marker(&signal, 4).expect("Parsing checks that there is a start-of-packet marker");
+}
+
+pub fn solve_2(lines: &Vec<String>) -> usize {
+    let line = lines.iter().next().unwrap();
+    let signal = line.chars().collect::<Vec<char>>();
+    return find_signal_marker(&signal, 14).expect("Parsing checks that there is a start-of-message marker");
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u32 = 6;
+    type Input = Vec<String>;
+    type Config = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(lines: &Vec<String>, _: &()) -> usize {
+        return solve_1(lines);
+    }
+
+    fn part2(lines: &Vec<String>, _: &()) -> usize {
+        return solve_2(lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day06, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let lines = load::<Day06>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&lines), 7);
+    }
+
+    #[test]
+    fn test_2() {
+        let lines = load::<Day06>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&lines), 19);
+    }
+}
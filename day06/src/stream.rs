@@ -0,0 +1,109 @@
+use std::io::{BufRead, Read};
+
+use aoc_common::parse::ParseError;
+use aoc_common::stream::{StreamError, Streaming};
+
+use crate::Day06;
+
+// Finds the end of the first `lookback` different letters, reading one letter at a time
+struct MarkerFinder {
+    lookback: usize,
+    last_positions: [Option<usize>; 26],
+    // Start of the longest run of different letters that ends at the last letter
+    run_start: usize,
+    marker: Option<usize>,
+}
+
+impl MarkerFinder {
+    fn new(lookback: usize) -> MarkerFinder {
+        return MarkerFinder { lookback, last_positions: [None; 26], run_start: 0, marker: None };
+    }
+
+    fn push(&mut self, position: usize, letter: u8) {
+        if self.marker.is_some() {
+            return;
+        }
+        let letter_index = (letter - b'a') as usize;
+        if let Some(last_position) = self.last_positions[letter_index] {
+            self.run_start = self.run_start.max(last_position + 1);
+        }
+        self.last_positions[letter_index] = Some(position);
+        if position + 1 - self.run_start >= self.lookback {
+            self.marker = Some(position + 1);
+        }
+    }
+}
+
+impl Streaming for Day06 {
+    // The datastream is a single line, so it is read byte by byte instead of line by line
+    fn solve_stream(reader: &mut dyn BufRead, _: &()) -> Result<(usize, usize), StreamError> {
+        let mut packet_finder = MarkerFinder::new(4);
+        let mut message_finder = MarkerFinder::new(14);
+        let mut bytes = reader.bytes().peekable();
+        let mut position = 0;
+
+        while let Some(byte) = bytes.next() {
+            let byte = byte?;
+            match byte {
+                b'\n' => break,
+                b'\r' if matches!(bytes.peek(), Some(Ok(b'\n'))) => break,
+                b'a'..=b'z' => {
+                    packet_finder.push(position, byte);
+                    message_finder.push(position, byte);
+                }
+                _ => {
+                    // Report the whole character, like parse does
+                    let length = match byte {
+                        0xc0..=0xdf => 2,
+                        0xe0..=0xef => 3,
+                        0xf0..=0xf7 => 4,
+                        _ => 1,
+                    };
+                    let mut character = vec![byte];
+                    for _ in 1..length {
+                        character.push(bytes.next().transpose()?.unwrap_or_default());
+                    }
+                    let error = ParseError::new(1, position + 1, "a lowercase letter", &String::from_utf8_lossy(&character));
+                    return Err(error.into());
+                }
+            }
+            position += 1;
+        }
+
+        if position == 0 {
+            return Err(ParseError::new(1, 1, "a datastream", "").into());
+        }
+        // The datastream ends before `position + 1`, where the marker was expected. Like parse, both markers
+        // are needed even when only one part is asked for.
+        let packet_marker = packet_finder.marker.ok_or_else(|| ParseError::new(1, position + 1, "a start-of-packet marker", ""))?;
+        let message_marker = message_finder.marker.ok_or_else(|| ParseError::new(1, position + 1, "a start-of-message marker", ""))?;
+        return Ok((packet_marker, message_marker));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::Day06;
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_text::<Day06>(&text, &()).unwrap(), (Answer::Number(7), Answer::Number(19)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day06>(&generate::<Day06>(seed, 100).text, &()).unwrap(), solve_generated::<Day06>(seed, 100));
+        }
+        for text in ["", "\r\n", "abcdéfgh", "abcd efgh", "abcabc", "abcdabcd\n"] {
+            assert_eq!(solve_text::<Day06>(text, &()).unwrap_err().to_string(), Day06::parse(text).unwrap_err().to_string());
+        }
+        assert_eq!(solve_text::<Day06>("abcabc", &()).unwrap_err().to_string(),
+                   "line 1, column 7: expected a start-of-packet marker, found nothing");
+        assert_eq!(solve_text::<Day06>("abcdabcd\n", &()).unwrap_err().to_string(),
+                   "line 1, column 9: expected a start-of-message marker, found nothing");
+    }
+}
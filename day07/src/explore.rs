@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use aoc_common::explore::{Command, Explore};
+
+use crate::ContentType::Directory;
+use crate::{Config, Day07, DirectoryContent};
+
+// Paths are keyed as "|/|a|e" in the directory map, and written as "/a/e"
+fn to_key(path: &str) -> Result<String, String> {
+    if !path.starts_with('/') {
+        return Err(format!("'{}' is not an absolute path like /a/e", path));
+    }
+    let names = path.split('/').filter(|name| !name.is_empty()).collect::<Vec<&str>>();
+    return Ok(names.iter().fold("|/".to_string(), |key, name| key + "|" + name));
+}
+
+fn to_path(key: &str) -> String {
+    let path = key.strip_prefix("|/").unwrap().replace('|', "/");
+    return if path.is_empty() { "/".to_string() } else { path };
+}
+
+fn find<'a>(directory_map: &'a HashMap<String, Vec<DirectoryContent>>, path: &str) -> Result<&'a DirectoryContent, String> {
+    let key = to_key(path)?;
+    let parent = if key == "|/" { "root" } else { &key[..key.rfind('|').unwrap()] };
+    return directory_map.get(parent).and_then(|contents| contents.iter().find(|content| content.path == key))
+        .ok_or(format!("There is no file or directory {}", path));
+}
+
+// Directories that were listed but never entered have no contents in the map
+fn contents<'a>(directory_map: &'a HashMap<String, Vec<DirectoryContent>>, key: &str) -> Vec<&'a DirectoryContent> {
+    let mut contents = directory_map.get(key).map(|contents| contents.iter().collect::<Vec<_>>()).unwrap_or_default();
+    contents.sort_by(|first, second| first.path.cmp(&second.path));
+    return contents;
+}
+
+fn name(content: &DirectoryContent) -> &str {
+    return &content.path[content.path.rfind('|').unwrap() + 1..];
+}
+
+fn write_tree(directory_map: &HashMap<String, Vec<DirectoryContent>>, content: &DirectoryContent, depth: usize, lines: &mut Vec<String>) {
+    let kind = if content.content_type == Directory { "dir" } else { "file" };
+    lines.push(format!("{}- {} ({}, size={})", "  ".repeat(depth), name(content), kind, content.size));
+    if content.content_type == Directory {
+        for child in contents(directory_map, &content.path) {
+            write_tree(directory_map, child, depth + 1, lines);
+        }
+    }
+}
+
+impl Explore for Day07 {
+    fn show(directory_map: &HashMap<String, Vec<DirectoryContent>>) -> String {
+        let mut lines = Vec::new();
+        write_tree(directory_map, &directory_map["root"][0], 0, &mut lines);
+        return lines.join("\n");
+    }
+
+    fn commands() -> Vec<Command> {
+        return vec![
+            Command { usage: "size <PATH>", description: "Print the total size of a directory or file, e.g. size /a/e" },
+            Command { usage: "ls <PATH>", description: "List the contents of a directory with their sizes" },
+        ];
+    }
+
+    fn run(directory_map: &mut HashMap<String, Vec<DirectoryContent>>, _: &Config, name: &str, args: &[&str]) -> Result<String, String> {
+        let [path] = args else {
+            return Err(format!("Usage: {} <PATH>", name));
+        };
+        let content = find(directory_map, path)?;
+        if name == "size" {
+            return Ok(content.size.to_string());
+        }
+        if content.content_type != Directory {
+            return Err(format!("{} is not a directory", path));
+        }
+        let lines = contents(directory_map, &content.path).iter().map(|child| {
+            let kind = if child.content_type == Directory { "dir " } else { "" };
+            return format!("{}{} {}", kind, to_path(&child.path), child.size);
+        }).collect::<Vec<String>>();
+        return Ok(lines.join("\n"));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::explore::explore_session;
+    use aoc_common::params::ParamArgs;
+
+    use crate::Day07;
+
+    #[test]
+    fn test_explore() {
+        let mut session = explore_session::<Day07>(&day_input!("test.txt"), &ParamArgs::default()).unwrap();
+        assert_eq!(session.execute("size /a/e"), Ok("584".to_string()));
+        assert_eq!(session.execute("size /"), Ok("48381165".to_string()));
+        assert_eq!(session.execute("size /d/k"), Ok("7214296".to_string()));
+        assert_eq!(session.execute("ls /a/"), Ok("dir /a/e 584\n/a/f 29116\n/a/g 2557\n/a/h.lst 62596".to_string()));
+        assert_eq!(session.execute("size /x"), Err("There is no file or directory /x".to_string()));
+        assert!(session.execute("show").unwrap().starts_with("- / (dir, size=48381165)\n  - a (dir, size=94853)\n    - e (dir, size=584)\n      - i (file, size=584)"));
+    }
+}
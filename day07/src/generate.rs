@@ -0,0 +1,91 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day07};
+
+struct GeneratedDirectory {
+    name: String,
+    files: Vec<(u32, String)>,
+    children: Vec<usize>,
+}
+
+fn unique_name(rng: &mut Rng, taken: &Vec<String>, extension: &str) -> String {
+    loop {
+        let name = rng.word(1..=8) + extension;
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+fn write_transcript(directories: &Vec<GeneratedDirectory>, index: usize, rng: &mut Rng, text: &mut String) {
+    let directory = &directories[index];
+    text.push_str(&format!("$ cd {}\n$ ls\n", directory.name));
+    let mut listing = directory.children.iter().map(|child| format!("dir {}", directories[*child].name))
+        .chain(directory.files.iter().map(|(size, name)| format!("{} {}", size, name)))
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut listing);
+    for entry in listing {
+        text.push_str(&(entry + "\n"));
+    }
+    for child in &directory.children {
+        write_transcript(directories, *child, rng, text);
+        text.push_str("$ cd ..\n");
+    }
+}
+
+impl Generator for Day07 {
+    /// A terminal transcript that lists `size` directories and enters every one of them. The config
+    /// leaves room for the update so that it needs some of the directories to be deleted.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let size = size.max(1);
+        // Keep the total size well within a u32
+        let max_file_size = ((1u32 << 31) / (5 * size as u32)).clamp(1, 300_000);
+        let mut directories: Vec<GeneratedDirectory> = Vec::new();
+        for index in 0..size {
+            let name = if index == 0 {
+                "/".to_string()
+            } else {
+                let parent = rng.range(0..index);
+                directories[parent].children.push(index);
+                let taken = directories[parent].children.iter().filter(|child| **child != index).map(|child| directories[*child].name.to_owned()).collect();
+                unique_name(rng, &taken, "")
+            };
+            let mut files = Vec::new();
+            let mut file_names = Vec::new();
+            for _ in 0..rng.range(usize::from(index == 0)..=4) {
+                let extension = rng.choose(&["", ".txt", ".dat", ".log"]).to_string();
+                // File names always have a dot, so that they never clash with the directories
+                let file_name = unique_name(rng, &file_names, if extension.is_empty() { "." } else { &extension });
+                file_names.push(file_name.to_owned());
+                files.push((rng.range(1..=max_file_size), file_name));
+            }
+            directories.push(GeneratedDirectory { name, files, children: Vec::new() });
+        }
+
+        let mut text = String::new();
+        write_transcript(&directories, 0, rng, &mut text);
+        let total_size = directories.iter().flat_map(|directory| &directory.files).map(|(size, _)| size).sum::<u32>();
+        let config = Config { maximum_space_allowed: total_size - rng.range(1..=total_size), ..Config::default() };
+        return (text, config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day07;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day07>(1, 100);
+        assert_eq!(input, generate::<Day07>(1, 100));
+        assert_eq!(input.text.lines().filter(|line| line.starts_with("$ cd") && !line.ends_with("..")).count(), 100);
+        assert_eq!(input.params[0].0, "maximum_space_allowed");
+        for seed in 0..20 {
+            let (_, deleted_size) = solve_generated::<Day07>(seed, 30);
+            assert!(matches!(deleted_size, Answer::Number(size) if size > 0));
+        }
+    }
+}
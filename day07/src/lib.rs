@@ -0,0 +1,230 @@
+mod explore;
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_common::{input, params};
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+use crate::ContentType::{Directory, File};
+
+#[derive(PartialEq)]
+#[derive(Debug)]
+pub enum ContentType {
+    File,
+    Directory,
+}
+
+#[derive(Debug)]
+pub struct DirectoryContent {
+    pub content_type: ContentType,
+    pub path: String,
+    pub size: u32,
+}
+
+pub fn create_directory_map(lines: &Vec<String>) -> Result<HashMap<String, Vec<DirectoryContent>>, ParseError> {
+    let mut directory_map: HashMap<String, Vec<DirectoryContent>> = HashMap::new();
+    directory_map.insert(
+        "root".to_string(),
+        vec!(DirectoryContent {
+            content_type: Directory,
+            path: String::from("|/"),
+            size: 0,
+        }),
+    );
+
+    let mut current_directory_path = "".to_string();
+
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+        if line_num == 0 {
+            line.expect_word(0, "$")?;
+            line.expect_word(1, "cd")?;
+            line.expect_word(2, "/")?;
+        }
+
+        let first = line.word(0, "a command or directory entry")?.text;
+        match first {
+            "$" => {
+                let command = line.word(1, "'cd' or 'ls'")?;
+                if command.text == "cd" {
+                    let new_directory = line.word(2, "a directory name")?;
+                    let new_directory_name = new_directory.text;
+                    if new_directory_name == ".." {
+                        if current_directory_path == "|/" {
+                            return Err(line.error(new_directory.start, "a directory below /"));
+                        }
+                        let previous_directory_path = current_directory_path.to_owned();
+                        let size: u32 = directory_map.get(&*current_directory_path)
+                            .unwrap().iter().map(|content| content.size).sum();
+
+                        current_directory_path = current_directory_path[0..current_directory_path.rfind('|').unwrap()].parse().unwrap();
+
+                        directory_map.entry(current_directory_path.to_owned())
+                            .and_modify(|vec: &mut Vec<DirectoryContent>| {
+                                let element = vec.iter().position(|elem| elem.path == previous_directory_path).unwrap();
+                                vec[element].size = size;
+                            });
+                    } else if line_num == 0 {
+                        current_directory_path.push_str("|/");
+                        directory_map.insert(current_directory_path.to_owned(), Vec::new());
+                    } else {
+                        let new_directory_path = current_directory_path.to_owned() + "|" + new_directory_name;
+                        let is_listed = directory_map.get(&current_directory_path).unwrap().iter()
+                            .any(|content| content.content_type == Directory && content.path == new_directory_path);
+                        if !is_listed {
+                            return Err(line.error(new_directory.start, "a directory listed by ls"));
+                        }
+                        current_directory_path = new_directory_path;
+                        directory_map.insert(current_directory_path.to_owned(), Vec::new());
+                    }
+                } else if command.text != "ls" {
+                    return Err(line.error(command.start, "'cd' or 'ls'"));
+                }
+            }
+            "dir" => {
+                let dir_name = line.word(1, "a directory name")?.text;
+                let content = DirectoryContent {
+                    content_type: Directory,
+                    path: current_directory_path.to_owned() + &*"|".to_owned() + dir_name,
+                    size: 0,
+                };
+                directory_map.entry(current_directory_path.to_owned())
+                    .and_modify(|vec: &mut Vec<DirectoryContent>| vec.push(content));
+            }
+            _ => {
+                let file_size: u32 = line.parse_word(0, "a file size or 'dir'")?;
+                let filename = line.word(1, "a file name")?.text;
+                let content = DirectoryContent {
+                    content_type: File,
+                    path: current_directory_path.to_owned() + &*"|".to_owned() + filename,
+                    size: file_size,
+                };
+                directory_map.entry(current_directory_path.to_owned())
+                    .and_modify(|vec: &mut Vec<DirectoryContent>| vec.push(content));
+            }
+        }
+    }
+    if lines.is_empty() {
+        return Err(ParseError::new(1, 1, "'$ cd /'", ""));
+    }
+
+    // Navigate back to the "/" directory
+    while current_directory_path != "|/" {
+        let previous_directory_path = current_directory_path.to_owned();
+        let size: u32 = directory_map.get(&*current_directory_path)
+            .unwrap().iter().map(|content| content.size).sum();
+
+        current_directory_path = current_directory_path[0..current_directory_path.rfind('|').unwrap()].parse().unwrap();
+
+        directory_map.entry(current_directory_path.to_owned())
+            .and_modify(|vec: &mut Vec<DirectoryContent>| {
+                let element = vec.iter().position(|elem| elem.path == previous_directory_path).unwrap();
+                vec[element].size = size;
+            });
+    }
+
+    // Add the size of the "/" folder to the "root" element
+    let size: u32 = directory_map.get("|/")
+        .unwrap().iter().map(|content| content.size).sum();
+
+    directory_map.entry("root".to_owned())
+        .and_modify(|vec: &mut Vec<DirectoryContent>| {
+            let element = vec.iter().position(|elem| elem.path == "|/").unwrap();
+            vec[element].size = size;
+        });
+
+    return Ok(directory_map);
+}
+
+pub fn solve_1(directory_map: &HashMap<String, Vec<DirectoryContent>>, size_limit: u32) -> u32 {
+    let mut total_size: u32 = 0;
+    let mut stack = vec!("root");
+    while let Some(path) = stack.pop() {
+        let contents = directory_map.get(path).unwrap();
+
+        for content in contents {
+            if content.content_type == Directory {
+                if content.size <= size_limit {
+                    total_size += content.size;
+                }
+                stack.push(&*content.path);
+            }
+        }
+    }
+    return total_size;
+}
+
+pub fn solve_2(directory_map: &HashMap<String, Vec<DirectoryContent>>, maximum_space_allowed: u32) -> u32 {
+    let current_space = directory_map.get("root").unwrap()[0].size;
+
+    let minimum_directory_size = current_space.saturating_sub(maximum_space_allowed);
+
+    let mut smallest_valid_directory_size = current_space;
+    let mut stack = vec!("root");
+    while let Some(path) = stack.pop() {
+        let contents = directory_map.get(path).unwrap();
+
+        for content in contents {
+            if content.content_type == Directory {
+                if content.size >= minimum_directory_size && content.size <= smallest_valid_directory_size {
+                    smallest_valid_directory_size = content.size;
+                }
+                stack.push(&*content.path);
+            }
+        }
+    }
+    return smallest_valid_directory_size;
+}
+
+params! {
+    pub struct Config {
+        /// The largest size of the directories to sum up in task 1
+        size_limit: u32 = 100_000,
+        /// The most space the files may use for the update to fit, the total space minus the space it needs
+        maximum_space_allowed: u32 = 40_000_000,
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u32 = 7;
+    type Input = HashMap<String, Vec<DirectoryContent>>;
+    type Config = Config;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<HashMap<String, Vec<DirectoryContent>>, ParseError> {
+        return create_directory_map(&input::lines(input));
+    }
+
+    fn part1(directory_map: &HashMap<String, Vec<DirectoryContent>>, config: &Config) -> u32 {
+        return solve_1(directory_map, config.size_limit);
+    }
+
+    fn part2(directory_map: &HashMap<String, Vec<DirectoryContent>>, config: &Config) -> u32 {
+        return solve_2(directory_map, config.maximum_space_allowed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day07, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let directory_map = load::<Day07>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&directory_map, 100_000), 95437);
+    }
+
+    #[test]
+    fn test_2() {
+        let directory_map = load::<Day07>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&directory_map, 40_000_000), 24933642);
+    }
+}
@@ -0,0 +1,35 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day08;
+
+impl Generator for Day08 {
+    /// A square grid of `size` by `size` tree heights.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut text = String::new();
+        for _ in 0..size.max(1) {
+            text += &(0..size.max(1)).map(|_| char::from_digit(rng.range(0..=9), 10).unwrap()).collect::<String>();
+            text.push('\n');
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day08;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day08>(1, 40);
+        assert_eq!(input, generate::<Day08>(1, 40));
+        assert!(input.text.lines().all(|line| line.len() == 40));
+        for seed in 0..20 {
+            // The trees on the edge are always visible
+            let (visible, _) = solve_generated::<Day08>(seed, 20);
+            assert!(matches!(visible, Answer::Number(visible) if (76..=400).contains(&visible)));
+        }
+    }
+}
@@ -0,0 +1,90 @@
+mod generate;
+
+use aoc_common::grid::{Grid, DIRECTIONS_4};
+use aoc_common::input;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::Solution;
+
+pub fn parse(lines: &Vec<String>) -> Result<Grid<u8>, ParseError> {
+    return Grid::parse(lines, "trees", "a tree height", |char| char.to_digit(10).map(|height| height as u8));
+}
+
+pub fn solve_1(tree_grid: &Grid<u8>) -> u32 {
+    let mut visible_tree_count = 0;
+
+    for position in tree_grid.positions() {
+        let height = tree_grid[position];
+        let is_visible = DIRECTIONS_4.iter()
+            .any(|direction| tree_grid.ray(position, *direction).all(|other_tree| tree_grid[other_tree] < height));
+        if is_visible {
+            visible_tree_count += 1;
+        }
+    }
+
+    return visible_tree_count;
+}
+
+pub fn solve_2(tree_grid: &Grid<u8>) -> u32 {
+    let mut max_scenic_score = 0;
+
+    for position in tree_grid.positions() {
+        let height = tree_grid[position];
+        let mut current_scenic_score = 1;
+
+        for direction in DIRECTIONS_4 {
+            let mut current_direction_score = 0;
+            for other_tree in tree_grid.ray(position, direction) {
+                current_direction_score += 1;
+                if tree_grid[other_tree] >= height {
+                    break;
+                }
+            }
+            current_scenic_score *= current_direction_score;
+        }
+        max_scenic_score = max_scenic_score.max(current_scenic_score);
+    }
+
+    return max_scenic_score;
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u32 = 8;
+    type Input = Grid<u8>;
+    type Config = ();
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<u8>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(tree_grid: &Grid<u8>, _: &()) -> u32 {
+        return solve_1(tree_grid);
+    }
+
+    fn part2(tree_grid: &Grid<u8>, _: &()) -> u32 {
+        return solve_2(tree_grid);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day08, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let tree_grid = load::<Day08>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&tree_grid), 21);
+    }
+
+    #[test]
+    fn test_2() {
+        let tree_grid = load::<Day08>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&tree_grid), 8);
+    }
+}
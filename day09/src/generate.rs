@@ -0,0 +1,34 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day09;
+
+impl Generator for Day09 {
+    /// `size` motions of the head of the rope, of 1 to 20 steps each.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut text = String::new();
+        for _ in 0..size {
+            text += &format!("{} {}\n", rng.choose(&["R", "U", "L", "D"]), rng.range(1..=20));
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day09;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day09>(1, 100);
+        assert_eq!(input, generate::<Day09>(1, 100));
+        assert_eq!(input.text.lines().count(), 100);
+        for seed in 0..20 {
+            // The tail of the long rope follows the tail of the short rope, so it visits no more positions
+            let (short_rope, long_rope) = solve_generated::<Day09>(seed, 50);
+            assert!(matches!((short_rope, long_rope), (Answer::Number(short), Answer::Number(long)) if long <= short));
+        }
+    }
+}
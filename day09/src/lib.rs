@@ -0,0 +1,127 @@
+mod generate;
+mod stream;
+mod visualize;
+
+use std::collections::HashSet;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+fn parse_motion(line: &Line) -> Result<(char, u32), ParseError> {
+    let direction = line.word(0, "a direction")?;
+    if !["R", "U", "L", "D"].contains(&direction.text) {
+        return Err(line.error(direction.start, "one of the directions R, U, L or D"));
+    }
+    let steps = line.parse_word::<u32>(1, "a number of steps")?;
+    return Ok((direction.text.chars().next().unwrap(), steps));
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    for (line_num, text) in lines.iter().enumerate() {
+        parse_motion(&Line::new(line_num, text))?;
+    }
+    return Ok(lines.clone());
+}
+
+// The knots of a rope, and every position its last knot has visited
+struct Rope {
+    knot_positions: Vec<(i32, i32)>,
+    last_knot_position_set: HashSet<(i32, i32)>,
+}
+
+impl Rope {
+    fn new(knot_number: usize) -> Rope {
+        return Rope { knot_positions: vec![(0, 0); knot_number], last_knot_position_set: HashSet::from([(0, 0)]) };
+    }
+
+    fn move_head(&mut self, direction: char, steps: u32) {
+        let knot_number = self.knot_positions.len();
+        let knot_positions = &mut self.knot_positions;
+
+        for _ in 0..steps {
+            match direction {
+                'R' => knot_positions[0].0 += 1,
+                'U' => knot_positions[0].1 += 1,
+                'L' => knot_positions[0].0 -= 1,
+                'D' => knot_positions[0].1 -= 1,
+                _ => panic!("{} is not a valid direction", direction)
+            }
+
+            for head in 0..knot_number - 1 {
+                let tail = head + 1;
+                let x_diff = knot_positions[head].0 - knot_positions[tail].0;
+                let y_diff = knot_positions[head].1 - knot_positions[tail].1;
+
+                let x_diff_size = x_diff.abs();
+                let y_diff_size = y_diff.abs();
+
+                if x_diff_size >= 2 || y_diff_size >= 2 {
+                    let x_change = x_diff.signum();
+                    let y_change = y_diff.signum();
+                    knot_positions[tail] = (knot_positions[tail].0 + x_change, knot_positions[tail].1 + y_change);
+                }
+            }
+            self.last_knot_position_set.insert(knot_positions[knot_number - 1]);
+        }
+    }
+}
+
+fn run_knot_simulation(lines: &Vec<String>, knot_number: usize) -> usize {
+    let mut rope = Rope::new(knot_number);
+    for (line_num, text) in lines.iter().enumerate() {
+        let (direction, steps) = parse_motion(&Line::new(line_num, text)).unwrap();
+        rope.move_head(direction, steps);
+    }
+    return rope.last_knot_position_set.len();
+}
+
+pub fn solve_1(lines: &Vec<String>) -> usize {
+   return run_knot_simulation(lines, 2);
+}
+
+pub fn solve_2(lines: &Vec<String>) -> usize {
+    return run_knot_simulation(lines, 10);
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u32 = 9;
+    type Input = Vec<String>;
+    type Config = ();
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(lines: &Vec<String>, _: &()) -> usize {
+        return solve_1(lines);
+    }
+
+    fn part2(lines: &Vec<String>, _: &()) -> usize {
+        return solve_2(lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day09, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let lines = load::<Day09>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&lines), 13);
+    }
+
+    #[test]
+    fn test_2() {
+        let lines = load::<Day09>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&lines), 1);
+    }
+}
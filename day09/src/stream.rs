@@ -0,0 +1,43 @@
+use std::io::BufRead;
+
+use aoc_common::stream::{Lines, StreamError, Streaming};
+
+use crate::{parse_motion, Day09, Rope};
+
+impl Streaming for Day09 {
+    // Only the visited positions are kept, which grow with the area the rope covers instead of the input
+    fn solve_stream(reader: &mut dyn BufRead, _: &()) -> Result<(usize, usize), StreamError> {
+        let mut lines = Lines::new(reader);
+        let mut short_rope = Rope::new(2);
+        let mut long_rope = Rope::new(10);
+
+        while let Some(line) = lines.next_line()? {
+            let (direction, steps) = parse_motion(&line)?;
+            short_rope.move_head(direction, steps);
+            long_rope.move_head(direction, steps);
+        }
+        return Ok((short_rope.last_knot_position_set.len(), long_rope.last_knot_position_set.len()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::Day09;
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_text::<Day09>(&text, &()).unwrap(), (Answer::Number(13), Answer::Number(1)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day09>(&generate::<Day09>(seed, 100).text, &()).unwrap(), solve_generated::<Day09>(seed, 100));
+        }
+        let error = solve_text::<Day09>("R 4\nX 2\n", &()).unwrap_err();
+        assert_eq!(error.to_string(), Day09::parse("R 4\nX 2\n").unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,88 @@
+use aoc_common::parse::Line;
+use aoc_common::visualize::{Animation, Scene, Visualize};
+
+use crate::{parse_motion, Day09, Rope};
+
+// The rope with y upwards as in the puzzle, so it is drawn upside down. The bounds hold every position
+// a knot has been at, so the picture only grows.
+struct RopeScene<'a> {
+    rope: &'a Rope,
+    bounds: ((i64, i64), (i64, i64)),
+    caption: String,
+}
+
+impl Scene for RopeScene<'_> {
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        return self.bounds;
+    }
+
+    fn focus(&self) -> (i64, i64) {
+        let head = self.rope.knot_positions[0];
+        return (head.0 as i64, -head.1 as i64);
+    }
+
+    fn cell(&self, x: i64, y: i64) -> char {
+        let position = (x as i32, -y as i32);
+        if let Some(knot) = self.rope.knot_positions.iter().position(|knot| *knot == position) {
+            return match knot {
+                0 => 'H',
+                _ if self.rope.knot_positions.len() == 2 => 'T',
+                _ => char::from_digit(knot as u32, 10).unwrap(),
+            };
+        }
+        return match position {
+            (0, 0) => 's',
+            _ if self.rope.last_knot_position_set.contains(&position) => '#',
+            _ => '.',
+        };
+    }
+
+    fn caption(&self) -> String {
+        return self.caption.to_owned();
+    }
+}
+
+impl Visualize for Day09 {
+    fn visualize(lines: &Vec<String>, _: &(), part: u32, animation: &mut Animation) {
+        let mut rope = Rope::new(if part == 1 { 2 } else { 10 });
+        let mut bounds = ((-1, -1), (1, 1));
+        for (line_num, text) in lines.iter().enumerate() {
+            let (direction, steps) = parse_motion(&Line::new(line_num, text)).unwrap();
+            for step in 0..steps {
+                rope.move_head(direction, 1);
+                for knot in &rope.knot_positions {
+                    let (x, y) = (knot.0 as i64, -knot.1 as i64);
+                    bounds = ((bounds.0.0.min(x - 1), bounds.0.1.min(y - 1)), (bounds.1.0.max(x + 1), bounds.1.1.max(y + 1)));
+                }
+
+                let caption = format!("Motion {}/{}: {} {}, step {}, the tail has visited {} positions", line_num + 1, lines.len(),
+                                      direction, steps, step + 1, rope.last_knot_position_set.len());
+                if !animation.frame(&RopeScene { rope: &rope, bounds, caption }) {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::params::ParamArgs;
+    use aoc_common::visualize::{visualize, Animation, AnimationOptions, FrameStyle};
+
+    use crate::Day09;
+
+    #[test]
+    fn test_visualize() {
+        let mut output = Vec::new();
+        let mut animation = Animation::new(&mut output, FrameStyle::Text, AnimationOptions::default());
+        visualize::<Day09>(&day_input!("test.txt"), 1, &ParamArgs::default(), &mut animation).unwrap();
+        assert_eq!(animation.finish().unwrap(), 24);
+
+        // The last frame shows the positions the tail has visited, like in the puzzle
+        let frames = String::from_utf8(output).unwrap();
+        let last_frame = &frames[frames.rfind("Frame 24").unwrap()..];
+        assert_eq!(last_frame, "Frame 24: Motion 8/8: R 2, step 2, the tail has visited 13 positions\n........\n...##...\n....##..\n..TH##..\n.....#..\n.s###...\n........\n\n");
+    }
+}
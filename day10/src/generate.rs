@@ -0,0 +1,43 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day10;
+
+impl Generator for Day10 {
+    /// A program that runs for `size` rows of 40 cycles on the CRT, keeping the sprite on the screen.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let mut text = String::new();
+        let mut register_x = 1;
+        let mut clock_cycle = 0;
+        let cycle_count = 40 * size.max(1);
+        while clock_cycle < cycle_count {
+            if clock_cycle == cycle_count - 1 || rng.chance(0.3) {
+                text += "noop\n";
+                clock_cycle += 1;
+            } else {
+                let number = rng.range(-1 - register_x..=40 - register_x);
+                text += &format!("addx {}\n", number);
+                register_x += number;
+                clock_cycle += 2;
+            }
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day10;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day10>(1, 6);
+        assert_eq!(input, generate::<Day10>(1, 6));
+        for seed in 0..20 {
+            let (_, display) = solve_generated::<Day10>(seed, 6);
+            assert!(matches!(display, Answer::Text(display) if display.trim().lines().count() == 6));
+        }
+    }
+}
@@ -0,0 +1,122 @@
+mod generate;
+mod stream;
+mod visualize;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+// The number to add for an addx instruction, or None for noop
+fn parse_instruction(line: &Line) -> Result<Option<i32>, ParseError> {
+    let command = line.word(0, "'noop' or 'addx'")?;
+    return match command.text {
+        "noop" => Ok(None),
+        "addx" => Ok(Some(line.parse_word::<i32>(1, "a number to add")?)),
+        _ => Err(line.error(command.start, "'noop' or 'addx'"))
+    };
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    for (line_num, text) in lines.iter().enumerate() {
+        parse_instruction(&Line::new(line_num, text))?;
+    }
+    return Ok(lines.clone());
+}
+
+// The CPU with its signal strength so far, and the CRT display it draws
+struct Cpu {
+    register_x: i32,
+    clock_cycle: i32,
+    signal_strength: i32,
+    display: String,
+}
+
+impl Cpu {
+    fn new() -> Cpu {
+        return Cpu { register_x: 1, clock_cycle: 0, signal_strength: 0, display: String::new() };
+    }
+
+    // Draw a pixel during the clock cycle and measure the signal strength
+    fn run_cycle(&mut self) {
+        let clock_cycle_line = self.clock_cycle % 40;
+        if clock_cycle_line == 0 {
+            self.display.push('\n');
+        }
+        let pixel = if self.register_x >= clock_cycle_line - 1 && self.register_x <= clock_cycle_line + 1 { '#' } else { '.' };
+        self.display.push(pixel);
+
+        self.clock_cycle += 1;
+        if self.clock_cycle % 40 == 20 {
+            self.signal_strength += self.clock_cycle * self.register_x;
+        }
+    }
+
+    fn execute(&mut self, instruction: Option<i32>) {
+        match instruction {
+            None => self.run_cycle(),
+            Some(number) => {
+                self.run_cycle();
+                self.run_cycle();
+                self.register_x += number;
+            }
+        }
+    }
+}
+
+fn run_program(lines: &Vec<String>) -> Cpu {
+    let mut cpu = Cpu::new();
+    for (line_num, text) in lines.iter().enumerate() {
+        cpu.execute(parse_instruction(&Line::new(line_num, text)).unwrap());
+    }
+    return cpu;
+}
+
+pub fn solve_1(lines: &Vec<String>) -> i32 {
+    return run_program(lines).signal_strength;
+}
+
+pub fn solve_2(lines: &Vec<String>) -> String {
+    return run_program(lines).display;
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    type Input = Vec<String>;
+    type Config = ();
+    type Output1 = i32;
+    type Output2 = String;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(lines: &Vec<String>, _: &()) -> i32 {
+        return solve_1(lines);
+    }
+
+    fn part2(lines: &Vec<String>, _: &()) -> String {
+        return solve_2(lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day10, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let lines = load::<Day10>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&lines), 13140);
+    }
+
+    #[test]
+    fn test_2() {
+        let lines = load::<Day10>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&lines), "\n##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n######......######......######......####\n#######.......#######.......#######.....");
+    }
+}
@@ -0,0 +1,40 @@
+use std::io::BufRead;
+
+use aoc_common::stream::{Lines, StreamError, Streaming};
+
+use crate::{parse_instruction, Cpu, Day10};
+
+impl Streaming for Day10 {
+    // Only the display is kept, which is the answer to task 2
+    fn solve_stream(reader: &mut dyn BufRead, _: &()) -> Result<(i32, String), StreamError> {
+        let mut lines = Lines::new(reader);
+        let mut cpu = Cpu::new();
+        while let Some(line) = lines.next_line()? {
+            cpu.execute(parse_instruction(&line)?);
+        }
+        return Ok((cpu.signal_strength, cpu.display));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::input::read_to_string;
+    use aoc_common::solution::{load, Answer, Solution};
+    use aoc_common::stream::solve_text;
+
+    use crate::{solve_2, Day10};
+
+    #[test]
+    fn test_stream() {
+        let text = read_to_string(&day_input!("test.txt")).unwrap();
+        let display = solve_2(&load::<Day10>(&day_input!("test.txt")).unwrap());
+        assert_eq!(solve_text::<Day10>(&text, &()).unwrap(), (Answer::Number(13140), Answer::Text(display)));
+        for seed in 0..5 {
+            assert_eq!(solve_text::<Day10>(&generate::<Day10>(seed, 3).text, &()).unwrap(), solve_generated::<Day10>(seed, 3));
+        }
+        let error = solve_text::<Day10>("noop\naddx x\n", &()).unwrap_err();
+        assert_eq!(error.to_string(), Day10::parse("noop\naddx x\n").unwrap_err().to_string());
+    }
+}
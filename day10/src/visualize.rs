@@ -0,0 +1,78 @@
+use aoc_common::parse::Line;
+use aoc_common::visualize::{Animation, Scene, Visualize};
+
+use crate::{parse_instruction, Cpu, Day10};
+
+// The sprite at the position of register X on top, and below it the CRT as far as it has been drawn
+struct Crt<'a> {
+    cpu: &'a Cpu,
+    caption: String,
+}
+
+impl Scene for Crt<'_> {
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        let rows = (self.cpu.clock_cycle as i64 + 39) / 40;
+        return ((0, 0), (39, 1 + rows.max(6)));
+    }
+
+    fn focus(&self) -> (i64, i64) {
+        let pixel = (self.cpu.clock_cycle - 1).max(0) as i64;
+        return (pixel % 40, 2 + pixel / 40);
+    }
+
+    fn cell(&self, x: i64, y: i64) -> char {
+        return match y {
+            0 if (self.cpu.register_x as i64 - x).abs() <= 1 => '#',
+            0 => '.',
+            1 => ' ',
+            _ => self.cpu.display.split('\n').nth(y as usize - 1).and_then(|row| row.chars().nth(x as usize)).unwrap_or(' '),
+        };
+    }
+
+    fn caption(&self) -> String {
+        return self.caption.to_owned();
+    }
+}
+
+impl Visualize for Day10 {
+    // Both parts run the same program, so they look the same: a frame for every clock cycle
+    fn visualize(lines: &Vec<String>, _: &(), _: u32, animation: &mut Animation) {
+        let mut cpu = Cpu::new();
+        for (line_num, text) in lines.iter().enumerate() {
+            let instruction = parse_instruction(&Line::new(line_num, text)).unwrap();
+            let cycles = if instruction.is_some() { 2 } else { 1 };
+            for _ in 0..cycles {
+                cpu.run_cycle();
+                let caption = format!("Cycle {}: {}, X={}, signal strength {}", cpu.clock_cycle, text, cpu.register_x, cpu.signal_strength);
+                if !animation.frame(&Crt { cpu: &cpu, caption }) {
+                    return;
+                }
+            }
+            cpu.register_x += instruction.unwrap_or(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::params::ParamArgs;
+    use aoc_common::visualize::{visualize, Animation, AnimationOptions, FrameStyle};
+
+    use crate::Day10;
+
+    #[test]
+    fn test_visualize() {
+        let mut output = Vec::new();
+        let mut animation = Animation::new(&mut output, FrameStyle::Text, AnimationOptions::default());
+        visualize::<Day10>(&day_input!("test.txt"), 2, &ParamArgs::default(), &mut animation).unwrap();
+        assert_eq!(animation.finish().unwrap(), 240);
+
+        let frames = String::from_utf8(output).unwrap();
+        assert!(frames.starts_with("Frame 1: Cycle 1: addx 15, X=1, signal strength 0\n###.....................................\n\n#\n\n"));
+        let last_frame = &frames[frames.rfind("Frame 240").unwrap()..];
+        assert!(last_frame.ends_with("\n\n##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n\
+            ####....####....####....####....####....\n#####.....#####.....#####.....#####.....\n\
+            ######......######......######......####\n#######.......#######.......#######.....\n\n"));
+    }
+}
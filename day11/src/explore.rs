@@ -0,0 +1,73 @@
+use aoc_common::explore::{Command, Explore};
+
+use crate::WorryLevelManagementType::{DivideByThree, ModByCommonMultiple};
+use crate::{monkey_business, play_rounds, Config, Day11, Monkey};
+
+fn describe(monkey_number: usize, monkey: &Monkey) -> String {
+    let items = monkey.items.iter().map(|item| item.to_string()).collect::<Vec<String>>();
+    return format!("Monkey {}: items [{}], new = old {} {}, divisible by {} ? {} : {}, {} inspections",
+                   monkey_number, items.join(", "), monkey.operation.operator, monkey.operation.new_worry_level,
+                   monkey.test.divisor, monkey.test.if_true_monkey_number, monkey.test.if_false_monkey_number, monkey.inspections);
+}
+
+fn show_items(monkeys: &Vec<Monkey>) -> String {
+    let mut lines = monkeys.iter().enumerate().map(|(monkey_number, monkey)| {
+        let items = monkey.items.iter().map(|item| item.to_string()).collect::<Vec<String>>();
+        return format!("Monkey {} ({} inspections): {}", monkey_number, monkey.inspections, items.join(", ")).trim_end().to_string();
+    }).collect::<Vec<String>>();
+    lines.push(format!("Monkey business: {}", monkey_business(monkeys)));
+    return lines.join("\n");
+}
+
+impl Explore for Day11 {
+    fn show(monkeys: &Vec<Monkey>) -> String {
+        return monkeys.iter().enumerate().map(|(monkey_number, monkey)| describe(monkey_number, monkey)).collect::<Vec<String>>().join("\n");
+    }
+
+    fn commands() -> Vec<Command> {
+        return vec![
+            Command { usage: "step <ROUNDS> [PART]", description: "Play rounds and print the items, dividing worry levels by three as in part 1 (default) or not as in part 2" },
+            Command { usage: "monkey <NUMBER>", description: "Print the items, operation, test and inspections of a monkey" },
+        ];
+    }
+
+    fn run(monkeys: &mut Vec<Monkey>, _: &Config, name: &str, args: &[&str]) -> Result<String, String> {
+        if name == "monkey" {
+            let monkey_number = args.first().and_then(|number| number.parse::<usize>().ok()).filter(|number| *number < monkeys.len())
+                .ok_or(format!("Usage: monkey <NUMBER>, where NUMBER is below {}", monkeys.len()))?;
+            return Ok(describe(monkey_number, &monkeys[monkey_number]));
+        }
+
+        let usage = "Usage: step <ROUNDS> [PART], where PART is 1 or 2";
+        let rounds = args.first().and_then(|rounds| rounds.parse::<u32>().ok()).ok_or(usage)?;
+        let worry_management_type = match args.get(1) {
+            None | Some(&"1") => DivideByThree,
+            Some(&"2") => ModByCommonMultiple,
+            _ => return Err(usage.to_string()),
+        };
+        play_rounds(monkeys, rounds, &worry_management_type);
+        return Ok(show_items(monkeys));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::explore::explore_session;
+    use aoc_common::params::ParamArgs;
+
+    use crate::Day11;
+
+    #[test]
+    fn test_explore() {
+        let mut session = explore_session::<Day11>(&day_input!("test.txt"), &ParamArgs::default()).unwrap();
+        let items = session.execute("step 1").unwrap();
+        assert!(items.starts_with("Monkey 0 (2 inspections): 20, 23, 27, 26\nMonkey 1 (4 inspections): 2080, 25, 167, 207, 401, 1046\n"));
+        assert!(session.execute("step 19").unwrap().ends_with("Monkey business: 10605"));
+        assert_eq!(session.execute("monkey 3"), Ok("Monkey 3: items [], new = old + 3, divisible by 17 ? 0 : 1, 105 inspections".to_string()));
+        assert!(session.execute("monkey 4").is_err());
+
+        session.execute("reset").unwrap();
+        assert!(session.execute("step 20 2").unwrap().ends_with("Monkey business: 10197"));
+    }
+}
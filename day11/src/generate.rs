@@ -0,0 +1,97 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day11};
+
+const PRIMES: [u64; 9] = [2, 3, 5, 7, 11, 13, 17, 19, 23];
+
+struct GeneratedMonkey {
+    items: Vec<u64>,
+    operation: (char, Option<u64>),
+    divisor: u64,
+    targets: (usize, usize),
+}
+
+fn generate_monkeys(rng: &mut Rng, monkey_count: usize) -> Vec<GeneratedMonkey> {
+    let mut divisors = PRIMES.to_vec();
+    rng.shuffle(&mut divisors);
+    // Like in the puzzle, at most one monkey squares the worry levels
+    let squaring_monkey = rng.range(0..monkey_count);
+    return (0..monkey_count).map(|number| {
+        let operation = if number == squaring_monkey && rng.chance(0.5) {
+            ('*', None)
+        } else if rng.chance(0.5) {
+            ('*', Some(rng.range(2..=19)))
+        } else {
+            ('+', Some(rng.range(1..=8)))
+        };
+        let other_monkey = |rng: &mut Rng| (number + rng.range(1..monkey_count)) % monkey_count;
+        GeneratedMonkey {
+            items: (0..rng.range(1..=6)).map(|_| rng.range(40..=99)).collect(),
+            operation,
+            divisor: divisors[number],
+            targets: (other_monkey(rng), other_monkey(rng)),
+        }
+    }).collect();
+}
+
+// Whether the worry levels stay within a u64 when they are divided by three
+fn fits_task_1(monkeys: &Vec<GeneratedMonkey>, rounds: u32) -> bool {
+    let mut items = monkeys.iter().map(|monkey| monkey.items.clone()).collect::<Vec<Vec<u64>>>();
+    for _ in 0..rounds {
+        for (number, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[number]) {
+                let operand = monkey.operation.1.unwrap_or(item);
+                let new_item = match monkey.operation.0 {
+                    '+' => item.checked_add(operand),
+                    _ => item.checked_mul(operand),
+                };
+                let Some(new_item) = new_item.map(|new_item| new_item / 3) else {
+                    return false;
+                };
+                let target = if new_item.is_multiple_of(monkey.divisor) { monkey.targets.0 } else { monkey.targets.1 };
+                items[target].push(new_item);
+            }
+        }
+    }
+    return true;
+}
+
+impl Generator for Day11 {
+    /// Notes on `size` monkeys, between 2 and 9 so that their divisors can be distinct primes
+    /// whose product squared fits in a u64.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let config = Config::default();
+        let monkeys = loop {
+            let monkeys = generate_monkeys(rng, size.clamp(2, PRIMES.len()));
+            if fits_task_1(&monkeys, config.rounds_1) {
+                break monkeys;
+            }
+        };
+
+        let notes = monkeys.iter().enumerate().map(|(number, monkey)| {
+            let items = monkey.items.iter().map(|item| item.to_string()).collect::<Vec<String>>().join(", ");
+            let operand = monkey.operation.1.map(|operand| operand.to_string()).unwrap_or("old".to_string());
+            format!("Monkey {}:\n  Starting items: {}\n  Operation: new = old {} {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                    number, items, monkey.operation.0, operand, monkey.divisor, monkey.targets.0, monkey.targets.1)
+        }).collect::<Vec<String>>();
+        return (notes.join("\n"), config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+
+    use crate::Day11;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day11>(1, 8);
+        assert_eq!(input, generate::<Day11>(1, 8));
+        assert_eq!(input.text.matches("Monkey").count(), 8);
+        assert!(input.params.is_empty());
+        for seed in 0..10 {
+            solve_generated::<Day11>(seed, 4);
+        }
+    }
+}
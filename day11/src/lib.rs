@@ -0,0 +1,271 @@
+mod explore;
+mod generate;
+
+use aoc_common::{input, params};
+use aoc_common::parse::{get_line, Line, ParseError};
+use aoc_common::solution::Solution;
+
+use crate::WorryLevelManagementType::{DivideByThree, ModByCommonMultiple};
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct MonkeyOperation {
+    pub operator: char,
+    pub new_worry_level: String,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct MonkeyTest {
+    pub divisor: u64,
+    pub if_true_monkey_number: usize,
+    pub if_false_monkey_number: usize,
+}
+
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct Monkey {
+    pub items: Vec<u64>,
+    pub operation: MonkeyOperation,
+    pub test: MonkeyTest,
+    pub inspections: u32,
+}
+
+fn parse_throw_target(line: &Line, condition: &str) -> Result<(usize, usize), ParseError> {
+    line.expect_word(0, "If")?;
+    line.expect_word(1, condition)?;
+    line.expect_word(2, "throw")?;
+    line.expect_word(3, "to")?;
+    line.expect_word(4, "monkey")?;
+    let target = line.word(5, "a monkey number")?;
+    let monkey_number = line.parse_word::<usize>(5, "a monkey number")?;
+    return Ok((monkey_number, target.start));
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<Monkey>, ParseError> {
+    let mut monkeys: Vec<Monkey> = Vec::new();
+    let mut throw_targets = Vec::new();
+
+    for (line_number, text) in lines.iter().enumerate() {
+        if text.starts_with("Monkey") {
+            let monkey_line = Line::new(line_number, text);
+            let expected_number = format!("'{}:'", monkeys.len());
+            let monkey_number = monkey_line.word(1, &expected_number)?;
+            if monkey_number.text != format!("{}:", monkeys.len()) {
+                return Err(monkey_line.error(monkey_number.start, &expected_number));
+            }
+
+            // Parse the starting items
+            let item_line = get_line(lines, line_number + 1, "'Starting items:'")?;
+            item_line.expect_word(0, "Starting")?;
+            item_line.expect_word(1, "items:")?;
+            let mut items = Vec::new();
+            for item in item_line.words().iter().skip(2) {
+                let item_text = item.text.strip_suffix(',').unwrap_or(item.text);
+                items.push(item_line.parse::<u64>(item.start, item.start + item_text.len(), "a worry level")?);
+            }
+
+            // Parse the operation
+            let operation_line = get_line(lines, line_number + 2, "'Operation:'")?;
+            operation_line.expect_word(0, "Operation:")?;
+            operation_line.expect_word(1, "new")?;
+            operation_line.expect_word(2, "=")?;
+            operation_line.expect_word(3, "old")?;
+            let operator_word = operation_line.word(4, "'+' or '*'")?;
+            let operator = match operator_word.text {
+                "+" => '+',
+                "*" => '*',
+                _ => return Err(operation_line.error(operator_word.start, "'+' or '*'"))
+            };
+            let operand = operation_line.word(5, "'old' or a number")?;
+            if operand.text != "old" {
+                operation_line.parse_word::<u64>(5, "'old' or a number")?;
+            }
+            let new_worry_level = operand.text.to_string();
+            let operation = MonkeyOperation { operator, new_worry_level };
+
+            // Parse the test
+            let test_line = get_line(lines, line_number + 3, "'Test:'")?;
+            test_line.expect_word(0, "Test:")?;
+            test_line.expect_word(1, "divisible")?;
+            test_line.expect_word(2, "by")?;
+            let divisor: u64 = test_line.parse_word(3, "a divisor")?;
+            if divisor == 0 {
+                return Err(test_line.error(test_line.word(3, "a divisor")?.start, "a divisor larger than 0"));
+            }
+
+            let true_line = get_line(lines, line_number + 4, "'If true:'")?;
+            let (if_true, true_start) = parse_throw_target(&true_line, "true:")?;
+
+            let false_line = get_line(lines, line_number + 5, "'If false:'")?;
+            let (if_false, false_start) = parse_throw_target(&false_line, "false:")?;
+
+            throw_targets.push((monkeys.len(), true_line, true_start, if_true));
+            throw_targets.push((monkeys.len(), false_line, false_start, if_false));
+            let test = MonkeyTest { divisor, if_true_monkey_number: if_true, if_false_monkey_number: if_false };
+
+            // Add the new monkey to the list of monkeys
+            monkeys.push(Monkey { items, operation, test, inspections: 0 })
+        }
+    }
+
+    // Monkeys can only throw to other monkeys that exist
+    for (monkey_number, line, start, target) in throw_targets {
+        if target >= monkeys.len() || target == monkey_number {
+            return Err(line.error(start, "the number of another monkey"));
+        }
+    }
+    return Ok(monkeys);
+}
+
+pub enum WorryLevelManagementType {
+    DivideByThree,
+    ModByCommonMultiple,
+}
+
+/// Let every monkey take its turn for a number of rounds, counting their inspections.
+pub fn play_rounds(monkeys: &mut Vec<Monkey>,
+                   rounds: u32,
+                   worry_management_type: &WorryLevelManagementType) {
+    let mut common_multiple = 1;
+    for monkey in monkeys.iter() {
+        common_multiple *= monkey.test.divisor;
+    }
+
+    for _ in 0..rounds {
+        for monkey_number in 0..monkeys.len() {
+            for item_number in 0..monkeys[monkey_number].items.len() {
+                let item = monkeys[monkey_number].items[item_number];
+
+                // Let the monkey inspect the item, and calculate the next worry level
+                monkeys[monkey_number].inspections += 1;
+
+                let operation = &monkeys[monkey_number].operation;
+                let new_level = match operation.new_worry_level.as_str() {
+                    "old" => item.to_owned(),
+                    x => x.to_owned().parse().unwrap()
+                };
+                let after_inspection_worry = match operation.operator {
+                    '+' => item + new_level,
+                    '*' => item * new_level,
+                    _ => panic!("{} is not a valid operation", operation.operator)
+                };
+                let next_worry_level = match worry_management_type {
+                    DivideByThree => after_inspection_worry / 3,
+                    ModByCommonMultiple => after_inspection_worry % common_multiple
+                };
+
+                // Find the next monkey according to the test
+                let test = &monkeys[monkey_number].test;
+                let next_monkey = if next_worry_level % test.divisor == 0 {
+                    test.if_true_monkey_number
+                } else {
+                    test.if_false_monkey_number
+                };
+
+                // Pass item to the next monkey
+                monkeys[next_monkey].items.push(next_worry_level);
+            }
+            // Remove all items of the current monkey because they have been thrown away
+            monkeys[monkey_number].items = Vec::new();
+        }
+    }
+}
+
+/// The inspections of the two most active monkeys multiplied.
+pub fn monkey_business(monkeys: &Vec<Monkey>) -> u64 {
+    let mut most_inspections = 0;
+    let mut second_most_inspections = 0;
+
+    for monkey in monkeys {
+        if monkey.inspections > most_inspections {
+            second_most_inspections = most_inspections;
+            most_inspections = monkey.inspections;
+        } else if monkey.inspections > second_most_inspections {
+            second_most_inspections = monkey.inspections
+        }
+    }
+
+    return most_inspections as u64 * second_most_inspections as u64;
+}
+
+fn run_monkey_in_the_middle(raw_monkeys: &Vec<Monkey>,
+                            rounds: u32,
+                            worry_management_type: WorryLevelManagementType) -> u64 {
+    let mut monkeys = raw_monkeys.to_owned();
+    play_rounds(&mut monkeys, rounds, &worry_management_type);
+    return monkey_business(&monkeys);
+}
+
+pub fn solve_1(monkeys: &Vec<Monkey>, rounds: u32) -> u64 {
+    return run_monkey_in_the_middle(monkeys,
+                                    rounds,
+                                    DivideByThree);
+}
+
+pub fn solve_2(monkeys: &Vec<Monkey>, rounds: u32) -> u64 {
+    return run_monkey_in_the_middle(monkeys,
+                                    rounds,
+                                    ModByCommonMultiple);
+}
+
+params! {
+    pub struct Config {
+        /// The number of rounds in task 1, where worry levels are divided by three
+        rounds_1: u32 = 20,
+        /// The number of rounds in task 2, where worry levels are not divided
+        rounds_2: u32 = 10_000,
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    type Input = Vec<Monkey>;
+    type Config = Config;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<Monkey>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(monkeys: &Vec<Monkey>, config: &Config) -> u64 {
+        return solve_1(monkeys, config.rounds_1);
+    }
+
+    fn part2(monkeys: &Vec<Monkey>, config: &Config) -> u64 {
+        return solve_2(monkeys, config.rounds_2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::ParseError;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day11, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let monkeys = load::<Day11>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&monkeys, 20), 10605);
+    }
+
+    #[test]
+    fn test_2() {
+        let monkeys = load::<Day11>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&monkeys, 10_000), 2713310158);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let monkey = "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = old * 19\n  Test: divisible by 23\n";
+        let error = Day11::parse(&(monkey.to_string() + "    If true: throw to monkey 2\n    If false: throw to monkey 0"));
+        assert_eq!(error.unwrap_err(), ParseError::new(5, 30, "the number of another monkey", "2"));
+        let error = Day11::parse("Monkey 0:\n  Starting items: 79, x8");
+        assert_eq!(error.unwrap_err(), ParseError::new(2, 23, "a worry level", "x8"));
+    }
+}
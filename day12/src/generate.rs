@@ -0,0 +1,66 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day12;
+
+impl Generator for Day12 {
+    /// A heightmap `size` squares wide, at least 30, that rises from west to east. A path that climbs at
+    /// most one step at a time leads from the start on the west edge to the end on the east edge.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let columns = size.max(30);
+        let rows = (columns / 3).max(5);
+        let mut heights = (0..rows)
+            .map(|_| (0..columns).map(|column| (column as i32 * 26 / columns as i32 + rng.range(-3..=3i32)).clamp(0, 25)).collect())
+            .collect::<Vec<Vec<i32>>>();
+
+        let start = (rng.range(0..rows), 0);
+        let end = (rng.range(0..rows), columns - 1);
+        // The path goes east and towards the row of the end, so it never visits a square twice
+        let mut path = vec![start];
+        let (mut row, mut column) = start;
+        while (row, column) != end {
+            if row != end.0 && (column == end.1 || rng.chance(0.4)) {
+                row = if row < end.0 { row + 1 } else { row - 1 };
+            } else {
+                column += 1;
+            }
+            path.push((row, column));
+        }
+        let climb_length = path.len() as i32 - 3;
+        for (index, (row, column)) in path[1..path.len() - 1].iter().enumerate() {
+            heights[*row][*column] = index as i32 * 25 / climb_length;
+        }
+
+        let mut text = String::new();
+        for (row, row_heights) in heights.iter().enumerate() {
+            for (column, height) in row_heights.iter().enumerate() {
+                text.push(match (row, column) {
+                    position if position == start => 'S',
+                    position if position == end => 'E',
+                    _ => (b'a' + *height as u8) as char,
+                });
+            }
+            text.push('\n');
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day12;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day12>(1, 60);
+        assert_eq!(input, generate::<Day12>(1, 60));
+        assert_eq!(input.text.lines().count(), 20);
+        for seed in 0..10 {
+            // The end can always be reached, and it is too high to be reached in fewer steps than the climb
+            let (from_start, from_any_a) = solve_generated::<Day12>(seed, 30);
+            assert!(matches!((from_start, from_any_a), (Answer::Number(start), Answer::Number(any)) if any <= start && (25..1000).contains(&any)));
+        }
+    }
+}
@@ -0,0 +1,120 @@
+extern crate core;
+
+mod generate;
+
+use aoc_common::grid::Grid;
+use aoc_common::input;
+use aoc_common::parse::ParseError;
+use aoc_common::search::{bfs, SearchSpace};
+use aoc_common::solution::Solution;
+
+pub fn parse(lines: &Vec<String>) -> Result<Grid<char>, ParseError> {
+    let heightmap = Grid::parse(lines, "squares", "a height between 'a' and 'z'", |char| match char {
+        'a'..='z' | 'S' | 'E' => Some(char),
+        _ => None,
+    })?;
+    let start_count = heightmap.iter().filter(|(_, char)| **char == 'S').count();
+    let end_count = heightmap.iter().filter(|(_, char)| **char == 'E').count();
+    if start_count != 1 || end_count != 1 {
+        return Err(ParseError::new(lines.len() + 1, 1, "exactly one 'S' and one 'E' in the heightmap", ""));
+    }
+    return Ok(heightmap);
+}
+
+fn find_start_position(heightmap: &Grid<char>) -> (usize, usize) {
+    return heightmap.find(|char| *char == 'S').expect("There is no starting square");
+}
+
+fn find_bottom_positions(heightmap: &Grid<char>) -> Vec<(usize, usize)> {
+    return heightmap.iter().filter(|(_, char)| **char == 'a').map(|(position, _)| position).collect();
+}
+
+// Climbing the heightmap: a step can go up at most one, down any amount, to any 'a' or onto the end from 'y' or 'z'
+struct Climb<'a> {
+    heightmap: &'a Grid<char>,
+}
+
+impl SearchSpace for Climb<'_> {
+    type State = (usize, usize);
+
+    fn neighbours(&self, position: &(usize, usize)) -> Vec<((usize, usize), u64)> {
+        let current_char = self.heightmap[*position];
+        return self.heightmap.neighbours_4(*position)
+            .filter(|new_position| {
+                let new_char = self.heightmap[*new_position];
+                return match new_char {
+                    'E' => current_char as u32 >= 'y' as u32,
+                    _ => new_char == 'a' || new_char as u32 <= current_char as u32 + 1,
+                };
+            })
+            .map(|new_position| (new_position, 1))
+            .collect();
+    }
+
+    fn is_goal(&self, position: &(usize, usize)) -> bool {
+        return self.heightmap[*position] == 'E';
+    }
+}
+
+// The fewest steps from the nearest of the start positions to the end, or u32::MAX if it cannot be reached
+fn find_shortest_path_from(start_positions: Vec<(usize, usize)>, heightmap: &Grid<char>) -> u32 {
+    return bfs(&Climb { heightmap }, start_positions).cost().map_or(u32::MAX, |steps| steps as u32);
+}
+
+pub fn find_shortest_path(start_position: (usize, usize), heightmap: &Grid<char>) -> u32 {
+    return find_shortest_path_from(vec![start_position], heightmap);
+}
+
+pub fn solve_1(heightmap: &Grid<char>) -> u32 {
+    let start_position = find_start_position(heightmap);
+    return find_shortest_path(start_position, heightmap);
+}
+
+pub fn solve_2(heightmap: &Grid<char>) -> u32 {
+    // Searching from all of them at once finds the nearest
+    let mut start_positions = find_bottom_positions(heightmap);
+    start_positions.push(find_start_position(heightmap));
+    return find_shortest_path_from(start_positions, heightmap);
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    type Input = Grid<char>;
+    type Config = ();
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(heightmap: &Grid<char>, _: &()) -> u32 {
+        return solve_1(heightmap);
+    }
+
+    fn part2(heightmap: &Grid<char>, _: &()) -> u32 {
+        return solve_2(heightmap);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day12, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let heightmap = load::<Day12>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&heightmap), 31);
+    }
+
+    #[test]
+    fn test_2() {
+        let heightmap = load::<Day12>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&heightmap), 29);
+    }
+}
@@ -0,0 +1,83 @@
+use std::cmp::Ordering;
+
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day13;
+
+enum Value {
+    Number(u32),
+    List(Vec<Value>),
+}
+
+impl Value {
+    fn random(rng: &mut Rng, depth: u32) -> Value {
+        let length = rng.range(0..=5);
+        return Value::List((0..length).map(|_| {
+            if depth >= 4 || rng.chance(0.6) {
+                Value::Number(rng.range(0..=10))
+            } else {
+                Value::random(rng, depth + 1)
+            }
+        }).collect());
+    }
+
+    // The order of the puzzle, which compares a number with a list as a list of that number
+    fn compare(&self, other: &Value) -> Ordering {
+        return match (self, other) {
+            (Value::Number(first), Value::Number(second)) => first.cmp(second),
+            (Value::List(first), Value::List(second)) => {
+                first.iter().zip(second).map(|(first, second)| first.compare(second))
+                    .find(|ordering| ordering.is_ne())
+                    .unwrap_or(first.len().cmp(&second.len()))
+            }
+            (Value::Number(first), list) => Value::List(vec![Value::Number(*first)]).compare(list),
+            (list, Value::Number(second)) => list.compare(&Value::List(vec![Value::Number(*second)])),
+        };
+    }
+
+    fn to_text(&self) -> String {
+        return match self {
+            Value::Number(number) => number.to_string(),
+            Value::List(items) => format!("[{}]", items.iter().map(|item| item.to_text()).collect::<Vec<String>>().join(",")),
+        };
+    }
+}
+
+impl Generator for Day13 {
+    /// `size` pairs of packets. No two packets are equal, also not to the divider packets, so that
+    /// every pair is in one order or the other.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let divider = |number| Value::List(vec![Value::List(vec![Value::Number(number)])]);
+        let mut packets = vec![divider(2), divider(6)];
+        while packets.len() < 2 * size + 2 {
+            let packet = Value::random(rng, 0);
+            if packets.iter().all(|other| packet.compare(other).is_ne()) {
+                packets.push(packet);
+            }
+        }
+
+        let pairs = packets[2..].chunks(2)
+            .map(|pair| format!("{}\n{}\n", pair[0].to_text(), pair[1].to_text()))
+            .collect::<Vec<String>>();
+        return (pairs.join("\n"), ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day13;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day13>(1, 50);
+        assert_eq!(input, generate::<Day13>(1, 50));
+        assert_eq!(input.text.lines().count(), 149);
+        for seed in 0..20 {
+            let (_, decoder_key) = solve_generated::<Day13>(seed, 20);
+            assert!(matches!(decoder_key, Answer::Number(key) if (2..=41 * 42).contains(&key)));
+        }
+    }
+}
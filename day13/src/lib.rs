@@ -0,0 +1,291 @@
+mod generate;
+
+use std::cmp::Ordering;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+// Check that a list starts at the byte offset `start` and return the offset after it
+fn parse_list(line: &Line, start: usize) -> Result<usize, ParseError> {
+    let bytes = line.text.as_bytes();
+    if bytes.get(start) != Some(&b'[') {
+        return Err(line.error(start, "'['"));
+    }
+    let mut position = start + 1;
+    if bytes.get(position) == Some(&b']') {
+        return Ok(position + 1);
+    }
+    loop {
+        position = match bytes.get(position) {
+            Some(b'[') => parse_list(line, position)?,
+            Some(byte) if byte.is_ascii_digit() => {
+                let digit_count = bytes[position..].iter().take_while(|byte| byte.is_ascii_digit()).count();
+                line.parse::<i32>(position, position + digit_count, "a number")?;
+                position + digit_count
+            }
+            _ => return Err(line.error(position, "a number or '['"))
+        };
+        match bytes.get(position) {
+            Some(b',') => position += 1,
+            Some(b']') => return Ok(position + 1),
+            _ => return Err(line.error(position, "',' or ']'"))
+        }
+    }
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+        if line_num % 3 == 2 {
+            if !text.is_empty() {
+                return Err(line.error(0, "an empty line between packet pairs"));
+            }
+        } else {
+            let end = parse_list(&line, 0)?;
+            if end != text.len() {
+                return Err(line.error(end, "the end of the packet"));
+            }
+        }
+    }
+    if lines.len() % 3 == 1 {
+        return Err(ParseError::new(lines.len() + 1, 1, "the second packet of the pair", ""));
+    }
+    return Ok(lines.clone());
+}
+
+fn split_line(line: &String) -> Vec<String> {
+    // Create a new string with comma separation also for '[' and ']'
+    let mut comma_separated_line = String::new();
+    for char in line.chars() {
+        if char == ']' {
+            comma_separated_line.push(',');
+        }
+        comma_separated_line.push(char);
+        if char == '[' {
+            comma_separated_line.push(',');
+        }
+    }
+
+    // Split the string on commas and filter out empty elements
+    return comma_separated_line.split(',')
+        .map(|s| s.to_string())
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<String>>();
+}
+
+pub fn is_right_order(first_raw: &String, second_raw: &String) -> bool {
+    let first = split_line(first_raw);
+    let second = split_line(second_raw);
+
+    // The current index that is being processed the vector
+    let mut first_index = 0;
+    let mut second_index = 0;
+
+    // The current actual list depth (as seen in the raw data)
+    let mut first_depth = 0;
+    let mut second_depth = 0;
+
+    // If numbers are compared to lists, the number is wrapped in a list. The extended depth
+    // measures the depth of this list
+    let mut first_extended_depth = 0;
+    let mut second_extended_depth = 0;
+
+    // A flag that notifies if a number needs to be compared. If this happens, we need to stop
+    // at the number and wait and potentially compare it to another number
+    let mut first_is_number = false;
+    let mut second_is_number = false;
+
+    loop {
+        // If we are not waiting to compare a number and the extended depth is not 0, we need to
+        // reduce the extended depth by reading a "]" instead of the next word
+        let first_word = if first_extended_depth == 0 || first_is_number {
+            &first[first_index]
+        } else {
+            "]"
+        };
+        let second_word = if second_extended_depth == 0 || second_is_number {
+            &second[second_index]
+        } else {
+            "]"
+        };
+
+        // The only possible words are "[", "]" and numbers
+        if first_word != "[" && first_word != "]" {
+            first_is_number = true;
+        }
+        if second_word != "[" && second_word != "]" {
+            second_is_number = true;
+        }
+
+        if first_word == "[" {
+            first_depth += 1;
+            first_index += 1;
+
+            // If the second word is waiting at a number, we need to extend its depth (add "[")
+            if second_is_number {
+                second_extended_depth += 1;
+            }
+        } else if first_word == "]" {
+            if first_extended_depth > 0 {
+                first_extended_depth -= 1;
+            } else {
+                first_depth -= 1;
+                first_index += 1;
+            }
+        }
+
+        if second_word == "[" {
+            second_depth += 1;
+            second_index += 1;
+
+            // If the first word is waiting at a number, we need to extend its depth (add "[")
+            if first_is_number {
+                first_extended_depth += 1;
+            }
+        } else if second_word == "]" {
+            if second_extended_depth > 0 {
+                second_extended_depth -= 1;
+            } else {
+                second_depth -= 1;
+                second_index += 1;
+            }
+        }
+
+        if first_depth + first_extended_depth < second_depth + second_extended_depth {
+            return true;
+        } else if first_depth + first_extended_depth > second_depth + second_extended_depth {
+            return false;
+        }
+
+        if first_is_number && second_is_number {
+            let first_number: i32 = first_word.parse().unwrap();
+            let second_number: i32 = second_word.parse().unwrap();
+            if first_number < second_number {
+                return true;
+            } else if first_number > second_number {
+                return false;
+            } else {
+                if first_extended_depth == 0 {
+                    first_index += 1;
+                }
+                if second_extended_depth == 0 {
+                    second_index += 1;
+                }
+            }
+            first_is_number = false;
+            second_is_number = false;
+        }
+    }
+}
+
+#[derive(Debug)]
+#[derive(Eq)]
+pub struct Packet {
+    pub line: String
+}
+
+// To implement Ord, this also needs to be implemented
+impl PartialEq<Self> for Packet {
+    fn eq(&self, other: &Self) -> bool {
+        self.line == other.line
+    }
+}
+
+// To implement Ord, this also needs to be implemented
+impl PartialOrd<Self> for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+// Define the order of packets so that they can be sorted
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if is_right_order(&self.line, &other.line){
+            return Ordering::Greater;
+        }
+        return Ordering::Less;
+    }
+}
+
+pub fn solve_1(lines: &Vec<String>) -> u32 {
+    let mut sum: u32 = 0;
+    let mut index = 0;
+    while index < lines.len() {
+        let first = &lines[index];
+        let second = &lines[index + 1];
+
+        if is_right_order(first, second) {
+            sum += index as u32 / 3 + 1;
+        }
+        index += 3;
+    }
+    return sum;
+}
+
+pub fn solve_2(lines: &Vec<String>) -> u32 {
+
+    let mut packets: Vec<Packet> = Vec::new();
+
+    for line in lines{
+        if !line.is_empty() {
+            packets.push(Packet{line: line.to_owned()})
+        }
+    }
+
+    packets.push(Packet{line: "[[2]]".parse().unwrap() });
+    packets.push(Packet{line: "[[6]]".parse().unwrap() });
+
+    packets.sort_by(|first, second| second.cmp(first));
+
+    let first_position = packets.iter()
+        .position(|packet| packet.line == "[[2]]").unwrap() as u32;
+
+    let second_position = packets.iter()
+        .position(|packet| packet.line == "[[6]]").unwrap() as u32;
+
+    return (first_position + 1) * (second_position + 1);
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    type Input = Vec<String>;
+    type Config = ();
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(lines: &Vec<String>, _: &()) -> u32 {
+        return solve_1(lines);
+    }
+
+    fn part2(lines: &Vec<String>, _: &()) -> u32 {
+        return solve_2(lines);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day13, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let lines = load::<Day13>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&lines), 13);
+    }
+
+    #[test]
+    fn test_2() {
+        let lines = load::<Day13>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&lines), 140);
+    }
+}
@@ -0,0 +1,48 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day14;
+
+impl Generator for Day14 {
+    /// A scan of `size` rock paths below the source of the sand. The cave is at most 300 deep, so
+    /// that the sand can spread out on the floor without reaching x = 0.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let depth = (20 + 3 * size as i32).min(300);
+        let mut text = String::new();
+        for _ in 0..size {
+            let mut corner = (rng.range(500 - depth..=500 + depth), rng.range(1..=depth));
+            let mut corners = vec![corner];
+            let mut horizontal = rng.chance(0.5);
+            for _ in 0..rng.range(1..=4) {
+                let length = rng.range(1..=8) * if rng.chance(0.5) { 1 } else { -1 };
+                corner = if horizontal {
+                    (corner.0 + length, corner.1)
+                } else {
+                    (corner.0, (corner.1 + length).clamp(1, depth))
+                };
+                corners.push(corner);
+                horizontal = !horizontal;
+            }
+            text += &(corners.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<String>>().join(" -> ") + "\n");
+        }
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day14;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day14>(1, 50);
+        assert_eq!(input, generate::<Day14>(1, 50));
+        assert_eq!(input.text.lines().count(), 50);
+        for seed in 0..10 {
+            let (on_rocks, with_floor) = solve_generated::<Day14>(seed, 10);
+            assert!(matches!((on_rocks, with_floor), (Answer::Number(on_rocks), Answer::Number(with_floor)) if on_rocks < with_floor));
+        }
+    }
+}
@@ -0,0 +1,204 @@
+mod generate;
+mod visualize;
+
+use std::cmp::{max, min};
+
+use aoc_common::grid::Grid;
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+// The sand comes from x = 500 at the top
+const SAND_SOURCE_X: u32 = 500;
+
+/// The most cells the grid of a cave can have. The sand fills a triangle as deep as the floor, so deeper
+/// caves would take too much memory and time.
+pub const MAX_CELLS: u64 = 10_000_000;
+
+// The number of cells in the grid of a cave spanning from `left` to `right` with its lowest rock at `lowest_rock`
+fn cave_cells(left: u64, right: u64, lowest_rock: u64) -> u64 {
+    let floor = lowest_rock + 2;
+    let left = left.min((SAND_SOURCE_X as u64).saturating_sub(floor));
+    let right = right.max(SAND_SOURCE_X as u64 + floor);
+    return (right - left + 1) * (floor + 1);
+}
+
+/// The scanned rocks. The grid starts at x = `left` and is wide and deep enough for the sand to pile up
+/// on the floor, two below the lowest rock.
+#[derive(Debug)]
+pub struct Cave {
+    pub grid: Grid<Tile>,
+    pub left: u32,
+    pub lowest_rock: u32,
+}
+
+impl Cave {
+    /// The position in the grid of the sand source.
+    pub fn source(&self) -> (usize, usize) {
+        return ((SAND_SOURCE_X - self.left) as usize, 0);
+    }
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Cave, ParseError> {
+    let mut rock_lines: Vec<((u32, u32), (u32, u32))> = Vec::new();
+    let (mut left_rock, mut right_rock, mut lowest_rock) = (SAND_SOURCE_X, SAND_SOURCE_X, 0);
+
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+        let mut corners = Vec::new();
+        let mut corner_starts = Vec::new();
+        let mut corner_start = 0;
+        loop {
+            let corner_end = line.find(" -> ", corner_start).unwrap_or(text.len());
+            let comma = line.find(",", corner_start)?;
+            let x: u32 = line.parse(corner_start, comma, "an x coordinate")?;
+            let y: u32 = line.parse(comma + 1, corner_end, "a y coordinate")?;
+            if cave_cells(min(left_rock, x) as u64, max(right_rock, x) as u64, max(lowest_rock, y) as u64) > MAX_CELLS {
+                return Err(line.error_span(corner_start, corner_end, &format!("a corner that keeps the cave below {} cells", MAX_CELLS)));
+            }
+            (left_rock, right_rock, lowest_rock) = (min(left_rock, x), max(right_rock, x), max(lowest_rock, y));
+            corners.push((x, y));
+            corner_starts.push(corner_start);
+            if corner_end == text.len() {
+                break;
+            }
+            corner_start = corner_end + 4;
+        }
+
+        for current_corner in 1..corners.len() {
+            let (x1, y1) = corners[current_corner - 1];
+            let (x2, y2) = corners[current_corner];
+            if x1 != x2 && y1 != y2 {
+                return Err(line.error(corner_starts[current_corner], "a corner in line with the previous one"));
+            }
+            rock_lines.push(((min(x1, x2), min(y1, y2)), (max(x1, x2), max(y1, y2))));
+        }
+    }
+
+    // Sand moves at most one to the side for every unit it falls. The cave is below `MAX_CELLS`, so this fits in 32 bits.
+    let floor = lowest_rock + 2;
+    let left = min(left_rock, SAND_SOURCE_X.saturating_sub(floor));
+    let right = max(right_rock, SAND_SOURCE_X + floor);
+    let mut grid = Grid::new((right - left + 1) as usize, floor as usize + 1, Tile::Air);
+    for ((x1, y1), (x2, y2)) in rock_lines {
+        for x in x1..x2 + 1 {
+            for y in y1..y2 + 1 {
+                grid[((x - left) as usize, y as usize)] = Tile::Rock;
+            }
+        }
+    }
+
+    return Ok(Cave { grid, left, lowest_rock });
+}
+
+fn find_next_sand_position(occupied: &Grid<Tile>, source: (usize, usize), lowest_rock: u32, include_floor: bool) -> Option<(usize, usize)> {
+    let mut sand_pos = source;
+    let floor = lowest_rock as usize + 2;
+    let is_occupied = |position: (usize, usize)| occupied[position] != Tile::Air;
+
+    if is_occupied(sand_pos) {
+        return None;
+    }
+
+    while sand_pos.1 < floor {
+        if !is_occupied((sand_pos.0, sand_pos.1 + 1)) {
+        } else if !is_occupied((sand_pos.0 - 1, sand_pos.1 + 1)) {
+            sand_pos.0 -= 1
+        } else if !is_occupied((sand_pos.0 + 1, sand_pos.1 + 1)) {
+            sand_pos.0 += 1;
+        } else {
+            return Some(sand_pos);
+        }
+        sand_pos.1 += 1
+    }
+
+    return if !include_floor {
+        None
+    } else {
+        Some((sand_pos.0, floor - 1))
+    }
+}
+
+fn fill_sand(cave: &Cave, include_floor: bool) -> u32 {
+    let mut occupied = cave.grid.clone();
+    let lowest_rock = cave.lowest_rock;
+
+    let mut sand_count = 0;
+    loop {
+        let sand_position = find_next_sand_position(&occupied, cave.source(), lowest_rock, include_floor);
+        match sand_position {
+            Some(position) => {
+                sand_count += 1;
+                occupied[position] = Tile::Sand;
+            },
+            None => return sand_count
+        }
+    }
+}
+
+pub fn solve_1(cave: &Cave) -> u32 {
+    return fill_sand(cave, false)
+}
+
+pub fn solve_2(cave: &Cave) -> u32 {
+    return fill_sand(cave, true)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    type Input = Cave;
+    type Config = ();
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Cave, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(cave: &Cave, _: &()) -> u32 {
+        return solve_1(cave);
+    }
+
+    fn part2(cave: &Cave, _: &()) -> u32 {
+        return solve_2(cave);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{parse, Day14, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let cave = load::<Day14>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&cave), 24);
+    }
+
+    #[test]
+    fn test_2() {
+        let cave = load::<Day14>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&cave), 93);
+    }
+
+    #[test]
+    fn test_large_cave() {
+        let error = parse(&vec!["500,2 -> 500,4".to_string(), "500,100000000 -> 501,100000000".to_string()]).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 1: expected a corner that keeps the cave below 10000000 cells, found '500,100000000'");
+        assert!(parse(&vec!["0,5 -> 4294967295,5".to_string()]).is_err());
+        assert_eq!(parse(&vec!["498,2000 -> 502,2000".to_string()]).unwrap().grid.height(), 2003);
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::grid::Grid;
+use aoc_common::visualize::{Animation, Scene, Visualize};
+
+use crate::{find_next_sand_position, Cave, Day14, Tile};
+
+struct SandScene<'a> {
+    cave: &'a Cave,
+    occupied: &'a Grid<Tile>,
+    // The x coordinates of the leftmost and the rightmost rock or sand in the grid
+    x_range: (usize, usize),
+    include_floor: bool,
+    last_sand: Option<(usize, usize)>,
+    caption: String,
+}
+
+impl Scene for SandScene<'_> {
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        let bottom = self.cave.lowest_rock + if self.include_floor { 2 } else { 1 };
+        return ((self.x_range.0 as i64 - 1, 0), (self.x_range.1 as i64 + 1, bottom as i64));
+    }
+
+    fn focus(&self) -> (i64, i64) {
+        let (x, y) = self.last_sand.unwrap_or(self.cave.source());
+        return (x as i64, y as i64);
+    }
+
+    fn cell(&self, x: i64, y: i64) -> char {
+        let position = (x as usize, y as usize);
+        if self.include_floor && y == self.cave.lowest_rock as i64 + 2 {
+            return '#';
+        }
+        return match self.occupied.get(position) {
+            Some(Tile::Rock) => '#',
+            Some(Tile::Sand) => 'o',
+            _ if position == self.cave.source() => '+',
+            _ => '.',
+        };
+    }
+
+    fn caption(&self) -> String {
+        return self.caption.to_owned();
+    }
+}
+
+impl Visualize for Day14 {
+    // A frame for every unit of sand that comes to rest, and one when the sand stops
+    fn visualize(cave: &Cave, _: &(), part: u32, animation: &mut Animation) {
+        let include_floor = part == 2;
+        let mut occupied = cave.grid.clone();
+        let mut sand_count = 0;
+        let mut x_range = cave.grid.iter().filter(|(_, tile)| **tile == Tile::Rock)
+            .fold((cave.source().0, cave.source().0), |(min_x, max_x), (rock, _)| (min_x.min(rock.0), max_x.max(rock.0)));
+
+        loop {
+            let Some(position) = find_next_sand_position(&occupied, cave.source(), cave.lowest_rock, include_floor) else {
+                let end = if include_floor { "the source is blocked" } else { "the sand flows into the abyss" };
+                let caption = format!("{} units of sand came to rest and {}", sand_count, end);
+                animation.frame(&SandScene { cave, occupied: &occupied, x_range, include_floor, last_sand: None, caption });
+                return;
+            };
+            occupied[position] = Tile::Sand;
+            sand_count += 1;
+            x_range = (x_range.0.min(position.0), x_range.1.max(position.0));
+
+            let caption = format!("Unit {} came to rest at {},{}", sand_count, position.0 as u32 + cave.left, position.1);
+            if !animation.frame(&SandScene { cave, occupied: &occupied, x_range, include_floor, last_sand: Some(position), caption }) {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::params::ParamArgs;
+    use aoc_common::visualize::{visualize, Animation, AnimationOptions, FrameStyle};
+
+    use crate::Day14;
+
+    #[test]
+    fn test_visualize() {
+        let mut output = Vec::new();
+        let mut animation = Animation::new(&mut output, FrameStyle::Text, AnimationOptions::default());
+        visualize::<Day14>(&day_input!("test.txt"), 1, &ParamArgs::default(), &mut animation).unwrap();
+        assert_eq!(animation.finish().unwrap(), 25);
+
+        let frames = String::from_utf8(output).unwrap();
+        let last_frame = &frames[frames.rfind("Frame 25").unwrap()..];
+        assert_eq!(last_frame, "Frame 25: 24 units of sand came to rest and the sand flows into the abyss\n\
+            .......+....\n............\n.......o....\n......ooo...\n.....#ooo##.\n....o#ooo#..\n...###ooo#..\n\
+            .....oooo#..\n..o.ooooo#..\n.#########..\n............\n\n");
+    }
+}
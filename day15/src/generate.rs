@@ -0,0 +1,82 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day15};
+
+fn distance(first: (i32, i32), second: (i32, i32)) -> i32 {
+    return (first.0 - second.0).abs() + (first.1 - second.1).abs();
+}
+
+// A position at exactly `distance` from `center`
+fn position_at(rng: &mut Rng, center: (i32, i32), distance: i32) -> (i32, i32) {
+    let x_offset = rng.range(-distance..=distance);
+    let y_offset = (distance - x_offset.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+    return (center.0 + x_offset, center.1 + y_offset);
+}
+
+impl Generator for Day15 {
+    /// Reports of `size` sensors, at least 4, in a search area of 20 * size² squared. The four sensors
+    /// diagonally around the distress beacon cover the whole search area except for it, and the
+    /// other sensors cover random parts of the area around it.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let size = size.max(4);
+        let search_max = 20 * (size * size) as i32;
+        let config = Config { row: rng.range(0..=search_max), search_max };
+        let target = (rng.range(0..=search_max), rng.range(0..=search_max));
+
+        let offset = search_max + 1;
+        let mut sensors = [(-1, -1), (1, -1), (-1, 1), (1, 1)].iter()
+            .map(|(x_sign, y_sign)| {
+                let sensor = (target.0 + x_sign * offset, target.1 + y_sign * offset);
+                (sensor, position_at(rng, sensor, 2 * offset - 1))
+            })
+            .collect::<Vec<((i32, i32), (i32, i32))>>();
+        while sensors.len() < size {
+            let sensor = (rng.range(-search_max / 2..=3 * search_max / 2), rng.range(-search_max / 2..=3 * search_max / 2));
+            if distance(sensor, target) > 1 {
+                let radius = rng.range(1..distance(sensor, target));
+                sensors.push((sensor, position_at(rng, sensor, radius)));
+            }
+        }
+        rng.shuffle(&mut sensors);
+
+        let text = sensors.iter()
+            .map(|(sensor, beacon)| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sensor.0, sensor.1, beacon.0, beacon.1))
+            .collect::<String>();
+        return (text, config);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::params::Params;
+    use aoc_common::solution::Solution;
+
+    use crate::generate::distance;
+    use crate::{Config, Day15};
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day15>(1, 30);
+        assert_eq!(input, generate::<Day15>(1, 30));
+        assert_eq!(input.text.lines().count(), 30);
+
+        for seed in 0..5 {
+            let input = generate::<Day15>(seed, 5);
+            let config = input.config::<Config>();
+            assert_eq!(config.describe()[1].value, "500");
+            let reports = Day15::parse(&input.text).unwrap();
+            Day15::part1(&reports, &config);
+
+            // There is exactly one position that no sensor covers
+            let mut uncovered_count = 0;
+            for x in 0..=config.search_max {
+                for y in 0..=config.search_max {
+                    let is_covered = reports.iter().any(|report| distance((x, y), report.sensor) <= distance(report.beacon, report.sensor));
+                    uncovered_count += usize::from(!is_covered);
+                }
+            }
+            assert_eq!(uncovered_count, 1);
+        }
+    }
+}
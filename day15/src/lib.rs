@@ -0,0 +1,238 @@
+mod generate;
+mod reference;
+
+use std::cmp::{max, min};
+
+use aoc_common::{input, params};
+use aoc_common::params::check_param;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+pub struct SensorReport {
+    pub sensor: (i32, i32),
+    pub beacon: (i32, i32),
+}
+
+// Parse an "x=.., y=.." position between the byte offsets `start` and `end`
+fn parse_position(line: &Line, start: usize, end: usize) -> Result<(i32, i32), ParseError> {
+    let x_start = line.find("x=", start)? + 2;
+    let x_stop = line.find(",", x_start)?;
+    let x: i32 = line.parse(x_start, x_stop, "an x coordinate")?;
+
+    let y_start = line.find("y=", x_stop)? + 2;
+    let y: i32 = line.parse(y_start, end, "a y coordinate")?;
+
+    return Ok((x, y));
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<SensorReport>, ParseError> {
+    let mut reports = Vec::new();
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+
+        let sensor_start = line.expect_prefix("Sensor at ")?;
+        let sensor_end = line.find(":", sensor_start)?;
+        let beacon_start = line.find(" closest beacon is at ", sensor_end)?;
+
+        let sensor_position = parse_position(&line, sensor_start, sensor_end)?;
+        let beacon_position = parse_position(&line, beacon_start, text.len())?;
+
+        reports.push(SensorReport {
+            sensor: sensor_position,
+            beacon: beacon_position,
+        });
+    }
+
+    return Ok(reports);
+}
+
+#[derive(Debug)]
+struct SensorCoverage {
+    sensor: (i32, i32),
+    distance: i32,
+}
+
+fn calculate_distance(first: (i32, i32), second: (i32, i32)) -> i32 {
+    return (second.0 - first.0).abs() + (second.1 - first.1).abs();
+}
+
+pub fn solve_1(sensor_reports: &Vec<SensorReport>, y: i32) -> i32 {
+    let sensor_coverages = sensor_reports.iter().map(|report| {
+        SensorCoverage {
+            sensor: report.sensor,
+            distance: calculate_distance(report.sensor, report.beacon),
+        }
+    }).collect::<Vec<SensorCoverage>>();
+
+    let mut lowest_covered_x = sensor_coverages[0].sensor.0;
+    let mut highest_covered_x = sensor_coverages[0].sensor.0;
+
+    for sensor_coverage in &sensor_coverages {
+        lowest_covered_x = min(
+            lowest_covered_x,
+            sensor_coverage.sensor.0 - sensor_coverage.distance,
+        );
+
+        highest_covered_x = max(
+            highest_covered_x,
+            sensor_coverage.sensor.0 + sensor_coverage.distance,
+        );
+    }
+
+    let mut impossible_position_count = 0;
+
+    for x in lowest_covered_x..=highest_covered_x {
+        let current_position = (x, y);
+
+        let mut is_beacon = false;
+        for sensor_report in sensor_reports {
+            // Check if the current position is on top of a beacon - if so we want to break out
+            if calculate_distance(current_position, sensor_report.beacon) == 0 {
+                is_beacon = true;
+                break;
+            }
+        }
+
+        if !is_beacon {
+            for sensor_coverage in &sensor_coverages {
+                let sensor_distance = calculate_distance(current_position, sensor_coverage.sensor);
+
+                // Check if the current position is inside the coverage of the sensor - if so, we count
+                // it, and also break out to the next position in order to not count it several times
+                if sensor_distance <= sensor_coverage.distance {
+                    impossible_position_count += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    return impossible_position_count;
+}
+
+pub fn solve_2(sensor_reports: &Vec<SensorReport>, x_max: i32, y_max: i32) -> i64 {
+    let sensor_coverages = sensor_reports.iter().map(|report| {
+        SensorCoverage {
+            sensor: report.sensor,
+            distance: calculate_distance(report.sensor, report.beacon),
+        }
+    }).collect::<Vec<SensorCoverage>>();
+
+    for sensor_coverage in &sensor_coverages {
+        let sensor_x = sensor_coverage.sensor.0;
+        let sensor_y = sensor_coverage.sensor.1;
+
+        // Start one step outside the border of the sensor coverage
+        let mut x = sensor_x + sensor_coverage.distance + 1;
+        let mut y = sensor_y;
+
+        loop {
+            // Positions outside the search area are skipped, but the walk goes on around the sensor
+            if x >= 0 && x <= x_max && y >= 0 && y <= y_max {
+                let mut impossible_position = false;
+
+                for other_sensor_coverage in &sensor_coverages {
+                    let sensor_distance = calculate_distance((x, y), other_sensor_coverage.sensor);
+
+                    // Check if the current position is inside the coverage of the sensor - if so, we
+                    // mark it as an impossible position for the hidden beacon and break out
+                    if sensor_distance <= other_sensor_coverage.distance {
+                        impossible_position = true;
+                        break;
+                    }
+                }
+                if !impossible_position {
+                    return (x as i64) * 4_000_000 + (y as i64);
+                }
+            }
+
+            if x > sensor_x && y >= sensor_y {
+                // Move diagonally up left
+                x -= 1;
+                y += 1;
+            } else if x <= sensor_x && y > sensor_y {
+                // Move diagonally down left
+                x -= 1;
+                y -= 1;
+            } else if x < sensor_x {
+                // Move diagonally down right
+                x += 1;
+                y -= 1;
+            } else {
+                // Move diagonally up right
+                x += 1;
+                y += 1;
+            }
+
+            // Check if we moved around the whole sensor
+            if x == sensor_x + sensor_coverage.distance + 1 {
+                break;
+            }
+        }
+    }
+
+    panic!("There is no possible position for the distress beacon!")
+}
+
+params! {
+    pub struct Config {
+        /// The row to count impossible beacon positions in for task 1
+        row: i32 = 2_000_000,
+        /// The highest x and y coordinate the distress beacon can have in task 2
+        search_max: i32 = 4_000_000,
+    }
+
+    validate(config) {
+        check_param("search_max", config.search_max >= 0, "at least 0", config.search_max)
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    type Input = Vec<SensorReport>;
+    type Config = Config;
+    type Output1 = i32;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<SensorReport>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(sensor_reports: &Vec<SensorReport>, config: &Config) -> i32 {
+        return solve_1(sensor_reports, config.row);
+    }
+
+    fn part2(sensor_reports: &Vec<SensorReport>, config: &Config) -> i64 {
+        return solve_2(sensor_reports, config.search_max, config.search_max);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::ParseError;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day15, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let sensor_reports = load::<Day15>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&sensor_reports, 10), 26);
+    }
+
+    #[test]
+    fn test_2() {
+        let sensor_reports = load::<Day15>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&sensor_reports, 20, 20), 56_000_011);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day15::parse("Sensor at x=2, y=18: closest beacon is at x=-2, y=1five").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 51, "a y coordinate", "1five"));
+    }
+}
@@ -0,0 +1,56 @@
+use aoc_common::reference::Reference;
+
+use crate::{Config, Day15, SensorReport};
+
+fn distance(first: (i32, i32), second: (i32, i32)) -> i32 {
+    return (first.0 - second.0).abs() + (first.1 - second.1).abs();
+}
+
+fn is_covered(sensor_reports: &Vec<SensorReport>, position: (i32, i32)) -> bool {
+    return sensor_reports.iter().any(|report| distance(position, report.sensor) <= distance(report.beacon, report.sensor));
+}
+
+impl Reference for Day15 {
+    // Check every position of the row
+    fn reference_part1(sensor_reports: &Vec<SensorReport>, config: &Config) -> i32 {
+        let min_x = sensor_reports.iter().map(|report| report.sensor.0 - distance(report.beacon, report.sensor)).min().unwrap();
+        let max_x = sensor_reports.iter().map(|report| report.sensor.0 + distance(report.beacon, report.sensor)).max().unwrap();
+        return (min_x..=max_x)
+            .filter(|x| is_covered(sensor_reports, (*x, config.row)))
+            .filter(|x| !sensor_reports.iter().any(|report| report.beacon == (*x, config.row)))
+            .count() as i32;
+    }
+
+    // Go through every row, jumping to the end of the range of every sensor that covers a position
+    fn reference_part2(sensor_reports: &Vec<SensorReport>, config: &Config) -> i64 {
+        for y in 0..=config.search_max {
+            let mut x = 0;
+            while x <= config.search_max {
+                let covering_report = sensor_reports.iter().find(|report| distance((x, y), report.sensor) <= distance(report.beacon, report.sensor));
+                match covering_report {
+                    Some(report) => x = report.sensor.0 + distance(report.beacon, report.sensor) - (y - report.sensor.1).abs() + 1,
+                    None => return x as i64 * 4_000_000 + y as i64,
+                }
+            }
+        }
+        panic!("There is no possible position for the distress beacon!")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::reference::{find_smallest_disagreement, Reference};
+    use aoc_common::solution::load;
+
+    use crate::{Config, Day15};
+
+    #[test]
+    fn test_reference() {
+        let sensor_reports = load::<Day15>(&day_input!("test.txt")).unwrap();
+        let config = Config { row: 10, search_max: 20 };
+        assert_eq!(Day15::reference_part1(&sensor_reports, &config), 26);
+        assert_eq!(Day15::reference_part2(&sensor_reports, &config), 56_000_011);
+        assert_eq!(find_smallest_disagreement::<Day15>(&[4, 6, 8], 0, 10), None);
+    }
+}
@@ -0,0 +1,81 @@
+use aoc_common::explore::{Command, Explore};
+use aoc_common::search::{bfs, SearchSpace};
+
+use crate::{Config, Day16, PipeNetwork};
+
+fn describe(pipe_network: &PipeNetwork, name: &str) -> Result<String, String> {
+    let valve = pipe_network.valves.get(name).ok_or(format!("There is no valve {}", name))?;
+    return Ok(format!("Valve {} has flow rate={}; tunnels lead to valves {}", name, valve.flow_rate, valve.neighbors.join(", ")));
+}
+
+// Walking through the tunnels to a valve, a minute per tunnel
+struct Tunnels<'a> {
+    pipe_network: &'a PipeNetwork,
+    to: &'a str,
+}
+
+impl SearchSpace for Tunnels<'_> {
+    type State = String;
+
+    fn neighbours(&self, valve: &String) -> Vec<(String, u64)> {
+        return self.pipe_network.valves[valve].neighbors.iter().map(|neighbor| (neighbor.to_owned(), 1)).collect();
+    }
+
+    fn is_goal(&self, valve: &String) -> bool {
+        return valve == self.to;
+    }
+}
+
+impl Explore for Day16 {
+    fn show(pipe_network: &PipeNetwork) -> String {
+        let mut names = pipe_network.valves.keys().collect::<Vec<&String>>();
+        names.sort();
+        return names.iter().map(|name| describe(pipe_network, name).unwrap()).collect::<Vec<String>>().join("\n");
+    }
+
+    fn commands() -> Vec<Command> {
+        return vec![
+            Command { usage: "valve <NAME>", description: "Print the flow rate and tunnels of a valve" },
+            Command { usage: "path <FROM> <TO>", description: "Print a shortest walk through the tunnels between two valves" },
+        ];
+    }
+
+    fn run(pipe_network: &mut PipeNetwork, _: &Config, name: &str, args: &[&str]) -> Result<String, String> {
+        if name == "valve" {
+            let [valve] = args else {
+                return Err("Usage: valve <NAME>".to_string());
+            };
+            return describe(pipe_network, valve);
+        }
+
+        let [from, to] = args else {
+            return Err("Usage: path <FROM> <TO>".to_string());
+        };
+        for valve in [from, to] {
+            describe(pipe_network, valve)?;
+        }
+        return match bfs(&Tunnels { pipe_network, to }, [from.to_string()]).path {
+            Some(path) => Ok(format!("{} ({} minutes)", path.states.join(" -> "), path.cost)),
+            None => Ok(format!("No tunnels lead from {} to {}", from, to)),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::explore::explore_session;
+    use aoc_common::params::ParamArgs;
+
+    use crate::Day16;
+
+    #[test]
+    fn test_explore() {
+        let mut session = explore_session::<Day16>(&day_input!("test.txt"), &ParamArgs::default()).unwrap();
+        assert_eq!(session.execute("valve JJ"), Ok("Valve JJ has flow rate=21; tunnels lead to valves II".to_string()));
+        assert_eq!(session.execute("path AA JJ"), Ok("AA -> II -> JJ (2 minutes)".to_string()));
+        assert_eq!(session.execute("valve ZZ"), Err("There is no valve ZZ".to_string()));
+        assert!(session.execute("show").unwrap().starts_with("Valve AA has flow rate=0; tunnels lead to valves DD, II, BB\n"));
+        assert!(session.execute("part 1 minutes_1=3 start=BB").unwrap().starts_with("26 ("));
+    }
+}
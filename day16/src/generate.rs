@@ -0,0 +1,80 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day16};
+
+// As many valves with a flow rate as in the puzzle inputs, which the solution needs to finish in time
+const MAX_FLOWING_VALVES: usize = 15;
+
+fn valve_name(index: usize) -> String {
+    return [(b'A' + (index / 26) as u8) as char, (b'A' + (index % 26) as u8) as char].iter().collect();
+}
+
+impl Generator for Day16 {
+    /// A scan of `size` valves, between 2 and 676, connected by tunnels so that every valve can be
+    /// reached from valve AA. About a third of the valves other than AA have a flow rate, at least one and
+    /// at most 15.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let valve_count = size.clamp(2, 26 * 26);
+        // AA is valve 0, the others get random names
+        let mut names = (1..26 * 26).collect::<Vec<usize>>();
+        rng.shuffle(&mut names);
+        let names = [vec![0], names[..valve_count - 1].to_vec()].concat().into_iter().map(valve_name).collect::<Vec<String>>();
+
+        // A random tree of tunnels, with some shortcuts
+        let mut neighbors = vec![Vec::new(); valve_count];
+        let mut connect = |first: usize, second: usize| {
+            if first != second && !neighbors[first].contains(&second) {
+                neighbors[first].push(second);
+                neighbors[second].push(first);
+            }
+        };
+        for valve in 1..valve_count {
+            connect(valve, rng.range(0..valve));
+        }
+        for _ in 0..valve_count / 3 {
+            connect(rng.range(0..valve_count), rng.range(0..valve_count));
+        }
+
+        let mut order = (0..valve_count).collect::<Vec<usize>>();
+        rng.shuffle(&mut order);
+        let mut flowing_valves = (1..valve_count).collect::<Vec<usize>>();
+        rng.shuffle(&mut flowing_valves);
+        flowing_valves.truncate((valve_count / 3).clamp(1, MAX_FLOWING_VALVES));
+        let mut text = String::new();
+        for valve in order {
+            let flow_rate = if flowing_valves.contains(&valve) { rng.range(1..=25) } else { 0 };
+            let tunnels = neighbors[valve].iter().map(|neighbor| names[*neighbor].to_owned()).collect::<Vec<String>>();
+            let (tunnel_text, valve_text) = if tunnels.len() == 1 { ("tunnel leads", "valve") } else { ("tunnels lead", "valves") };
+            text += &format!("Valve {} has flow rate={}; {} to {} {}\n", names[valve], flow_rate, tunnel_text, valve_text, tunnels.join(", "));
+        }
+        return (text, Config::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day16;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day16>(1, 60);
+        assert_eq!(input, generate::<Day16>(1, 60));
+        assert_eq!(input.text.lines().count(), 60);
+        assert!(input.text.contains("Valve AA has flow rate=0;"));
+        for seed in 0..5 {
+            let (alone, with_elephant) = solve_generated::<Day16>(seed, 8);
+            assert!(matches!((alone, with_elephant), (Answer::Number(alone), Answer::Number(both)) if alone > 0 && both > 0));
+        }
+
+        // Larger inputs have no more valves with a flow rate than the puzzle inputs, so they solve too
+        for size in [100, 1000] {
+            let flowing_valves = generate::<Day16>(0, size).text.lines().filter(|line| !line.contains("rate=0;")).count();
+            assert_eq!(flowing_valves, 15);
+        }
+        let (alone, with_elephant) = solve_generated::<Day16>(0, 30);
+        assert!(matches!((alone, with_elephant), (Answer::Number(alone), Answer::Number(both)) if alone > 0 && both > alone));
+    }
+}
@@ -0,0 +1,250 @@
+mod explore;
+mod generate;
+mod reference;
+
+use std::cmp::Reverse;
+use std::collections::HashMap;
+
+use aoc_common::{input, params};
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+pub struct Valve {
+    pub flow_rate: u32,
+    pub neighbors: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct PipeNetwork {
+    pub valves: HashMap<String, Valve>,
+}
+
+#[derive(Debug)]
+struct PipeNetworkAction {
+    next_valve: String,
+    additional_pressure_release: u32,
+}
+
+// A set of the valves with a flow rate, as a bit per valve in the order of `FlowingValves`
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq, Eq, Hash)]
+struct OpenValves {
+    bits: Vec<u64>,
+}
+
+impl OpenValves {
+    fn none(valve_count: usize) -> OpenValves {
+        return OpenValves { bits: vec![0; valve_count.div_ceil(64)] };
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        return self.bits[index / 64] & (1 << (index % 64)) != 0;
+    }
+
+    fn with(&self, index: usize) -> OpenValves {
+        let mut open_valves = self.clone();
+        open_valves.bits[index / 64] |= 1 << (index % 64);
+        return open_valves;
+    }
+
+    fn is_disjoint(&self, other: &OpenValves) -> bool {
+        return self.bits.iter().zip(&other.bits).all(|(bits, other_bits)| bits & other_bits == 0);
+    }
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<PipeNetwork, ParseError> {
+    let mut valves: HashMap<String, Valve> = HashMap::new();
+    let mut neighbor_positions = Vec::new();
+
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+        line.expect_word(0, "Valve")?;
+        let valve_name = line.word(1, "a valve name")?.text.to_owned();
+
+        let equal_sign_index = line.find("=", 0)?;
+        let colon_sign_index = line.find(";", equal_sign_index)?;
+        let flow_rate: u32 = line.parse(equal_sign_index + 1, colon_sign_index, "a flow rate")?;
+
+        let tunnel_words = line.words().into_iter()
+            .skip_while(|word| word.start <= colon_sign_index)
+            .collect::<Vec<_>>();
+        if tunnel_words.len() < 5 || !tunnel_words[3].text.starts_with("valve") {
+            return Err(line.error(colon_sign_index + 1, "'tunnels lead to valves'"));
+        }
+        let mut neighbors = Vec::new();
+        for word in &tunnel_words[4..] {
+            let neighbor = word.text.strip_suffix(',').unwrap_or(word.text);
+            neighbors.push(neighbor.to_owned());
+            neighbor_positions.push((line, word.start, neighbor.to_owned()));
+        }
+
+        let valve = Valve { flow_rate, neighbors };
+        valves.insert(valve_name, valve);
+    }
+
+    // Tunnels can only lead to valves that are described in the input
+    for (line, start, neighbor) in neighbor_positions {
+        if !valves.contains_key(&neighbor) {
+            return Err(line.error(start, "the name of a valve in the input"));
+        }
+    }
+    return Ok(PipeNetwork { valves });
+}
+
+fn get_action_candidates(pipe_network: &PipeNetwork, current_valve: &str, minutes_left: u32, can_open_valve: bool) -> Vec<PipeNetworkAction> {
+    let mut action_candidates: Vec<PipeNetworkAction> = Vec::new();
+
+    if can_open_valve {
+        let flow_rate = pipe_network.valves.get(current_valve).unwrap().flow_rate;
+        let additional_pressure_release = flow_rate * minutes_left;
+
+        if additional_pressure_release > 0 {
+            let open_valve = PipeNetworkAction {
+                next_valve: current_valve.to_owned(),
+                additional_pressure_release,
+            };
+            action_candidates.push(open_valve);
+        }
+    }
+
+    for neighbor in &pipe_network.valves.get(current_valve).unwrap().neighbors {
+        let move_valve = PipeNetworkAction {
+            next_valve: neighbor.to_owned(),
+            additional_pressure_release: 0,
+        };
+        action_candidates.push(move_valve);
+    }
+
+    return action_candidates;
+}
+
+// The highest pressure release for every set of valves that can be opened alone in time
+fn highest_releases(pipe_network: &PipeNetwork, start: &str, minutes: u32) -> HashMap<OpenValves, u32> {
+    assert!(pipe_network.valves.contains_key(start), "There is no valve {} to start at", start);
+
+    let mut flowing_valves = pipe_network.valves.iter()
+        .filter(|(_, valve)| valve.flow_rate > 0)
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<&str>>();
+    flowing_valves.sort();
+    let valve_indices = flowing_valves.iter().enumerate().map(|(index, name)| (*name, index)).collect::<HashMap<&str, usize>>();
+
+    // Only the best pressure release is kept for each set of open valves and current valve
+    let no_open_valves = OpenValves::none(flowing_valves.len());
+    let mut states: HashMap<(OpenValves, String), u32> = HashMap::from([((no_open_valves.clone(), start.to_string()), 0)]);
+    let mut highest_releases: HashMap<OpenValves, u32> = HashMap::from([(no_open_valves, 0)]);
+
+    for minutes_left in (1..minutes).rev() {
+        let mut next_states: HashMap<(OpenValves, String), u32> = HashMap::new();
+
+        for ((open_valves, valve), pressure_release) in &states {
+            let valve_index = valve_indices.get(valve.as_str()).copied();
+            let can_open_valve = valve_index.is_some_and(|index| !open_valves.contains(index));
+
+            for action in get_action_candidates(pipe_network, valve, minutes_left, can_open_valve) {
+                let updated_open_valves = match valve_index {
+                    Some(index) if action.additional_pressure_release > 0 => open_valves.with(index),
+                    _ => open_valves.clone(),
+                };
+                let updated_pressure_release = pressure_release + action.additional_pressure_release;
+
+                let highest_release = highest_releases.entry(updated_open_valves.clone()).or_insert(0);
+                *highest_release = (*highest_release).max(updated_pressure_release);
+                let best_state_release = next_states.entry((updated_open_valves, action.next_valve)).or_insert(0);
+                *best_state_release = (*best_state_release).max(updated_pressure_release);
+            }
+        }
+        states = next_states;
+    }
+
+    return highest_releases;
+}
+
+pub fn solve_1(pipe_network: &PipeNetwork, start: &str, minutes: u32) -> u32 {
+    return *highest_releases(pipe_network, start, minutes).values().max().unwrap();
+}
+
+pub fn solve_2(pipe_network: &PipeNetwork, start: &str, minutes: u32) -> u32 {
+    // You and the elephant open different valves, so the best is the best pair of sets without a common valve
+    let mut releases = highest_releases(pipe_network, start, minutes).into_iter().collect::<Vec<(OpenValves, u32)>>();
+    releases.sort_by_key(|(_, release)| Reverse(*release));
+
+    let mut highest_release: u32 = 0;
+    for (index, (first_open_valves, first_release)) in releases.iter().enumerate() {
+        if first_release * 2 <= highest_release {
+            break;
+        }
+        for (second_open_valves, second_release) in &releases[index..] {
+            if first_release + second_release <= highest_release {
+                break;
+            }
+            if first_open_valves.is_disjoint(second_open_valves) {
+                highest_release = first_release + second_release;
+                break;
+            }
+        }
+    }
+    return highest_release;
+}
+
+params! {
+    pub struct Config {
+        /// The valve everyone starts at
+        start: String = "AA".to_string(),
+        /// The minutes until the volcano erupts in task 1
+        minutes_1: u32 = 30,
+        /// The minutes left in task 2, after teaching the elephant
+        minutes_2: u32 = 26,
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    type Input = PipeNetwork;
+    type Config = Config;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<PipeNetwork, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(pipe_network: &PipeNetwork, config: &Config) -> u32 {
+        return solve_1(pipe_network, &config.start, config.minutes_1);
+    }
+
+    fn part2(pipe_network: &PipeNetwork, config: &Config) -> u32 {
+        return solve_2(pipe_network, &config.start, config.minutes_2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::ParseError;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day16, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let data = load::<Day16>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&data, "AA", 30), 1651);
+    }
+
+    #[test]
+    fn test_2() {
+        let data = load::<Day16>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&data, "AA", 26), 1707);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let error = Day16::parse("Valve AA has flow rate=0; tunnels lead to valves BB, CC\nValve BB has flow rate=13; tunnel leads to valve AA").unwrap_err();
+        assert_eq!(error, ParseError::new(1, 54, "the name of a valve in the input", "CC"));
+    }
+}
@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use aoc_common::reference::Reference;
+
+use crate::{Config, Day16, PipeNetwork};
+
+// The valves by index in name order, with their flow rates and the indices of their neighbors
+struct Valves {
+    names: Vec<String>,
+    flow_rates: Vec<u32>,
+    neighbors: Vec<Vec<usize>>,
+}
+
+impl Valves {
+    fn new(pipe_network: &PipeNetwork) -> Valves {
+        let mut names = pipe_network.valves.keys().cloned().collect::<Vec<String>>();
+        names.sort();
+        assert!(names.len() <= 64, "The reference solution can only open 64 valves");
+        let index = |name: &String| names.binary_search(name).unwrap();
+        let flow_rates = names.iter().map(|name| pipe_network.valves[name].flow_rate).collect();
+        let neighbors = names.iter().map(|name| pipe_network.valves[name].neighbors.iter().map(index).collect()).collect();
+        return Valves { names, flow_rates, neighbors };
+    }
+
+    fn index(&self, name: &str) -> usize {
+        return self.names.iter().position(|other| other == name).unwrap_or_else(|| panic!("There is no valve {} to start at", name));
+    }
+
+    // The moves of someone at `valve`: opening it, if it is closed and has a flow, or walking to a neighbor.
+    // An opened valve is given as the released pressure and the opened valves afterwards.
+    fn moves(&self, valve: usize, opened: u64, minutes_left: u32) -> Vec<(usize, u32, u64)> {
+        let mut moves = self.neighbors[valve].iter().map(|neighbor| (*neighbor, 0, opened)).collect::<Vec<_>>();
+        if opened & (1 << valve) == 0 && self.flow_rates[valve] > 0 {
+            moves.push((valve, self.flow_rates[valve] * (minutes_left - 1), opened | (1 << valve)));
+        }
+        return moves;
+    }
+}
+
+// Try every move in every minute, remembering the best release of every state
+fn most_release_alone(valves: &Valves, valve: usize, opened: u64, minutes_left: u32, memo: &mut HashMap<(usize, u64, u32), u32>) -> u32 {
+    if minutes_left == 0 {
+        return 0;
+    }
+    if let Some(release) = memo.get(&(valve, opened, minutes_left)) {
+        return *release;
+    }
+    let mut best = 0;
+    for (next_valve, release, next_opened) in valves.moves(valve, opened, minutes_left) {
+        best = best.max(release + most_release_alone(valves, next_valve, next_opened, minutes_left - 1, memo));
+    }
+    memo.insert((valve, opened, minutes_left), best);
+    return best;
+}
+
+fn most_release_together(valves: &Valves, positions: (usize, usize), opened: u64, minutes_left: u32, memo: &mut HashMap<((usize, usize), u64, u32), u32>) -> u32 {
+    if minutes_left == 0 {
+        return 0;
+    }
+    // It does not matter who stands where
+    let positions = (positions.0.min(positions.1), positions.0.max(positions.1));
+    if let Some(release) = memo.get(&(positions, opened, minutes_left)) {
+        return *release;
+    }
+    let mut best = 0;
+    for (my_valve, my_release, my_opened) in valves.moves(positions.0, opened, minutes_left) {
+        for (elephant_valve, elephant_release, elephant_opened) in valves.moves(positions.1, my_opened, minutes_left) {
+            // Both cannot open the same valve
+            if my_release > 0 && elephant_release > 0 && my_valve == elephant_valve {
+                continue;
+            }
+            let release = my_release + elephant_release + most_release_together(valves, (my_valve, elephant_valve), my_opened | elephant_opened, minutes_left - 1, memo);
+            best = best.max(release);
+        }
+    }
+    memo.insert((positions, opened, minutes_left), best);
+    return best;
+}
+
+impl Reference for Day16 {
+    fn reference_part1(pipe_network: &PipeNetwork, config: &Config) -> u32 {
+        let valves = Valves::new(pipe_network);
+        return most_release_alone(&valves, valves.index(&config.start), 0, config.minutes_1, &mut HashMap::new());
+    }
+
+    fn reference_part2(pipe_network: &PipeNetwork, config: &Config) -> u32 {
+        let valves = Valves::new(pipe_network);
+        let start = valves.index(&config.start);
+        return most_release_together(&valves, (start, start), 0, config.minutes_2, &mut HashMap::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::reference::{find_smallest_disagreement, Reference};
+    use aoc_common::solution::load;
+
+    use crate::{Config, Day16};
+
+    #[test]
+    fn test_reference() {
+        let pipe_network = load::<Day16>(&day_input!("test.txt")).unwrap();
+        let config = Config::default();
+        assert_eq!(Day16::reference_part1(&pipe_network, &config), 1651);
+        assert_eq!(Day16::reference_part2(&pipe_network, &config), 1707);
+        assert_eq!(find_smallest_disagreement::<Day16>(&[3, 4, 6, 8], 0, 10), None);
+    }
+}
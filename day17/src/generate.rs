@@ -0,0 +1,31 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day17};
+
+impl Generator for Day17 {
+    /// A jet pattern of `size` pushes, at least 2.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let jets = (0..size.max(2)).map(|_| *rng.choose(&['<', '>'])).collect::<String>();
+        return (jets + "\n", Config::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day17;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day17>(1, 1000);
+        assert_eq!(input, generate::<Day17>(1, 1000));
+        assert_eq!(input.text.trim_end().len(), 1000);
+        for seed in 0..3 {
+            // Every rock adds at most 4 to the height of the tower
+            let (height, _) = solve_generated::<Day17>(seed, 40);
+            assert!(matches!(height, Answer::Number(height) if (2022..=4 * 2022).contains(&height)));
+        }
+    }
+}
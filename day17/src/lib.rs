@@ -0,0 +1,223 @@
+mod generate;
+mod reference;
+mod visualize;
+
+use std::cmp::max;
+use std::collections::HashMap;
+
+use aoc_common::grid::Grid;
+use aoc_common::{input, params};
+use aoc_common::params::check_param;
+use aoc_common::parse::{get_line, ParseError};
+use aoc_common::solution::Solution;
+
+pub fn parse(lines: &Vec<String>) -> Result<String, ParseError> {
+    let line = get_line(lines, 0, "a jet pattern")?;
+    if line.text.is_empty() {
+        return Err(line.error(0, "a jet pattern"));
+    }
+    if let Some((position, jet)) = line.text.char_indices().find(|(_, jet)| *jet != '<' && *jet != '>') {
+        return Err(line.error_span(position, position + jet.len_utf8(), "'<' or '>'"));
+    }
+    return Ok(line.text.to_owned());
+}
+
+// The number of rows at the top of the tower that must be the same for the pattern to repeat
+const COMPARED_ROWS: u64 = 30;
+
+/// The narrowest chamber rocks fit in: they appear two units from the left wall, and the widest is four units wide.
+pub const MIN_WIDTH: u32 = 6;
+
+fn get_shape(round: u64) -> Vec<(u32, u64)> {
+    match round % 5 {
+        0 => Vec::from([(0, 0), (1, 0), (2, 0), (3, 0)]), // Flat line shape
+        1 => Vec::from([(0, 1), (1, 0), (1, 1), (1, 2), (2, 1)]), // Plus shape
+        2 => Vec::from([(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)]), // Backwards L shape
+        3 => Vec::from([(0, 0), (0, 1), (0, 2), (0, 3)]), // Vertical line shape
+        4 => Vec::from([(0, 0), (0, 1), (1, 1), (1, 0)]), // Square shape
+        _ => panic!("Not a valid shape")
+    }
+}
+
+// The chamber with only its floor, which is the bottom row. The grid grows upwards as rocks come to rest.
+fn new_chamber(width: u32) -> Grid<bool> {
+    assert!(width >= MIN_WIDTH, "The chamber is {} units wide, but rocks need at least {}", width, MIN_WIDTH);
+    return Grid::new(width as usize, 1, true);
+}
+
+fn is_rock(stationary_rocks: &Grid<bool>, (x, y): (u32, u64)) -> bool {
+    return stationary_rocks[(x as usize, y as usize)];
+}
+
+fn run_simulation(jet_pattern: &str, right_wall: u32, rounds: u64) -> u64 {
+    let jet_pattern_length = jet_pattern.len();
+    let mut jet_index: usize = 0;
+
+    let mut height: u64 = 0;
+
+    let mut stationary_rocks = new_chamber(right_wall);
+
+    // The round and height at which every combination of shape, jet and top rows of the tower was first seen
+    let mut seen_states: HashMap<(u64, usize, Vec<bool>), (u64, u64)> = HashMap::new();
+    let mut added_height = 0;
+    let mut round = 0;
+
+    while round < rounds {
+        let mut rock = get_shape(round);
+        while (stationary_rocks.height() as u64) < height + 8 {
+            stationary_rocks.push_row(vec![false; right_wall as usize]);
+        }
+
+        for rock_part in rock.iter_mut() {
+            rock_part.0 += 2;
+            rock_part.1 += 4 + height;
+        }
+
+        let mut is_falling = true;
+
+        while is_falling {
+            let jet_direction = jet_pattern.chars().nth(jet_index).unwrap();
+            if jet_index < jet_pattern_length - 1 {
+                jet_index += 1;
+            } else {
+                jet_index = 0;
+            }
+
+            let mut can_move_sideways = true;
+            if jet_direction == '>' {
+                for rock_part in rock.iter() {
+                    if (rock_part.0 == right_wall - 1) || is_rock(&stationary_rocks, (rock_part.0 + 1, rock_part.1)) {
+                        can_move_sideways = false;
+                    }
+                }
+
+                if can_move_sideways {
+                    for rock_part in rock.iter_mut() {
+                        rock_part.0 += 1;
+                    }
+                }
+            } else if jet_direction == '<' {
+                for rock_part in rock.iter() {
+                    if (rock_part.0 == 0) || is_rock(&stationary_rocks, (rock_part.0 - 1, rock_part.1)) {
+                        can_move_sideways = false;
+                    }
+                }
+                if can_move_sideways {
+                    for rock_part in rock.iter_mut() {
+                        rock_part.0 -= 1;
+                    }
+                }
+            }
+
+            for rock_part in rock.iter() {
+                if is_rock(&stationary_rocks, (rock_part.0, rock_part.1 - 1)) {
+                    is_falling = false;
+                }
+            }
+            if is_falling {
+                for rock_part in rock.iter_mut() {
+                    rock_part.1 -= 1;
+                }
+            } else {
+                for rock_part in rock.iter() {
+                    height = max(height, rock_part.1);
+                    stationary_rocks[(rock_part.0 as usize, rock_part.1 as usize)] = true;
+                }
+
+                if added_height == 0 && height >= COMPARED_ROWS {
+                    let top_rows = (height + 1 - COMPARED_ROWS..=height)
+                        .flat_map(|y| stationary_rocks.row(y as usize).copied())
+                        .collect::<Vec<bool>>();
+                    if let Some((first_round, first_height)) = seen_states.insert((round % 5, jet_index, top_rows), (round, height)) {
+                        // The pattern repeats. Use the round difference and height difference of this
+                        // pattern to calculate the height of all the remaining repetitions, and then drop
+                        // the rocks after the last repetition.
+
+                        let round_difference = round - first_round;
+                        let height_difference = height - first_height;
+                        let remaining_rounds = (rounds - round - 1) % round_difference;
+                        let multiplication_factor = (rounds - round - 1 - remaining_rounds) / round_difference;
+
+                        round += multiplication_factor * round_difference;
+                        added_height = height_difference * multiplication_factor;
+                    }
+                }
+
+                round += 1;
+            }
+        }
+    }
+    return height + added_height;
+}
+
+pub fn solve_1(jet_pattern: &str, width: u32, rounds: u64) -> u64 {
+    return run_simulation(jet_pattern, width, rounds);
+}
+
+pub fn solve_2(jet_pattern: &str, width: u32, rounds: u64) -> u64 {
+    return run_simulation(jet_pattern, width, rounds);
+}
+
+params! {
+    pub struct Config {
+        /// The width of the chamber, at least `MIN_WIDTH`
+        width: u32 = 7,
+        /// The number of rocks to drop in task 1
+        rounds_1: u64 = 2022,
+        /// The number of rocks to drop in task 2
+        rounds_2: u64 = 1_000_000_000_000,
+    }
+
+    validate(config) {
+        check_param("width", config.width >= MIN_WIDTH, &format!("at least {}", MIN_WIDTH), config.width)
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    type Input = String;
+    type Config = Config;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(jet_pattern: &String, config: &Config) -> u64 {
+        return solve_1(jet_pattern, config.width, config.rounds_1);
+    }
+
+    fn part2(jet_pattern: &String, config: &Config) -> u64 {
+        return solve_2(jet_pattern, config.width, config.rounds_2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day17, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let jet_pattern = load::<Day17>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&jet_pattern, 7, 2022), 3068);
+    }
+
+    #[test]
+    #[should_panic(expected = "The chamber is 5 units wide, but rocks need at least 6")]
+    fn test_narrow_chamber() {
+        solve_1(">>><<", 5, 10);
+    }
+
+    #[test]
+    fn test_2() {
+        let jet_pattern = load::<Day17>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&jet_pattern, 7, 1_000_000_000_000), 1514285714288);
+    }
+}
+
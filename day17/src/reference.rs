@@ -0,0 +1,69 @@
+use std::collections::HashSet;
+
+use aoc_common::reference::Reference;
+
+use crate::{get_shape, Config, Day17};
+
+// Drop every rock one by one, without looking for repetitions
+fn tower_height(jet_pattern: &str, width: u32, rounds: u64) -> u64 {
+    let jets = jet_pattern.chars().collect::<Vec<char>>();
+    let mut jet_index = 0;
+    let mut rocks: HashSet<(u32, u64)> = HashSet::new();
+    let mut height = 0;
+    let is_free = |rocks: &HashSet<(u32, u64)>, rock: &Vec<(u32, u64)>| rock.iter().all(|part| part.0 < width && part.1 > 0 && !rocks.contains(part));
+
+    for round in 0..rounds {
+        let mut rock = get_shape(round).into_iter().map(|(x, y)| (x + 2, y + height + 4)).collect::<Vec<(u32, u64)>>();
+        loop {
+            let pushed = match jets[jet_index % jets.len()] {
+                '<' if rock.iter().all(|part| part.0 > 0) => rock.iter().map(|(x, y)| (x - 1, *y)).collect(),
+                '>' => rock.iter().map(|(x, y)| (x + 1, *y)).collect(),
+                _ => rock.clone(),
+            };
+            jet_index += 1;
+            if is_free(&rocks, &pushed) {
+                rock = pushed;
+            }
+
+            let fallen = rock.iter().map(|(x, y)| (*x, y - 1)).collect();
+            if !is_free(&rocks, &fallen) {
+                break;
+            }
+            rock = fallen;
+        }
+        height = height.max(rock.iter().map(|part| part.1).max().unwrap());
+        rocks.extend(rock);
+    }
+    return height;
+}
+
+impl Reference for Day17 {
+    // Simulating a trillion rocks takes too long
+    fn reference_config(config: Config) -> Config {
+        return Config { rounds_2: config.rounds_2.min(5000), ..config };
+    }
+
+    fn reference_part1(jet_pattern: &String, config: &Config) -> u64 {
+        return tower_height(jet_pattern, config.width, config.rounds_1);
+    }
+
+    fn reference_part2(jet_pattern: &String, config: &Config) -> u64 {
+        return tower_height(jet_pattern, config.width, config.rounds_2);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::reference::{find_smallest_disagreement, Reference};
+    use aoc_common::solution::load;
+
+    use crate::{Config, Day17};
+
+    #[test]
+    fn test_reference() {
+        let jet_pattern = load::<Day17>(&day_input!("test.txt")).unwrap();
+        assert_eq!(Day17::reference_part1(&jet_pattern, &Config::default()), 3068);
+        assert_eq!(find_smallest_disagreement::<Day17>(&[2, 3, 5], 0, 3), None);
+    }
+}
@@ -0,0 +1,120 @@
+use aoc_common::grid::Grid;
+use aoc_common::visualize::{Animation, Scene, Visualize};
+
+use crate::{get_shape, new_chamber, Config, Day17};
+
+// The chamber with its walls and floor, with y downwards so the tower grows towards negative y
+struct Chamber<'a> {
+    rocks: &'a Grid<bool>,
+    falling_rock: &'a Vec<(u32, u64)>,
+    width: u32,
+    height: u64,
+    caption: String,
+}
+
+impl Scene for Chamber<'_> {
+    fn bounds(&self) -> ((i64, i64), (i64, i64)) {
+        return ((0, -(self.height as i64) - 7), (self.width as i64 + 1, 0));
+    }
+
+    fn focus(&self) -> (i64, i64) {
+        let (x, y) = self.falling_rock[0];
+        return (x as i64 + 1, -(y as i64));
+    }
+
+    fn cell(&self, x: i64, y: i64) -> char {
+        let is_wall = x == 0 || x == self.width as i64 + 1;
+        if y == 0 {
+            return if is_wall { '+' } else { '-' };
+        }
+        if is_wall {
+            return '|';
+        }
+        if self.falling_rock.contains(&(x as u32 - 1, -y as u64)) {
+            return '@';
+        }
+        return if self.rocks.get((x as usize - 1, -y as usize)) == Some(&true) { '#' } else { '.' };
+    }
+
+    fn caption(&self) -> String {
+        return self.caption.to_owned();
+    }
+}
+
+impl Visualize for Day17 {
+    // A frame for every push of a jet, after which the rock falls one unit if it can. Part 2 drops
+    // `rounds_2` rocks, so it runs until it has drawn `--max-frames` frames.
+    fn visualize(jet_pattern: &String, config: &Config, part: u32, animation: &mut Animation) {
+        let rounds = if part == 1 { config.rounds_1 } else { config.rounds_2 };
+        let width = config.width;
+        let jets = jet_pattern.chars().collect::<Vec<char>>();
+        let mut jet_index = 0;
+        let mut rocks = new_chamber(width);
+        let mut height = 0;
+        let is_free = |rocks: &Grid<bool>, rock: &Vec<(u32, u64)>| rock.iter().all(|part| part.0 < width && !rocks[(part.0 as usize, part.1 as usize)]);
+
+        for round in 0..rounds {
+            while (rocks.height() as u64) < height + 8 {
+                rocks.push_row(vec![false; width as usize]);
+            }
+            let mut rock = get_shape(round).into_iter().map(|(x, y)| (x + 2, y + height + 4)).collect::<Vec<(u32, u64)>>();
+            loop {
+                let jet = jets[jet_index % jets.len()];
+                let pushed = match jet {
+                    '<' if rock.iter().all(|part| part.0 > 0) => rock.iter().map(|(x, y)| (x - 1, *y)).collect(),
+                    '>' => rock.iter().map(|(x, y)| (x + 1, *y)).collect(),
+                    _ => rock.clone(),
+                };
+                jet_index += 1;
+                if is_free(&rocks, &pushed) {
+                    rock = pushed;
+                }
+
+                let fallen = rock.iter().map(|(x, y)| (*x, y - 1)).collect();
+                let is_falling = is_free(&rocks, &fallen);
+                if is_falling {
+                    rock = fallen;
+                }
+                let caption = format!("Rock {}/{}, jet {} {}, tower height {}", round + 1, rounds, jet_index, jet, height);
+                if !animation.frame(&Chamber { rocks: &rocks, falling_rock: &rock, width, height, caption }) {
+                    return;
+                }
+                if !is_falling {
+                    break;
+                }
+            }
+            height = height.max(rock.iter().map(|part| part.1).max().unwrap());
+            for (x, y) in rock {
+                rocks[(x as usize, y as usize)] = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::params::ParamArgs;
+    use aoc_common::visualize::{visualize, Animation, AnimationOptions, FrameStyle};
+
+    use crate::Day17;
+
+    #[test]
+    fn test_visualize() {
+        let mut output = Vec::new();
+        let options = AnimationOptions { max_frames: Some(8), ..AnimationOptions::default() };
+        let mut animation = Animation::new(&mut output, FrameStyle::Text, options);
+        let mut params = ParamArgs::default();
+        params.add_override("rounds_2=2").unwrap();
+        visualize::<Day17>(&day_input!("test.txt"), 2, &params, &mut animation).unwrap();
+        assert_eq!(animation.finish().unwrap(), 8);
+
+        // The second rock appears after the first has come to rest, like in the puzzle
+        let frames = String::from_utf8(output).unwrap();
+        assert!(frames.contains("Frame 4: Rock 1/2, jet 4 <, tower height 0\n|.......|\n|.......|\n\
+            |.......|\n|.......|\n|.......|\n|.......|\n|..@@@@.|\n+-------+\n\n"));
+        let last_frame = &frames[frames.rfind("Frame 8").unwrap()..];
+        assert_eq!(last_frame, "Frame 8: Rock 2/2, jet 8 >, tower height 1\n|.......|\n|.......|\n|.......|\n\
+            |.......|\n|...@...|\n|..@@@..|\n|...@...|\n|..####.|\n+-------+\n\n");
+    }
+}
@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day18;
+
+impl Generator for Day18 {
+    /// A droplet of `size` cubes, grown from a single cube so that it has pockets of air inside.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let side = ((size as f64).cbrt() * 2.0).ceil() as u32 + 2;
+        let center = side / 2;
+        let mut cubes = vec![(center, center, center)];
+        let mut occupied = HashSet::from([(center, center, center)]);
+        while cubes.len() < size.min((side * side * side) as usize) {
+            let (x, y, z) = *rng.choose(&cubes);
+            let neighbor = match rng.range(0..6) {
+                0 => (x.wrapping_sub(1), y, z),
+                1 => (x + 1, y, z),
+                2 => (x, y.wrapping_sub(1), z),
+                3 => (x, y + 1, z),
+                4 => (x, y, z.wrapping_sub(1)),
+                _ => (x, y, z + 1),
+            };
+            if neighbor.0 < side && neighbor.1 < side && neighbor.2 < side && occupied.insert(neighbor) {
+                cubes.push(neighbor);
+            }
+        }
+
+        rng.shuffle(&mut cubes);
+        let text = cubes.iter().map(|(x, y, z)| format!("{},{},{}\n", x, y, z)).collect::<String>();
+        return (text, ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+    use aoc_common::solution::Answer;
+
+    use crate::Day18;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day18>(1, 500);
+        assert_eq!(input, generate::<Day18>(1, 500));
+        assert_eq!(input.text.lines().count(), 500);
+        for seed in 0..5 {
+            // The outside of the droplet is part of its surface
+            let (surface, outside) = solve_generated::<Day18>(seed, 100);
+            assert!(matches!((surface, outside), (Answer::Number(surface), Answer::Number(outside)) if outside <= surface));
+        }
+    }
+}
@@ -0,0 +1,196 @@
+mod generate;
+
+use std::cmp::max;
+use std::collections::HashSet;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::search::{dfs, SearchSpace};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+struct Boulder {
+    cubes: HashSet<(u32, u32, u32)>,
+    sides: u32,
+    encompassing_cube: (u32, u32, u32),
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<(u32, u32, u32)>, ParseError> {
+    let mut cubes = Vec::new();
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+        let first_comma = line.find(",", 0)?;
+        let second_comma = line.find(",", first_comma + 1)?;
+        let x: u32 = line.parse(0, first_comma, "an x coordinate")?;
+        let y: u32 = line.parse(first_comma + 1, second_comma, "a y coordinate")?;
+        let z: u32 = line.parse_rest(second_comma + 1, "a z coordinate")?;
+        cubes.push((x, y, z));
+    }
+    return Ok(cubes);
+}
+
+fn calculate_boulder(cubes: &Vec<(u32, u32, u32)>) -> Boulder {
+    let mut visited_cubes: HashSet<(u32, u32, u32)> = HashSet::new();
+    let mut sides = 0;
+
+    let mut max_x = 0;
+    let mut max_y = 0;
+    let mut max_z = 0;
+
+    for cube in cubes {
+        let mut new_sides = 6;
+
+        if cube.0 > 0 && visited_cubes.contains(&(cube.0 - 1, cube.1, cube.2)) {
+            new_sides -= 2;
+        }
+        if visited_cubes.contains(&(cube.0 + 1, cube.1, cube.2)) {
+            new_sides -= 2;
+        }
+        if cube.1 > 0 && visited_cubes.contains(&(cube.0, cube.1 - 1, cube.2)) {
+            new_sides -= 2;
+        }
+        if visited_cubes.contains(&(cube.0, cube.1 + 1, cube.2)) {
+            new_sides -= 2;
+        }
+        if cube.2 > 0 && visited_cubes.contains(&(cube.0, cube.1, cube.2 - 1)) {
+            new_sides -= 2;
+        }
+        if visited_cubes.contains(&(cube.0, cube.1, cube.2 + 1)) {
+            new_sides -= 2;
+        }
+
+        visited_cubes.insert(*cube);
+        sides += new_sides;
+
+        max_x = max(max_x, cube.0);
+        max_y = max(max_y, cube.1);
+        max_z = max(max_z, cube.2);
+    }
+
+    return Boulder {
+        cubes: visited_cubes,
+        sides: sides as u32,
+        encompassing_cube: (max_x, max_y, max_z),
+    };
+}
+
+// The air around the boulder, which reaches the outside at the sides of the encompassing cube
+struct Air<'a> {
+    cubes: &'a HashSet<(u32, u32, u32)>,
+    encompassing_cube: (u32, u32, u32),
+}
+
+impl SearchSpace for Air<'_> {
+    type State = (u32, u32, u32);
+
+    // Only called for cubes inside the encompassing cube, so the neighbours do not go below 0
+    fn neighbours(&self, cube: &(u32, u32, u32)) -> Vec<((u32, u32, u32), u64)> {
+        let neighbors = [
+            (cube.0 - 1, cube.1, cube.2),
+            (cube.0 + 1, cube.1, cube.2),
+            (cube.0, cube.1 - 1, cube.2),
+            (cube.0, cube.1 + 1, cube.2),
+            (cube.0, cube.1, cube.2 - 1),
+            (cube.0, cube.1, cube.2 + 1),
+        ];
+        return neighbors.into_iter().filter(|neighbor| !self.cubes.contains(neighbor)).map(|neighbor| (neighbor, 1)).collect();
+    }
+
+    fn is_goal(&self, cube: &(u32, u32, u32)) -> bool {
+        return cube.0 == 0 || cube.0 == self.encompassing_cube.0 ||
+            cube.1 == 0 || cube.1 == self.encompassing_cube.1 ||
+            cube.2 == 0 || cube.2 == self.encompassing_cube.2;
+    }
+}
+
+fn is_internal(cube: (u32, u32, u32), cubes: &HashSet<(u32, u32, u32)>, encompassing_cube: (u32, u32, u32)) -> bool {
+    return dfs(&Air { cubes, encompassing_cube }, [cube]).path.is_none();
+}
+
+pub fn solve_1(cubes: &Vec<(u32, u32, u32)>) -> u32 {
+    return calculate_boulder(cubes).sides;
+}
+
+pub fn solve_2(cubes: &Vec<(u32, u32, u32)>) -> u32 {
+    let boulder = calculate_boulder(cubes);
+
+    let mut sides = boulder.sides;
+
+    let max_x = boulder.encompassing_cube.0;
+    let max_y = boulder.encompassing_cube.1;
+    let max_z = boulder.encompassing_cube.2;
+
+    for x in 1..max_x {
+        for y in 1..max_y {
+            for z in 1..max_z {
+                let current_cube = (x, y, z);
+
+                if !boulder.cubes.contains(&current_cube) &&
+                    is_internal(current_cube, &boulder.cubes, boulder.encompassing_cube) {
+                    if cubes.contains(&(current_cube.0 - 1, current_cube.1, current_cube.2)) {
+                        sides -= 1;
+                    }
+                    if cubes.contains(&(current_cube.0 + 1, current_cube.1, current_cube.2)) {
+                        sides -= 1;
+                    }
+                    if cubes.contains(&(current_cube.0, current_cube.1 - 1, current_cube.2)) {
+                        sides -= 1;
+                    }
+                    if cubes.contains(&(current_cube.0, current_cube.1 + 1, current_cube.2)) {
+                        sides -= 1;
+                    }
+                    if cubes.contains(&(current_cube.0, current_cube.1, current_cube.2 - 1)) {
+                        sides -= 1;
+                    }
+                    if cubes.contains(&(current_cube.0, current_cube.1, current_cube.2 + 1)) {
+                        sides -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    return sides;
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    type Input = Vec<(u32, u32, u32)>;
+    type Config = ();
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<(u32, u32, u32)>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(cubes: &Vec<(u32, u32, u32)>, _: &()) -> u32 {
+        return solve_1(cubes);
+    }
+
+    fn part2(cubes: &Vec<(u32, u32, u32)>, _: &()) -> u32 {
+        return solve_2(cubes);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day18, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let cubes = load::<Day18>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&cubes), 64);
+    }
+
+    #[test]
+    fn test_2() {
+        let cubes = load::<Day18>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&cubes), 58);
+    }
+}
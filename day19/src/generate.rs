@@ -0,0 +1,32 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day19};
+
+impl Generator for Day19 {
+    /// `size` blueprints with costs in the ranges of the puzzle.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let mut text = String::new();
+        for id in 1..=size.max(1) {
+            text += &format!("Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. \
+                              Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                             id, rng.range(2..=4), rng.range(2..=4), rng.range(2..=4), rng.range(5..=20), rng.range(2..=4), rng.range(7..=20));
+        }
+        return (text, Config::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+
+    use crate::Day19;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day19>(1, 30);
+        assert_eq!(input, generate::<Day19>(1, 30));
+        assert_eq!(input.text.lines().count(), 30);
+        assert!(input.text.lines().all(|line| line.matches("costs").count() == 4));
+        solve_generated::<Day19>(2, 2);
+    }
+}
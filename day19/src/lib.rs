@@ -0,0 +1,262 @@
+mod generate;
+
+use aoc_common::{input, params};
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::search::{dfs_with_pruning, SearchSpace};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+pub struct Blueprint {
+    pub id: u32,
+    pub ore_for_ore_robot: u32,
+    pub ore_for_clay_robot: u32,
+    pub ore_for_obsidian_robot: u32,
+    pub clay_for_obsidian_robot: u32,
+    pub ore_for_geode_robot: u32,
+    pub obsidian_for_geode_robot: u32,
+}
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq, Eq, Hash)]
+struct Resources {
+    ore_robots: u32,
+    clay_robots: u32,
+    obsidian_robots: u32,
+    geode_robots: u32,
+    ores: u32,
+    clay: u32,
+    obsidian: u32,
+    geodes: u32,
+}
+
+// Parse the amount between `prefix` and `unit`, returning it with the offset after the unit
+fn parse_cost(line: &Line, from: usize, prefix: &str, unit: &str) -> Result<(u32, usize), ParseError> {
+    let cost_start = line.find(prefix, from)? + prefix.len();
+    let cost_end = line.find(unit, cost_start)?;
+    let expected = format!("a number of{}", unit);
+    let cost = line.parse::<u32>(cost_start, cost_end, &expected)?;
+    return Ok((cost, cost_end + unit.len()));
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<Blueprint>, ParseError> {
+    return lines.iter().enumerate().map(|(line_num, text)| {
+        let line = Line::new(line_num, text);
+        let blueprint_id_start = line.expect_prefix("Blueprint ")?;
+        let blueprint_id_end = line.find(":", blueprint_id_start)?;
+        let blueprint_id = line.parse::<u32>(blueprint_id_start, blueprint_id_end, "a blueprint id")?;
+
+        let (ore_for_ore, ore_end) = parse_cost(&line, blueprint_id_end, "Each ore robot costs ", " ore")?;
+        let (ore_for_clay, clay_end) = parse_cost(&line, ore_end, "Each clay robot costs ", " ore")?;
+        let (ore_for_obsidian, obsidian_ore_end) = parse_cost(&line, clay_end, "Each obsidian robot costs ", " ore")?;
+        let (clay_for_obsidian, obsidian_end) = parse_cost(&line, obsidian_ore_end, "and ", " clay")?;
+        let (ore_for_geode, geode_ore_end) = parse_cost(&line, obsidian_end, "Each geode robot costs ", " ore")?;
+        let (obsidian_for_geode, _) = parse_cost(&line, geode_ore_end, "and ", " obsidian")?;
+
+        Ok(Blueprint {
+            id: blueprint_id,
+            ore_for_ore_robot: ore_for_ore,
+            ore_for_clay_robot: ore_for_clay,
+            ore_for_obsidian_robot: ore_for_obsidian,
+            clay_for_obsidian_robot: clay_for_obsidian,
+            ore_for_geode_robot: ore_for_geode,
+            obsidian_for_geode_robot: obsidian_for_geode,
+        })
+    }).collect::<Result<Vec<Blueprint>, ParseError>>();
+}
+
+// The robots that can be built with the resources of every minute. The geodes opened in a minute are the cost of a step.
+struct RobotFactory<'a> {
+    blueprint: &'a Blueprint,
+    max_minutes: u32,
+}
+
+impl SearchSpace for RobotFactory<'_> {
+    type State = (u32, Resources);
+
+    fn neighbours(&self, (minute, resources): &(u32, Resources)) -> Vec<((u32, Resources), u64)> {
+        let blueprint = self.blueprint;
+        let next_minute = minute + 1;
+        let opened_geodes = resources.geode_robots as u64;
+        let mut next_states = Vec::new();
+
+        // Building a geode robot first finds many geodes early, which prunes the most
+        if resources.ores >= blueprint.ore_for_geode_robot &&
+            resources.obsidian >= blueprint.obsidian_for_geode_robot &&
+            (resources.ores < blueprint.ore_for_geode_robot + resources.ore_robots ||
+                resources.obsidian < blueprint.obsidian_for_geode_robot + resources.obsidian_robots) {
+            next_states.push((
+                next_minute,
+                Resources {
+                    ores: resources.ores - blueprint.ore_for_geode_robot + resources.ore_robots,
+                    clay: resources.clay + resources.clay_robots,
+                    obsidian: resources.obsidian - blueprint.obsidian_for_geode_robot + resources.obsidian_robots,
+                    geodes: resources.geodes + resources.geode_robots,
+                    geode_robots: resources.geode_robots + 1,
+                    ..*resources
+                }
+            ));
+        }
+
+        if resources.ores >= blueprint.ore_for_obsidian_robot &&
+            resources.clay >= blueprint.clay_for_obsidian_robot &&
+            (resources.ores < blueprint.ore_for_obsidian_robot + resources.ore_robots ||
+                resources.clay < blueprint.clay_for_obsidian_robot + resources.clay_robots) {
+            next_states.push((
+                next_minute,
+                Resources {
+                    ores: resources.ores - blueprint.ore_for_obsidian_robot + resources.ore_robots,
+                    clay: resources.clay - blueprint.clay_for_obsidian_robot + resources.clay_robots,
+                    obsidian: resources.obsidian + resources.obsidian_robots,
+                    geodes: resources.geodes + resources.geode_robots,
+                    obsidian_robots: resources.obsidian_robots + 1,
+                    ..*resources
+                }
+            ));
+        }
+
+        if resources.ores >= blueprint.ore_for_clay_robot &&
+            resources.ores < blueprint.ore_for_clay_robot + resources.ore_robots {
+            next_states.push((
+                next_minute,
+                Resources {
+                    ores: resources.ores - blueprint.ore_for_clay_robot + resources.ore_robots,
+                    clay: resources.clay + resources.clay_robots,
+                    obsidian: resources.obsidian + resources.obsidian_robots,
+                    geodes: resources.geodes + resources.geode_robots,
+                    clay_robots: resources.clay_robots + 1,
+                    ..*resources
+                }
+            ));
+        }
+
+        if resources.ores >= blueprint.ore_for_ore_robot &&
+            resources.ores < blueprint.ore_for_ore_robot + resources.ore_robots {
+            next_states.push((
+                next_minute,
+                Resources {
+                    ores: resources.ores - blueprint.ore_for_ore_robot + resources.ore_robots,
+                    clay: resources.clay + resources.clay_robots,
+                    obsidian: resources.obsidian + resources.obsidian_robots,
+                    geodes: resources.geodes + resources.geode_robots,
+                    ore_robots: resources.ore_robots + 1,
+                    ..*resources
+                }
+            ));
+        }
+
+        next_states.push((
+            next_minute,
+            Resources {
+                ores: resources.ores + resources.ore_robots,
+                clay: resources.clay + resources.clay_robots,
+                obsidian: resources.obsidian + resources.obsidian_robots,
+                geodes: resources.geodes + resources.geode_robots,
+                ..*resources
+            }
+        ));
+
+        return next_states.into_iter().map(|next_state| (next_state, opened_geodes)).collect();
+    }
+
+    fn is_goal(&self, (minute, _): &(u32, Resources)) -> bool {
+        return *minute >= self.max_minutes;
+    }
+}
+
+fn find_max_geodes(blueprint: &Blueprint, max_minutes: u32) -> u32 {
+    let start = Resources {
+        ore_robots: 1,
+        clay_robots: 0,
+        obsidian_robots: 0,
+        geode_robots: 0,
+        ores: 0,
+        clay: 0,
+        obsidian: 0,
+        geodes: 0,
+    };
+
+    // At most, a geode robot is built every remaining minute
+    let max_possible_geodes = |(minute, resources): &(u32, Resources)| {
+        let remaining_minutes = (max_minutes - minute) as u64;
+        return remaining_minutes * resources.geode_robots as u64 + remaining_minutes * remaining_minutes.saturating_sub(1) / 2;
+    };
+    let search = dfs_with_pruning(&RobotFactory { blueprint, max_minutes }, (0, start), max_possible_geodes);
+    return search.cost().expect("Simulation did not end!") as u32;
+}
+
+pub fn solve_1(blueprints: &Vec<Blueprint>) -> u32 {
+    let mut total_quality_level = 0;
+    for blueprint in blueprints {
+        total_quality_level += blueprint.id * find_max_geodes(blueprint, 24);
+    }
+
+    return total_quality_level;
+}
+
+pub fn solve_2(blueprints: &Vec<Blueprint>, open_count: usize) -> u32 {
+    let mut multiplied_geode_count = 1;
+
+    for blueprint in blueprints.iter().take(open_count) {
+        multiplied_geode_count *= find_max_geodes(blueprint, 32);
+    }
+
+    return multiplied_geode_count;
+}
+
+params! {
+    pub struct Config {
+        /// The number of blueprints that are left in task 2
+        open_count: usize = 3,
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    type Input = Vec<Blueprint>;
+    type Config = Config;
+    type Output1 = u32;
+    type Output2 = u32;
+
+    fn parse(input: &str) -> Result<Vec<Blueprint>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(blueprints: &Vec<Blueprint>, _: &Config) -> u32 {
+        return solve_1(blueprints);
+    }
+
+    fn part2(blueprints: &Vec<Blueprint>, config: &Config) -> u32 {
+        return solve_2(blueprints, config.open_count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::parse::ParseError;
+    use aoc_common::solution::{load, Solution};
+
+    use crate::{Day19, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let blueprints = load::<Day19>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&blueprints), 33);
+    }
+
+    #[test]
+    fn test_2() {
+        let blueprints = load::<Day19>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&blueprints, 2), 56 * 62);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let blueprint = "Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs two ore.";
+        let error = Day19::parse(blueprint).unwrap_err();
+        assert_eq!(error, ParseError::new(1, 64, "a number of ore", "two"));
+    }
+}
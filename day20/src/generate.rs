@@ -0,0 +1,32 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::{Config, Day20};
+
+impl Generator for Day20 {
+    /// An encrypted file of `size` numbers, at least 2, with exactly one 0.
+    fn generate(rng: &mut Rng, size: usize) -> (String, Config) {
+        let mut numbers = (1..size.max(2)).map(|_| {
+            let number = rng.range(1..=10_000i64);
+            if rng.chance(0.5) { -number } else { number }
+        }).collect::<Vec<i64>>();
+        numbers.insert(rng.range(0..=numbers.len()), 0);
+        return (numbers.iter().map(|number| format!("{}\n", number)).collect(), Config::default());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::{generate, solve_generated};
+
+    use crate::Day20;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day20>(1, 1000);
+        assert_eq!(input, generate::<Day20>(1, 1000));
+        assert_eq!(input.text.lines().filter(|line| *line == "0").count(), 1);
+        for seed in 0..10 {
+            solve_generated::<Day20>(seed, 50);
+        }
+    }
+}
@@ -0,0 +1,145 @@
+mod generate;
+mod reference;
+
+use aoc_common::{input, params};
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<(usize, i64)>, ParseError> {
+    let code = lines.iter().enumerate().map(|(line_number, text)| {
+        let number = Line::new(line_number, text).parse_rest::<i64>(0, "a number")?;
+        Ok((line_number, number))
+    }).collect::<Result<Vec<(usize, i64)>, ParseError>>()?;
+
+    // Mixing needs at least two numbers, and the grove coordinates are counted from the 0
+    if code.len() < 2 || !code.iter().any(|(_, number)| *number == 0) {
+        return Err(ParseError::new(lines.len() + 1, 1, "at least two numbers including a 0", ""));
+    }
+    return Ok(code);
+}
+
+fn mix(original_code: &Vec<(usize, i64)>, decryption_key: i64, mix_count: u32) -> Vec<(usize, i64)> {
+    let mut mixed_code = original_code.clone();
+    let length = mixed_code.len();
+
+    for order in 0..length {
+        mixed_code[order] = (mixed_code[order].0, mixed_code[order].1 * decryption_key);
+    }
+
+    for _ in 0..mix_count {
+        for order in 0..length {
+            let (index, number) = mixed_code[order];
+
+            let raw_updated_index = index as i64 + number;
+            let updated_index = if raw_updated_index < 0 || raw_updated_index > (length - 1) as i64 {
+                raw_updated_index.rem_euclid((length - 1) as i64) as usize
+            } else {
+                raw_updated_index as usize
+            };
+
+            mixed_code[order] = (updated_index, number);
+
+            for adjustment_order in 0..length {
+                if adjustment_order != order {
+                    let not_adjusted_index = mixed_code[adjustment_order].0;
+                    if updated_index > index {
+                        if not_adjusted_index > index && not_adjusted_index <= updated_index {
+                            mixed_code[adjustment_order] = (not_adjusted_index - 1, mixed_code[adjustment_order].1);
+                        }
+                    } else if updated_index < index && not_adjusted_index >= updated_index && not_adjusted_index < index {
+                        mixed_code[adjustment_order] = (not_adjusted_index + 1, mixed_code[adjustment_order].1);
+                    }
+                }
+            }
+        }
+    }
+    return mixed_code;
+}
+
+fn find_coordinates(mixed_code: &Vec<(usize, i64)>) -> i64 {
+    let length = mixed_code.len();
+
+    for code_entry in mixed_code {
+        if code_entry.1 == 0 {
+            let zero_position = code_entry.0;
+
+            let thousandth = (zero_position + 1000) % length;
+            let twothousandth = (zero_position + 2000) % length;
+            let threethousandth = (zero_position + 3000) % length;
+
+            let mut coordinates = 0;
+            for coordinate_candidate in mixed_code {
+                if coordinate_candidate.0 == thousandth ||
+                    coordinate_candidate.0 == twothousandth ||
+                    coordinate_candidate.0 == threethousandth {
+                    coordinates += coordinate_candidate.1;
+                }
+            }
+
+            return coordinates;
+        }
+    }
+
+    panic!("Could not find the 0 in the code")
+}
+
+pub fn solve_1(code: &Vec<(usize, i64)>) -> i64 {
+    let mixed_code = mix(code, 1, 1);
+    return find_coordinates(&mixed_code);
+}
+
+pub fn solve_2(code: &Vec<(usize, i64)>, decryption_key: i64, mix_count: u32) -> i64 {
+    let mixed_code = mix(code, decryption_key, mix_count);
+    return find_coordinates(&mixed_code);
+}
+
+params! {
+    pub struct Config {
+        /// The number every value is multiplied with before mixing in task 2
+        decryption_key: i64 = 811_589_153,
+        /// How often the numbers are mixed in task 2
+        mix_count: u32 = 10,
+    }
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    type Input = Vec<(usize, i64)>;
+    type Config = Config;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<(usize, i64)>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(code: &Vec<(usize, i64)>, _: &Config) -> i64 {
+        return solve_1(code);
+    }
+
+    fn part2(code: &Vec<(usize, i64)>, config: &Config) -> i64 {
+        return solve_2(code, config.decryption_key, config.mix_count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day20, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let coordinates = load::<Day20>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&coordinates), 3);
+    }
+
+    #[test]
+    fn test_2() {
+        let coordinates = load::<Day20>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&coordinates, 811_589_153, 10), 1623178306);
+    }
+}
@@ -0,0 +1,50 @@
+use aoc_common::reference::Reference;
+
+use crate::{Config, Day20};
+
+// Move every number by removing it from the list and inserting it again
+fn mix(code: &Vec<(usize, i64)>, decryption_key: i64, mix_count: u32) -> Vec<i64> {
+    let mut numbers = code.iter().map(|(order, number)| (*order, number * decryption_key)).collect::<Vec<(usize, i64)>>();
+    let length = numbers.len() as i64;
+    for _ in 0..mix_count {
+        for order in 0..code.len() {
+            let index = numbers.iter().position(|(number_order, _)| *number_order == order).unwrap();
+            let number = numbers.remove(index);
+            let updated_index = (index as i64 + number.1).rem_euclid(length - 1);
+            numbers.insert(updated_index as usize, number);
+        }
+    }
+    return numbers.into_iter().map(|(_, number)| number).collect();
+}
+
+fn grove_coordinates(numbers: &Vec<i64>) -> i64 {
+    let zero_index = numbers.iter().position(|number| *number == 0).unwrap();
+    return [1000, 2000, 3000].iter().map(|offset| numbers[(zero_index + offset) % numbers.len()]).sum();
+}
+
+impl Reference for Day20 {
+    fn reference_part1(code: &Vec<(usize, i64)>, _: &Config) -> i64 {
+        return grove_coordinates(&mix(code, 1, 1));
+    }
+
+    fn reference_part2(code: &Vec<(usize, i64)>, config: &Config) -> i64 {
+        return grove_coordinates(&mix(code, config.decryption_key, config.mix_count));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::reference::{find_smallest_disagreement, Reference};
+    use aoc_common::solution::load;
+
+    use crate::{Config, Day20};
+
+    #[test]
+    fn test_reference() {
+        let code = load::<Day20>(&day_input!("test.txt")).unwrap();
+        assert_eq!(Day20::reference_part1(&code, &Config::default()), 3);
+        assert_eq!(Day20::reference_part2(&code, &Config::default()), 1623178306);
+        assert_eq!(find_smallest_disagreement::<Day20>(&[2, 3, 4, 5, 10, 50], 0, 10), None);
+    }
+}
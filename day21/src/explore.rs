@@ -0,0 +1,61 @@
+use aoc_common::explore::{Command, Explore};
+
+use crate::{yelled_number, Day21, MonkeyJob};
+
+fn find<'a>(monkey_jobs: &'a mut Vec<MonkeyJob>, monkey: &str) -> Result<&'a mut MonkeyJob, String> {
+    return monkey_jobs.iter_mut().find(|monkey_job| monkey_job.monkey == monkey).ok_or(format!("There is no monkey {}", monkey));
+}
+
+impl Explore for Day21 {
+    fn show(monkey_jobs: &Vec<MonkeyJob>) -> String {
+        return monkey_jobs.iter().map(|monkey_job| format!("{}: {}", monkey_job.monkey, monkey_job.job)).collect::<Vec<String>>().join("\n");
+    }
+
+    fn commands() -> Vec<Command> {
+        return vec![
+            Command { usage: "value <MONKEY>", description: "Print the number a monkey yells, e.g. value pppw" },
+            Command { usage: "job <MONKEY>", description: "Print the job of a monkey" },
+            Command { usage: "yell <MONKEY> <NUMBER>", description: "Change the job of a monkey to yelling a number, e.g. to try a number for humn" },
+        ];
+    }
+
+    fn run(monkey_jobs: &mut Vec<MonkeyJob>, _: &(), name: &str, args: &[&str]) -> Result<String, String> {
+        match (name, args) {
+            ("value", [monkey]) => {
+                find(monkey_jobs, monkey)?;
+                return Ok(yelled_number(monkey_jobs, monkey).to_string());
+            }
+            ("job", [monkey]) => return Ok(find(monkey_jobs, monkey)?.job.to_owned()),
+            ("yell", [monkey, number]) => {
+                let number = number.parse::<i64>().map_err(|_| format!("'{}' is not a number", number))?;
+                find(monkey_jobs, monkey)?.job = number.to_string();
+                return Ok(String::new());
+            }
+            _ => return Err(format!("Usage: {}", Day21::commands().iter().find(|command| command.name() == name).unwrap().usage)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::explore::explore_session;
+    use aoc_common::params::ParamArgs;
+
+    use crate::Day21;
+
+    #[test]
+    fn test_explore() {
+        let mut session = explore_session::<Day21>(&day_input!("test.txt"), &ParamArgs::default()).unwrap();
+        assert_eq!(session.execute("value pppw"), Ok("2".to_string()));
+        assert_eq!(session.execute("value sjmn"), Ok("150".to_string()));
+        assert_eq!(session.execute("job root"), Ok("pppw + sjmn".to_string()));
+        assert_eq!(session.execute("value root"), Ok("152".to_string()));
+        assert_eq!(session.execute("value abcd"), Err("There is no monkey abcd".to_string()));
+
+        // The number to yell in part 2 makes both numbers root waits for equal
+        session.execute("yell humn 301").unwrap();
+        assert_eq!(session.execute("value pppw"), session.execute("value sjmn"));
+        assert_eq!(session.execute("yell humn"), Err("Usage: yell <MONKEY> <NUMBER>".to_string()));
+    }
+}
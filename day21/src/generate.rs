@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Day21;
+
+struct TreeBuilder<'a> {
+    rng: &'a mut Rng,
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+fn gcd(first: i64, second: i64) -> i64 {
+    return if second == 0 { first.abs() } else { gcd(second, first % second) };
+}
+
+impl TreeBuilder<'_> {
+    fn new_name(&mut self) -> String {
+        loop {
+            let name = self.rng.word(4..=4);
+            if name != "root" && name != "humn" && self.names.insert(name.to_owned()) {
+                return name;
+            }
+        }
+    }
+
+    fn add_operation(&mut self, first: &str, operator: char, second: &str) -> String {
+        let name = self.new_name();
+        self.jobs.push(format!("{}: {} {} {}", name, first, operator, second));
+        return name;
+    }
+
+    // A monkey that yells exactly `value`, with a tree of at most `budget` monkeys below it
+    fn constant(&mut self, value: i64, budget: usize) -> String {
+        if budget < 3 {
+            let name = self.new_name();
+            self.jobs.push(format!("{}: {}", name, value));
+            return name;
+        }
+        let first_budget = self.rng.range(1..=budget - 2);
+        let second_budget = budget - 1 - first_budget;
+        let divisors = (2..=9).filter(|divisor| value != 0 && value % divisor == 0).collect::<Vec<i64>>();
+        // Like in the puzzle, the monkeys that yell a number yell a positive one where possible
+        let (first, operator, second) = match self.rng.range(0..4) {
+            choice if choice == 1 || value < 2 => {
+                let subtrahend = self.rng.range(1..=10) + (1 - value).max(0);
+                (value + subtrahend, '-', subtrahend)
+            }
+            2 if !divisors.is_empty() => {
+                let divisor = *self.rng.choose(&divisors);
+                (value / divisor, '*', divisor)
+            }
+            3 if value.abs() < 1_000_000_000_000 => {
+                let divisor = self.rng.range(2..=5);
+                (value * divisor, '/', divisor)
+            }
+            _ => {
+                let part = self.rng.range(1..value);
+                (part, '+', value - part)
+            }
+        };
+        let first = self.constant(first, first_budget);
+        let second = self.constant(second, second_budget);
+        if (operator == '+' || operator == '*') && self.rng.chance(0.5) {
+            return self.add_operation(&second, operator, &first);
+        }
+        return self.add_operation(&first, operator, &second);
+    }
+}
+
+impl Generator for Day21 {
+    /// Jobs for about `size` monkeys. The number of humn enters root through a chain of operations
+    /// that only divide when the division is exact for any number, so that task 2 has an integer answer.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let path_length = (size / 20).clamp(1, 60);
+        let subtree_budget = (size.saturating_sub(2 * path_length + 2) / (path_length + 1)).max(1);
+        let original_humn = rng.range(1..=5000i64);
+        let answer = rng.range(1..=1_000_000i64);
+        let mut builder = TreeBuilder { rng, names: HashSet::new(), jobs: vec![format!("humn: {}", original_humn)] };
+
+        // The monkey on the chain yells factor * humn + offset
+        let (mut factor, mut offset) = (1i64, 0i64);
+        let mut current = "humn".to_string();
+        for _ in 0..path_length {
+            let constant = builder.rng.range(2..=9i64);
+            let divisible = gcd(factor, offset) % constant == 0;
+            current = match builder.rng.range(0..5) {
+                0 if factor.abs() * constant <= 1_000_000_000 && offset.abs() * constant <= 1_000_000_000_000_000 => {
+                    (factor, offset) = (factor * constant, offset * constant);
+                    let other = builder.constant(constant, subtree_budget);
+                    builder.add_operation(&other, '*', &current)
+                }
+                1 if divisible => {
+                    (factor, offset) = (factor / constant, offset / constant);
+                    let other = builder.constant(constant, subtree_budget);
+                    builder.add_operation(&current, '/', &other)
+                }
+                2 => {
+                    let constant = builder.rng.range(1..=1000);
+                    (factor, offset) = (-factor, constant - offset);
+                    let other = builder.constant(constant, subtree_budget);
+                    builder.add_operation(&other, '-', &current)
+                }
+                3 => {
+                    let constant = builder.rng.range(1..=1000);
+                    offset -= constant;
+                    let other = builder.constant(constant, subtree_budget);
+                    builder.add_operation(&current, '-', &other)
+                }
+                _ => {
+                    let constant = builder.rng.range(1..=1000);
+                    offset += constant;
+                    let other = builder.constant(constant, subtree_budget);
+                    builder.add_operation(&current, '+', &other)
+                }
+            };
+        }
+        let other = builder.constant(factor * answer + offset, subtree_budget);
+        let root = if builder.rng.chance(0.5) { format!("root: {} + {}", current, other) } else { format!("root: {} + {}", other, current) };
+        builder.jobs.push(root);
+
+        let mut jobs = builder.jobs;
+        rng.shuffle(&mut jobs);
+        return (jobs.iter().map(|job| format!("{}\n", job)).collect(), ());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::solution::Solution;
+
+    use crate::Day21;
+
+    #[test]
+    fn test_generate() {
+        let input = generate::<Day21>(1, 2000);
+        assert_eq!(input, generate::<Day21>(1, 2000));
+        assert!(input.text.lines().count() <= 2000);
+        for seed in 0..20 {
+            let text = generate::<Day21>(seed, 200).text;
+            let monkey_jobs = Day21::parse(&text).unwrap();
+            Day21::part1(&monkey_jobs, &());
+            let humn = Day21::part2(&monkey_jobs, &());
+
+            // With the answer as the number of humn, both sides of root are equal
+            let checked_text = text.lines()
+                .map(|line| if line.starts_with("humn: ") { format!("humn: {}", humn) } else if line.starts_with("root: ") { line.replace('+', "-") } else { line.to_string() })
+                .collect::<Vec<String>>()
+                .join("\n");
+            assert_eq!(Day21::part1(&Day21::parse(&checked_text).unwrap(), &()), 0);
+        }
+    }
+}
@@ -0,0 +1,295 @@
+mod explore;
+mod generate;
+
+use std::collections::HashMap;
+
+use aoc_common::input;
+use aoc_common::parse::{Line, ParseError};
+use aoc_common::solution::Solution;
+
+#[derive(Debug)]
+pub struct MonkeyJob {
+    pub monkey: String,
+    pub job: String,
+}
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<MonkeyJob>, ParseError> {
+    let mut monkey_jobs = Vec::new();
+    let mut referenced_monkeys = Vec::new();
+
+    for (line_num, text) in lines.iter().enumerate() {
+        let line = Line::new(line_num, text);
+        let job_start = line.find(": ", 0)? + 2;
+        let monkey = text[..job_start - 2].to_owned();
+        let job = text[job_start..].to_owned();
+
+        if job.parse::<i64>().is_err() {
+            let words = line.words();
+            if words.len() != 4 {
+                return Err(line.error(job_start, "a number or an operation"));
+            }
+            if !["+", "-", "*", "/"].contains(&words[2].text) {
+                return Err(line.error(words[2].start, "'+', '-', '*' or '/'"));
+            }
+            referenced_monkeys.push((line, words[1]));
+            referenced_monkeys.push((line, words[3]));
+        }
+        monkey_jobs.push(MonkeyJob { monkey, job });
+    }
+
+    // Operations can only use monkeys that have a job
+    for (line, word) in referenced_monkeys {
+        if !monkey_jobs.iter().any(|monkey_job| monkey_job.monkey == word.text) {
+            return Err(line.error(word.start, "the name of a monkey in the input"));
+        }
+    }
+    for name in ["root", "humn"] {
+        if !monkey_jobs.iter().any(|monkey_job| monkey_job.monkey == name) {
+            let expected = format!("a job for the monkey {}", name);
+            return Err(ParseError::new(lines.len() + 1, 1, &expected, ""));
+        }
+    }
+    return Ok(monkey_jobs);
+}
+
+fn create_original_ordered_list(monkey_to_job: &HashMap<String, String>, monkey: &str) -> Vec<String> {
+    let mut ordered_monkey_list: Vec<String> = vec![monkey.to_string()];
+    let mut current_monkey_number = 0;
+
+    while current_monkey_number < ordered_monkey_list.len() {
+        let monkey_name = &ordered_monkey_list[current_monkey_number];
+        let job = monkey_to_job.get(monkey_name).unwrap();
+
+        if job.parse::<i64>().is_err() {
+            let mut split_job = job.split_whitespace();
+            let first_monkey = split_job.next().unwrap().to_owned();
+            split_job.next();
+            let second_monkey = split_job.next().unwrap().to_owned();
+            ordered_monkey_list.push(first_monkey);
+            ordered_monkey_list.push(second_monkey);
+        }
+        current_monkey_number += 1;
+    }
+
+    ordered_monkey_list.reverse();
+    return ordered_monkey_list;
+}
+
+fn calculate_original_monkey_numbers(monkey_to_job: &HashMap<String, String>,
+                                     ordered_monkey_names: &Vec<String>) -> HashMap<String, i64> {
+    let mut monkey_to_number: HashMap<String, i64> = HashMap::new();
+    for monkey_name in ordered_monkey_names {
+        let job = monkey_to_job.get(monkey_name).unwrap();
+
+        if job.parse::<i64>().is_ok() {
+            monkey_to_number.insert(monkey_name.to_owned(), job.parse().unwrap());
+        } else {
+            let mut split_job = job.split_whitespace();
+            let first_monkey = split_job.next().unwrap();
+            let first_number = monkey_to_number.get(first_monkey).unwrap();
+
+            let operator = split_job.next().unwrap().chars().next().unwrap();
+
+            let second_monkey = split_job.next().unwrap();
+            let second_number = monkey_to_number.get(second_monkey).unwrap();
+
+            let monkey_number = match operator {
+                '+' => first_number + second_number,
+                '-' => first_number - second_number,
+                '*' => first_number * second_number,
+                '/' => first_number / second_number,
+                _ => panic!("{} is not a valid operation", operator)
+            };
+
+            monkey_to_number.insert(monkey_name.to_string(), monkey_number);
+        }
+    }
+
+    return monkey_to_number;
+}
+
+fn create_revised_ordered_list(monkey_to_job: &HashMap<String, String>) -> Vec<String> {
+    let mut original_ordered_monkey_list: Vec<String> = vec!["root".to_string()];
+    let mut current_monkey_number = 0;
+
+    let mut monkey_to_parent: HashMap<String, String> = HashMap::new();
+
+    while current_monkey_number < original_ordered_monkey_list.len() {
+        let monkey_name = original_ordered_monkey_list[current_monkey_number].to_string();
+        let job = monkey_to_job.get(&monkey_name).unwrap().to_string();
+
+        if job.parse::<i64>().is_err() {
+            let mut split_job = job.split_whitespace();
+            let first_monkey = split_job.next().unwrap().to_string();
+            split_job.next();
+            let second_monkey = split_job.next().unwrap().to_string();
+            original_ordered_monkey_list.push(first_monkey.to_owned());
+            original_ordered_monkey_list.push(second_monkey.to_owned());
+            monkey_to_parent.insert(first_monkey.to_owned(), monkey_name.to_owned());
+            monkey_to_parent.insert(second_monkey.to_owned(), monkey_name.to_owned());
+        }
+        current_monkey_number += 1;
+    }
+
+    let mut ordered_monkey_list: Vec<String> = vec![];
+    let mut reversed_monkey_list: Vec<String> = vec![];
+    let mut next_reversed_monkey = "humn";
+
+    while !original_ordered_monkey_list.is_empty(){
+        let monkey_name = original_ordered_monkey_list.pop().unwrap().to_string();
+        if monkey_name.ne(next_reversed_monkey) {
+            ordered_monkey_list.push(monkey_name);
+        } else {
+            reversed_monkey_list.push(monkey_name.to_owned());
+            if monkey_name.ne("root") {
+                next_reversed_monkey = monkey_to_parent.get(&*monkey_name).unwrap();
+            }
+        }
+    }
+
+    reversed_monkey_list.reverse();
+    ordered_monkey_list.append(&mut reversed_monkey_list);
+
+    return ordered_monkey_list;
+}
+
+fn calculate_revised_monkey_numbers(monkey_to_job: &HashMap<String, String>,
+                                     ordered_monkey_names: &Vec<String>) -> HashMap<String, i64> {
+    let mut monkey_to_number: HashMap<String, i64> = HashMap::new();
+    let mut is_original_order = true;
+    let mut previous_monkey = &ordered_monkey_names[0];
+    for monkey_name in ordered_monkey_names {
+        if monkey_name == "root" || previous_monkey == "root" {
+            monkey_to_number.insert(monkey_name.to_string(), *monkey_to_number.get(previous_monkey).unwrap());
+            is_original_order = false;
+        } else {
+            let job = if is_original_order {
+                monkey_to_job.get(monkey_name).unwrap()
+            } else {
+                monkey_to_job.get(previous_monkey).unwrap()
+            };
+
+            if job.parse::<i64>().is_ok() {
+                monkey_to_number.insert(monkey_name.to_owned(), job.parse().unwrap());
+            } else {
+                let mut split_job = job.split_whitespace();
+                let first_monkey = split_job.next().unwrap();
+                let operator = split_job.next().unwrap().chars().next().unwrap();
+                let second_monkey = split_job.next().unwrap();
+
+                let monkey_number = if is_original_order {
+                    let first_number = monkey_to_number.get(first_monkey).unwrap();
+                    let second_number = monkey_to_number.get(second_monkey).unwrap();
+
+                    match operator {
+                        '+' => first_number + second_number,
+                        '-' => first_number - second_number,
+                        '*' => first_number * second_number,
+                        '/' => first_number / second_number,
+                        _ => panic!("{} is not a valid operation", operator)
+                    }
+                } else {
+                    let previous_monkey_number = monkey_to_number.get(previous_monkey).unwrap();
+                    if first_monkey == monkey_name {
+                        let second_number = monkey_to_number.get(second_monkey).unwrap();
+                        match operator {
+                            '+' => previous_monkey_number - second_number,
+                            '-' => previous_monkey_number + second_number,
+                            '*' => previous_monkey_number / second_number,
+                            '/' => previous_monkey_number * second_number,
+                            _ => panic!("{} is not a valid operation", operator)
+                        }
+                    } else {
+                        let first_number = monkey_to_number.get(first_monkey).unwrap();
+                        match operator {
+                            '+' => previous_monkey_number - first_number,
+                            '-' => first_number - previous_monkey_number,
+                            '*' => previous_monkey_number / first_number,
+                            '/' => first_number / previous_monkey_number,
+                            _ => panic!("{} is not a valid operation", operator)
+                        }
+                    }
+                };
+
+                monkey_to_number.insert(monkey_name.to_string(), monkey_number);
+            }
+        }
+
+        previous_monkey = monkey_name;
+    }
+
+    return monkey_to_number;
+}
+
+/// The number a monkey yells, once the monkeys it waits for have yelled theirs.
+pub fn yelled_number(monkey_jobs: &Vec<MonkeyJob>, monkey: &str) -> i64 {
+    let mut monkey_to_job: HashMap<String, String> = HashMap::new();
+
+    for monkey_job in monkey_jobs {
+        monkey_to_job.insert(monkey_job.monkey.to_owned(), monkey_job.job.to_owned());
+    }
+
+    let ordered_monkey_names = create_original_ordered_list(&monkey_to_job, monkey);
+    let monkey_to_number = calculate_original_monkey_numbers(&monkey_to_job, &ordered_monkey_names);
+
+    return monkey_to_number[monkey];
+}
+
+pub fn solve_1(monkey_jobs: &Vec<MonkeyJob>) -> i64 {
+    return yelled_number(monkey_jobs, "root");
+}
+
+pub fn solve_2(monkey_jobs: &Vec<MonkeyJob>) -> i64 {
+    let mut monkey_to_job: HashMap<String, String> = HashMap::new();
+
+    for monkey_job in monkey_jobs {
+        monkey_to_job.insert(monkey_job.monkey.to_owned(), monkey_job.job.to_owned());
+    }
+
+    let ordered_monkey_names = create_revised_ordered_list(&monkey_to_job);
+    let monkey_to_number = calculate_revised_monkey_numbers(&monkey_to_job, &ordered_monkey_names);
+
+    return monkey_to_number["humn"];
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    type Input = Vec<MonkeyJob>;
+    type Config = ();
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<MonkeyJob>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(monkey_jobs: &Vec<MonkeyJob>, _: &()) -> i64 {
+        return solve_1(monkey_jobs);
+    }
+
+    fn part2(monkey_jobs: &Vec<MonkeyJob>, _: &()) -> i64 {
+        return solve_2(monkey_jobs);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Day21, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let data = load::<Day21>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&data), 152);
+    }
+
+    #[test]
+    fn test_2() {
+        let data = load::<Day21>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&data), 301);
+    }
+}
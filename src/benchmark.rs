@@ -0,0 +1,78 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_common::bench::{BenchOptions, Measurement};
+
+const CSV_HEADER: &str = "timestamp,day,phase,warmup,iterations,mean_us,median_us,std_dev_us";
+
+/// The measurements of one day.
+#[derive(Debug)]
+pub struct DayResult {
+    pub day: u32,
+    pub measurements: Vec<Measurement>,
+}
+
+pub fn print_table(results: &[DayResult]) {
+    println!("{:<5}{:<8}{:>14}{:>14}{:>14}", "Day", "Phase", "Mean (µs)", "Median (µs)", "Std dev (µs)");
+    for result in results {
+        for measurement in &result.measurements {
+            let stats = measurement.stats;
+            println!("{:<5}{:<8}{:>14.1}{:>14.1}{:>14.1}",
+                     format!("{:02}", result.day), measurement.phase, stats.mean, stats.median, stats.std_dev);
+        }
+    }
+}
+
+pub fn csv_rows(timestamp: u64, options: &BenchOptions, results: &[DayResult]) -> Vec<String> {
+    let mut rows = Vec::new();
+    for result in results {
+        for measurement in &result.measurements {
+            let stats = measurement.stats;
+            rows.push(format!("{},{},{},{},{},{:.2},{:.2},{:.2}",
+                              timestamp, result.day, measurement.phase, options.warmup, options.iterations,
+                              stats.mean, stats.median, stats.std_dev));
+        }
+    }
+    return rows;
+}
+
+/// Append the results to a CSV file, so that runs from different commits can be compared.
+pub fn append_csv(path: &Path, options: &BenchOptions, results: &[DayResult]) -> io::Result<()> {
+    if let Some(directory) = path.parent().filter(|directory| !directory.as_os_str().is_empty()) {
+        fs::create_dir_all(directory)?;
+    }
+    let is_new = fs::metadata(path).map(|metadata| metadata.len() == 0).unwrap_or(true);
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "{}", CSV_HEADER)?;
+    }
+
+    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.as_secs()).unwrap_or(0);
+    for row in csv_rows(timestamp, options, results) {
+        writeln!(file, "{}", row)?;
+    }
+    return Ok(());
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::bench::{BenchOptions, Measurement, Stats};
+
+    use crate::benchmark::{csv_rows, DayResult};
+
+    #[test]
+    fn test_csv_rows() {
+        let stats = Stats { mean: 12.5, median: 12.0, std_dev: 0.25 };
+        let results = vec![DayResult {
+            day: 5,
+            measurements: vec![Measurement { phase: "parse", stats }, Measurement { phase: "part2", stats }],
+        }];
+        let rows = csv_rows(1_700_000_000, &BenchOptions { warmup: 1, iterations: 10 }, &results);
+        assert_eq!(rows, vec![
+            "1700000000,5,parse,1,10,12.50,12.00,0.25",
+            "1700000000,5,part2,1,10,12.50,12.00,0.25",
+        ]);
+    }
+}
@@ -0,0 +1,133 @@
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::process::exit;
+
+use advent_of_code_2022::{get_day, DAYS};
+use aoc_common::explore::Session;
+use aoc_common::params::ParamArgs;
+
+const USAGE: &str = "Usage:
+    cargo run --bin repl -- <DAY> [--input <INPUT>] [--param <NAME=VALUE>]... [--config <FILE>]
+
+Parses the input of a day and reads commands from standard input, such as show, part 2 rounds_2=500,
+step 20 (day 11), size /a/e (day 7) or value pppw (day 21). Type help for the commands of the day.
+
+Options:
+    --input <INPUT>     Puzzle input to use instead of the day's input/input.txt: the name of another file in the
+                        day's input/ directory without .txt, such as test, or a file
+    --param <NAME=VALUE>
+                        Override a puzzle parameter of the day, e.g. rounds_2=100 (repeatable)
+    --config <FILE>     Parameter file to use instead of the day's config.toml
+    --help              Print this message";
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+struct Arguments {
+    day: u32,
+    input: Option<String>,
+    params: ParamArgs,
+}
+
+fn parse_arguments(args: &[String]) -> Result<Arguments, String> {
+    let mut args = args.iter();
+    let day = args.next().ok_or("The day to explore is required")?;
+    let day = day.parse::<u32>().ok().filter(|day| get_day(*day).is_some())
+        .ok_or(format!("The day must be a number between 1 and {}, got '{}'", DAYS.len(), day))?;
+    let mut arguments = Arguments { day, input: None, params: ParamArgs::default() };
+
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("{} requires a value", arg))?;
+        match arg.as_str() {
+            "--input" => arguments.input = Some(value.to_owned()),
+            "--param" => arguments.params.add_override(value).map_err(|error| error.to_string())?,
+            "--config" => arguments.params.config_files = vec![PathBuf::from(value)],
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+    return Ok(arguments);
+}
+
+// Run commands until `quit`, `exit` or the end of the input. Failed commands are reported and the session goes on.
+fn run(session: &mut dyn Session, reader: &mut dyn BufRead, output: &mut dyn Write, prompt: &str) -> io::Result<()> {
+    let mut line = String::new();
+    loop {
+        write!(output, "{}", prompt)?;
+        output.flush()?;
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            return writeln!(output);
+        }
+        if ["quit", "exit"].contains(&line.trim()) {
+            return Ok(());
+        }
+        match session.execute(&line) {
+            Ok(text) if text.is_empty() => (),
+            Ok(text) => writeln!(output, "{}", text)?,
+            Err(message) => writeln!(output, "Error: {}", message)?,
+        }
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+    let arguments = match parse_arguments(&args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            exit(2);
+        }
+    };
+
+    let day = get_day(arguments.day).unwrap();
+    let (source, default_params) = day.named_input(arguments.input.as_deref());
+    let mut params = arguments.params;
+    if params.config_files.is_empty() {
+        params.config_files = default_params.config_files;
+    }
+    let mut session = match day.session(&source, &params) {
+        Ok(session) => session,
+        Err(error) => {
+            eprintln!("{}", error);
+            exit(1);
+        }
+    };
+
+    println!("Parsed {}. Type help for the commands.", source);
+    let prompt = format!("day{:02}> ", day.number);
+    if let Err(error) = run(session.as_mut(), &mut io::stdin().lock(), &mut io::stdout(), &prompt) {
+        eprintln!("{}", error);
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use advent_of_code_2022::get_day;
+
+    use crate::{parse_arguments, run};
+
+    #[test]
+    fn test_arguments() {
+        let args = ["7", "--input", "test", "--param", "size_limit=10"].map(|arg| arg.to_string());
+        let arguments = parse_arguments(&args).unwrap();
+        assert_eq!((arguments.day, arguments.input.as_deref()), (7, Some("test")));
+        assert_eq!(arguments.params.overrides, vec![("size_limit".to_string(), "10".to_string())]);
+        assert!(parse_arguments(&["22".to_string()]).is_err());
+        assert!(parse_arguments(&["7".to_string(), "--part".to_string(), "1".to_string()]).is_err());
+    }
+
+    #[test]
+    fn test_run() {
+        let day = get_day(7).unwrap();
+        let (source, params) = day.named_input(Some("test"));
+        let mut session = day.session(&source, &params).unwrap();
+        let mut output = Vec::new();
+        run(session.as_mut(), &mut "size /a/e\n\nsize /a/x\nquit\nsize /\n".as_bytes(), &mut output, "> ").unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "> 584\n> > Error: There is no file or directory /a/x\n> ");
+    }
+}
@@ -0,0 +1,98 @@
+use std::env;
+use std::net::TcpListener;
+use std::process::exit;
+use std::time::Duration;
+
+use advent_of_code_2022::server::{serve, ServerOptions};
+
+const USAGE: &str = "Usage:
+    cargo run --release --bin server -- [--address <ADDRESS>] [--timeout <SECONDS>] [--max-body <BYTES>]
+        [--workers <N>] [--max-connections <N>]
+
+Answers POST /day/<DAY>/part/<PART> with the puzzle input as the body, e.g.
+    curl --data-binary @day01/input/input.txt localhost:8022/day/1/part/2
+Parameters of the day go into the query, e.g. /day/11/part/2?rounds_2=500.
+
+Options:
+    --address <ADDRESS> Address to listen on (default: 127.0.0.1:8022)
+    --timeout <SECONDS> Time to receive and solve a request in (default: 10)
+    --max-body <BYTES>  Largest puzzle input to accept (default: 1048576)
+    --workers <N>       Number of parts to solve at the same time, others are answered with 503 (default: 4)
+    --max-connections <N>
+                        Number of connections to answer at the same time, others get 503 (default: 64)
+    --help              Print this message";
+
+fn parse_arguments(args: &[String]) -> Result<(String, ServerOptions), String> {
+    let mut address = "127.0.0.1:8022".to_string();
+    let mut options = ServerOptions::default();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("{} requires a value", arg))?;
+        match arg.as_str() {
+            "--address" => address = value.to_owned(),
+            "--timeout" => {
+                let seconds = value.parse::<f64>().ok().filter(|seconds| *seconds > 0.0 && seconds.is_finite())
+                    .ok_or(format!("--timeout must be a positive number of seconds, got '{}'", value))?;
+                options.timeout = Duration::from_secs_f64(seconds);
+            }
+            "--max-body" => {
+                options.max_body_size = value.parse().map_err(|_| format!("--max-body must be a number of bytes, got '{}'", value))?;
+            }
+            "--workers" => {
+                options.workers = value.parse().ok().filter(|workers| *workers > 0)
+                    .ok_or(format!("--workers must be a positive number, got '{}'", value))?;
+            }
+            "--max-connections" => {
+                options.max_connections = value.parse().ok().filter(|connections| *connections > 0)
+                    .ok_or(format!("--max-connections must be a positive number, got '{}'", value))?;
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg)),
+        }
+    }
+    return Ok((address, options));
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+    let (address, options) = match parse_arguments(&args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            exit(2);
+        }
+    };
+
+    let listener = match TcpListener::bind(&address) {
+        Ok(listener) => listener,
+        Err(error) => {
+            eprintln!("Could not listen on {}: {}", address, error);
+            exit(1);
+        }
+    };
+    eprintln!("Listening on http://{}", listener.local_addr().map(|address| address.to_string()).unwrap_or(address));
+    serve(listener, options);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use advent_of_code_2022::server::ServerOptions;
+
+    use crate::parse_arguments;
+
+    #[test]
+    fn test_arguments() {
+        let args = ["--timeout", "0.5", "--max-body", "100", "--workers", "2", "--max-connections", "8"].map(|arg| arg.to_string());
+        let (address, options) = parse_arguments(&args).unwrap();
+        let expected_options = ServerOptions { timeout: Duration::from_millis(500), max_body_size: 100, workers: 2, max_connections: 8 };
+        assert_eq!((address.as_str(), options), ("127.0.0.1:8022", expected_options));
+        assert!(parse_arguments(&["--timeout".to_string(), "-1".to_string()]).is_err());
+        assert!(parse_arguments(&["--port".to_string(), "80".to_string()]).is_err());
+    }
+}
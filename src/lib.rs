@@ -0,0 +1,279 @@
+pub mod benchmark;
+pub mod parallel;
+pub mod scaffold;
+pub mod server;
+pub mod stress;
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use aoc_common::answers::{self, ExpectedAnswers};
+use aoc_common::bench::{self, BenchOptions, Measurement};
+use aoc_common::cli;
+use aoc_common::error::Error;
+use aoc_common::explore::{self, Session};
+use aoc_common::generate::{self, GeneratedInput};
+use aoc_common::input::{self, day_path, InputSource, NamedInput};
+use aoc_common::params::{ParamArgs, ParamInfo, Params};
+use aoc_common::reference::{self, Disagreement};
+use aoc_common::report::{self, PartReport};
+use aoc_common::solution::{Answer, Solution};
+use aoc_common::stream;
+use aoc_common::visualize::{self, Animation};
+
+type SolveFn = fn(&InputSource, Option<u32>, &ParamArgs) -> Result<(), Error>;
+type ReportFn = fn(&InputSource, Option<u32>, &ParamArgs) -> Result<Vec<PartReport>, Error>;
+type BenchFn = fn(&InputSource, Option<u32>, &BenchOptions, &ParamArgs) -> Result<Vec<Measurement>, Error>;
+type ParamsFn = fn(&ParamArgs) -> Result<Vec<ParamInfo>, Error>;
+type GenerateFn = fn(u64, usize) -> GeneratedInput;
+type DifferentialFn = fn(&[usize], u64, u32) -> Option<Disagreement>;
+type SessionFn = fn(&InputSource, &ParamArgs) -> Result<Box<dyn Session>, Error>;
+type VisualizeFn = fn(&InputSource, u32, &ParamArgs, &mut Animation) -> Result<(), Error>;
+
+/// A day of the calendar, with its solution behind function pointers so days can be chosen at runtime.
+pub struct Day {
+    pub number: u32,
+    solve: SolveFn,
+    report: ReportFn,
+    bench: BenchFn,
+    params: ParamsFn,
+    generate: GenerateFn,
+    differential: Option<DifferentialFn>,
+    stream: Option<SolveFn>,
+    session: SessionFn,
+    visualize: Option<VisualizeFn>,
+}
+
+impl Day {
+    /// Directory of the day crate, e.g. `day05`.
+    pub fn crate_dir(&self) -> PathBuf {
+        return PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(format!("day{:02}", self.number));
+    }
+
+    pub fn default_input(&self) -> InputSource {
+        return InputSource::File(day_path(&self.crate_dir().to_string_lossy(), "input.txt"));
+    }
+
+    /// Every puzzle input in the day's `input` directory and its subdirectories, see `input::discover_inputs`.
+    pub fn inputs(&self) -> Result<Vec<NamedInput>, Error> {
+        return Ok(input::discover_inputs(&self.crate_dir().join("input"))?);
+    }
+
+    /// The input in the day's `input` directory with the given name, e.g. `test` or `alice/input`.
+    pub fn find_input(&self, name: &str) -> Result<Option<NamedInput>, Error> {
+        return Ok(self.inputs()?.into_iter().find(|input| input.name == name));
+    }
+
+    /// The input with the given name, see `find_input`, or else the file at that path, with the parameters
+    /// to solve it with. Without a name it is the day's `input.txt`.
+    pub fn named_input(&self, name: Option<&str>) -> (InputSource, ParamArgs) {
+        return match self.find_input(name.unwrap_or("input")).ok().flatten() {
+            Some(input) => (input.source(), self.input_params(&input)),
+            None => match name {
+                Some(name) => (InputSource::from_arg(name), self.default_params()),
+                None => (self.default_input(), self.default_params()),
+            },
+        };
+    }
+
+    /// The parameters from the day's `config.toml`, if it has one, without overrides.
+    pub fn default_params(&self) -> ParamArgs {
+        let config_file = self.crate_dir().join("config.toml");
+        return ParamArgs { config_files: Some(config_file).filter(|path| path.exists()).into_iter().collect(), overrides: Vec::new() };
+    }
+
+    /// The parameters for one input: the day's `config.toml` followed by the input's own `.toml` file.
+    pub fn input_params(&self, input: &NamedInput) -> ParamArgs {
+        let mut params = self.default_params();
+        params.config_files.extend(input.config_file());
+        return params;
+    }
+
+    /// The parameters of the day, with the values they have when solving with `params`.
+    pub fn params(&self, params: &ParamArgs) -> Result<Vec<ParamInfo>, Error> {
+        return (self.params)(params);
+    }
+
+    /// Load the input and print the answers to the requested part, or both parts.
+    pub fn solve(&self, source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<(), Error> {
+        return (self.solve)(source, part, params);
+    }
+
+    /// Solve the requested part, or both parts, with timings and the hash of the input.
+    pub fn report(&self, source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<Vec<PartReport>, Error> {
+        return (self.report)(source, part, params);
+    }
+
+    /// Compute the answer to the requested part, or to both parts, without printing them.
+    pub fn answers(&self, source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<Vec<(u32, Answer)>, Error> {
+        let reports = self.report(source, part, params)?;
+        return Ok(reports.into_iter().map(|report| (report.part, report.answer)).collect());
+    }
+
+    /// The answers from `answers.toml`, by input file name without `.txt`. A missing file has no answers.
+    pub fn expected_answers(&self) -> Result<HashMap<String, ExpectedAnswers>, Error> {
+        let path = self.crate_dir().join("answers.toml");
+        if !path.exists() {
+            return Ok(HashMap::new());
+        }
+        let source = InputSource::File(path);
+        let text = input::read_to_string(&source)?;
+        return answers::parse_answers(&text).map_err(|error| Error::Parse(source, error));
+    }
+
+    /// Time parsing and the requested parts separately, see `bench::benchmark`.
+    pub fn bench(&self, source: &InputSource, part: Option<u32>, options: &BenchOptions, params: &ParamArgs) -> Result<Vec<Measurement>, Error> {
+        return (self.bench)(source, part, options, params);
+    }
+
+    /// A random input of about `size` items for `seed`, see `generate::Generator`.
+    pub fn generate(&self, seed: u64, size: usize) -> GeneratedInput {
+        return (self.generate)(seed, size);
+    }
+
+    /// Whether the day can solve an input in a single pass without reading it into memory, see `stream::Streaming`.
+    pub fn can_stream(&self) -> bool {
+        return self.stream.is_some();
+    }
+
+    /// Stream the input and print the answers to the requested part, or both parts. Panics if the day cannot stream.
+    pub fn solve_stream(&self, source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<(), Error> {
+        let stream = self.stream.unwrap_or_else(|| panic!("Day {} cannot stream its input", self.number));
+        return stream(source, part, params);
+    }
+
+    /// Whether the day has a reference solution to compare against, see `reference::Reference`.
+    pub fn has_reference(&self) -> bool {
+        return self.differential.is_some();
+    }
+
+    /// Compare the solution with the reference solution on `count` inputs of every size, see
+    /// `reference::find_smallest_disagreement`. Panics if the day has no reference solution.
+    pub fn find_smallest_disagreement(&self, sizes: &[usize], seed: u64, count: u32) -> Option<Disagreement> {
+        let differential = self.differential.unwrap_or_else(|| panic!("Day {} has no reference solution", self.number));
+        return differential(sizes, seed, count);
+    }
+
+    /// Whether the day can draw the steps of its simulation, see `visualize::Visualize`.
+    pub fn can_visualize(&self) -> bool {
+        return self.visualize.is_some();
+    }
+
+    /// Draw a frame for every step of solving `part`. Panics if the day cannot visualize.
+    pub fn visualize(&self, source: &InputSource, part: u32, params: &ParamArgs, animation: &mut Animation) -> Result<(), Error> {
+        let visualize = self.visualize.unwrap_or_else(|| panic!("Day {} cannot visualize its steps", self.number));
+        return visualize(source, part, params, animation);
+    }
+
+    /// Parse the input for the REPL, with the day's own commands if it has any, see `explore::Explore`.
+    pub fn session(&self, source: &InputSource, params: &ParamArgs) -> Result<Box<dyn Session>, Error> {
+        return (self.session)(source, params);
+    }
+}
+
+fn report_day<S: Solution>(source: &InputSource, part: Option<u32>, params: &ParamArgs) -> Result<Vec<PartReport>, Error> {
+    let config = params.resolve::<S::Config>()?;
+    let text = input::read_to_string(source)?;
+    return report::report::<S>(&text, part, &config).map_err(|error| Error::Parse(source.clone(), error));
+}
+
+fn bench_day<S: Solution>(source: &InputSource, part: Option<u32>, options: &BenchOptions, params: &ParamArgs) -> Result<Vec<Measurement>, Error> {
+    let config = params.resolve::<S::Config>()?;
+    let text = input::read_to_string(source)?;
+    return bench::benchmark::<S>(&text, part, options, &config).map_err(|error| Error::Parse(source.clone(), error));
+}
+
+fn describe_params<S: Solution>(params: &ParamArgs) -> Result<Vec<ParamInfo>, Error> {
+    return Ok(params.resolve::<S::Config>()?.describe());
+}
+
+macro_rules! day {
+    (@reference $day:ident, $solution:ty) => {
+        $day.differential = Some(reference::find_smallest_disagreement::<$solution>)
+    };
+    (@stream $day:ident, $solution:ty) => {
+        $day.stream = Some(stream::solve::<$solution>)
+    };
+    (@explore $day:ident, $solution:ty) => {
+        $day.session = explore::explore_session::<$solution>
+    };
+    (@visualize $day:ident, $solution:ty) => {
+        $day.visualize = Some(visualize::visualize::<$solution>)
+    };
+    ($number:expr, $solution:ty) => {
+        Day {
+            number: $number,
+            solve: cli::solve::<$solution>,
+            report: report_day::<$solution>,
+            bench: bench_day::<$solution>,
+            params: describe_params::<$solution>,
+            generate: generate::generate::<$solution>,
+            differential: None,
+            stream: None,
+            session: explore::session::<$solution>,
+            visualize: None,
+        }
+    };
+    ($number:expr, $solution:ty, $($feature:ident),+) => {{
+        let mut day = day!($number, $solution);
+        $(day!(@$feature day, $solution);)+
+        day
+    }};
+}
+
+pub const DAYS: [Day; 21] = [
+    day!(1, day01::Day01, stream),
+    day!(2, day02::Day02, stream),
+    day!(3, day03::Day03, stream),
+    day!(4, day04::Day04, stream),
+    day!(5, day05::Day05, visualize),
+    day!(6, day06::Day06, stream),
+    day!(7, day07::Day07, explore),
+    day!(8, day08::Day08),
+    day!(9, day09::Day09, stream, visualize),
+    day!(10, day10::Day10, stream, visualize),
+    day!(11, day11::Day11, explore),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14, visualize),
+    day!(15, day15::Day15, reference),
+    day!(16, day16::Day16, reference, explore),
+    day!(17, day17::Day17, reference, visualize),
+    day!(18, day18::Day18),
+    day!(19, day19::Day19),
+    day!(20, day20::Day20, reference),
+    day!(21, day21::Day21, explore),
+];
+
+pub fn get_day(number: u32) -> Option<&'static Day> {
+    return DAYS.iter().find(|day| day.number == number);
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::solution::Answer;
+
+    use crate::{get_day, DAYS};
+
+    #[test]
+    fn test_days() {
+        for (index, day) in DAYS.iter().enumerate() {
+            assert_eq!(day.number as usize, index + 1);
+            assert!(day.crate_dir().join("Cargo.toml").exists());
+        }
+        assert_eq!(get_day(16).unwrap().crate_dir().file_name().unwrap(), "day16");
+        assert!(get_day(22).is_none());
+    }
+
+    #[test]
+    fn test_inputs() {
+        let day15 = get_day(15).unwrap();
+        let names = day15.inputs().unwrap().into_iter().map(|input| input.name).collect::<Vec<String>>();
+        assert!(names.contains(&"input".to_string()) && names.contains(&"test".to_string()));
+
+        let test_input = day15.find_input("test").unwrap().unwrap();
+        let answers = day15.answers(&test_input.source(), None, &day15.input_params(&test_input)).unwrap();
+        assert_eq!(answers, vec![(1, Answer::Number(26)), (2, Answer::Number(56_000_011))]);
+        assert!(day15.find_input("missing").unwrap().is_none());
+    }
+}
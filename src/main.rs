@@ -1,3 +1,639 @@
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter};
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::Instant;
+
+use advent_of_code_2022::benchmark::{self, DayResult};
+use advent_of_code_2022::parallel::{run_jobs, summary_table, Job};
+use advent_of_code_2022::scaffold::scaffold;
+use advent_of_code_2022::stress::{stress, stress_table, StressOptions};
+use advent_of_code_2022::{get_day, Day, DAYS};
+use aoc_common::bench::BenchOptions;
+use aoc_common::input::InputSource;
+use aoc_common::params::ParamArgs;
+use aoc_common::visualize::{Animation, AnimationOptions, FrameStyle};
+
+const USAGE: &str = "Usage:
+    cargo run -- --day <DAY> [--part <PART>] [--input <INPUT>] [--format <FORMAT>] [--param <NAME=VALUE>]... [--config <FILE>]
+    cargo run -- --day <DAY> --params
+    cargo run --release -- --day <DAY> --stream [--part <PART>] [--input <INPUT>] [--param <NAME=VALUE>]... [--config <FILE>]
+    cargo run --release -- --day <DAY> --visualize [--part <PART>] [--input <INPUT>] [--fps <N>] [--viewport <WxH>]
+                           [--max-frames <N>] [--export <FILE>]
+    cargo run -- --all [--part <PART>] [--format <FORMAT>] [--jobs <N>]
+    cargo run --release -- --bench [--day <DAY>] [--warmup <N>] [--iterations <N>] [--output <FILE>]
+    cargo run -- scaffold <DAY>
+    cargo run -- generate <DAY> [--size <N>] [--seed <N>]
+    cargo run --release -- stress <DAY> [--sizes <N,N,...>] [--seed <N>] [--count <N>]
+    cargo run --release -- diff <DAY> [--sizes <N,N,...>] [--seed <N>] [--count <N>]
+
+Commands:
+    scaffold <DAY>      Create dayNN/ from the template and add it to the workspace (1-25)
+    generate <DAY>      Print a random valid input for the day, and the --param options to solve it with
+    stress <DAY>        Solve random inputs of growing sizes, to find failing inputs and see how the day scales
+    diff <DAY>          Compare the solution with the day's reference solution on random inputs and print
+                        the smallest input they disagree on (days 15, 16, 17 and 20)
+
+Options:
+    --day <DAY>         Run the solution for a single day (1-21)
+    --part <PART>       Only run part 1 or part 2 (default: both)
+    --input <INPUT>     Puzzle input to use instead of the day's input/input.txt: the name of another file in the
+                        day's input/ directory without .txt, such as test or alice/input, a file, or - for stdin
+    --param <NAME=VALUE>
+                        Override a puzzle parameter of the day, e.g. rounds_2=100 (repeatable)
+    --config <FILE>     Parameter file to use instead of the day's config.toml
+    --params            List the parameters of the day with their values
+    --stream            Solve both parts in a single pass over the input without reading it into memory,
+                        for very large inputs (days 1, 2, 3, 4, 6, 9 and 10)
+    --visualize         Draw every step of the simulation of a part (default: part 1) in the terminal
+                        (days 5, 9, 10, 14 and 17)
+    --fps <N>           Frames per second to draw, or 0 for as fast as possible (default: 10)
+    --viewport <WxH>    Size of the part of the picture that is drawn, which follows the action (default: 80x24)
+    --max-frames <N>    Stop after drawing N frames
+    --export <FILE>     Write the frames to a text file one after another instead of drawing them
+    --all               Run every day in sequence
+    --format <FORMAT>   text, or json for a line of JSON per day and part (default: text)
+    --jobs <N>          Solve the days and parts on N threads and print a summary table
+    --bench             Time parsing and solving of one day, or of every day without --day
+    --warmup <N>        Untimed runs before measuring (default: 1)
+    --iterations <N>    Timed runs per phase (default: 10)
+    --output <FILE>     CSV file the benchmark results are appended to (default: target/benchmarks.csv)
+    --size <N>          Size of the generated input, e.g. the number of lines or monkeys (default: 100)
+    --sizes <N,N,...>   Sizes of the inputs to stress or diff the day with (default: 10,100,1000, or 2,3,5,10 for diff)
+    --seed <N>          Seed of the generated input, or of the first input of every size (default: 0)
+    --count <N>         Number of inputs of every size to stress or diff the day with (default: 3, or 20 for diff)
+    --help              Print this message";
+
+#[derive(Debug)]
+#[derive(Clone, Copy)]
+#[derive(PartialEq)]
+enum Format {
+    Text,
+    Json,
+}
+
+#[derive(Debug)]
+#[derive(PartialEq)]
+struct Arguments {
+    day: Option<u32>,
+    part: Option<u32>,
+    input: Option<PathBuf>,
+    params: ParamArgs,
+    list_params: bool,
+    stream: bool,
+    visualize: bool,
+    animation_options: AnimationOptions,
+    export: Option<PathBuf>,
+    all: bool,
+    format: Format,
+    jobs: Option<u32>,
+    bench: bool,
+    bench_options: BenchOptions,
+    output: Option<PathBuf>,
+}
+
+fn parse_number(flag: &str, value: Option<&String>, max_value: u32) -> Result<u32, String> {
+    let raw_value = value.ok_or(format!("{} requires a value", flag))?;
+    return match raw_value.parse::<u32>() {
+        Ok(number) if (1..=max_value).contains(&number) => Ok(number),
+        _ => Err(format!("{} must be a number between 1 and {}, got '{}'", flag, max_value, raw_value))
+    };
+}
+
+fn parse_count(flag: &str, value: Option<&String>) -> Result<u32, String> {
+    let raw_value = value.ok_or(format!("{} requires a value", flag))?;
+    return raw_value.parse::<u32>().map_err(|_| format!("{} must be a number, got '{}'", flag, raw_value));
+}
+
+fn parse_arguments(args: &[String]) -> Result<Arguments, String> {
+    let mut arguments = Arguments {
+        day: None,
+        part: None,
+        input: None,
+        params: ParamArgs::default(),
+        list_params: false,
+        stream: false,
+        visualize: false,
+        animation_options: AnimationOptions::default(),
+        export: None,
+        all: false,
+        format: Format::Text,
+        jobs: None,
+        bench: false,
+        bench_options: BenchOptions::default(),
+        output: None,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" => arguments.day = Some(parse_number(arg, args.next(), DAYS.len() as u32)?),
+            "--part" => arguments.part = Some(parse_number(arg, args.next(), 2)?),
+            "--input" => {
+                let input = args.next().ok_or("--input requires a value")?;
+                arguments.input = Some(PathBuf::from(input));
+            }
+            "--param" => {
+                let param = args.next().ok_or("--param requires a value")?;
+                arguments.params.add_override(param).map_err(|error| error.to_string())?;
+            }
+            "--config" => {
+                let config_file = args.next().ok_or("--config requires a value")?;
+                arguments.params.config_files = vec![PathBuf::from(config_file)];
+            }
+            "--params" => arguments.list_params = true,
+            "--stream" => arguments.stream = true,
+            "--visualize" => arguments.visualize = true,
+            "--fps" => {
+                let fps = args.next().ok_or("--fps requires a value")?;
+                arguments.animation_options.fps = fps.parse::<f64>().ok().filter(|fps| *fps >= 0.0 && fps.is_finite())
+                    .ok_or(format!("--fps must be a number of frames per second, got '{}'", fps))?;
+            }
+            "--viewport" => {
+                let viewport = args.next().ok_or("--viewport requires a value")?;
+                arguments.animation_options.viewport = viewport.split_once('x')
+                    .and_then(|(width, height)| Some((width.parse::<usize>().ok()?, height.parse::<usize>().ok()?)))
+                    .filter(|(width, height)| *width > 0 && *height > 0)
+                    .ok_or(format!("--viewport must be a width and height like 80x24, got '{}'", viewport))?;
+            }
+            "--max-frames" => arguments.animation_options.max_frames = Some(parse_number(arg, args.next(), u32::MAX)? as u64),
+            "--export" => {
+                let export = args.next().ok_or("--export requires a value")?;
+                arguments.export = Some(PathBuf::from(export));
+            }
+            "--all" => arguments.all = true,
+            "--format" => {
+                arguments.format = match args.next().map(|format| format.as_str()) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    _ => return Err("--format must be text or json".to_string()),
+                };
+            }
+            "--jobs" => arguments.jobs = Some(parse_number(arg, args.next(), 1024)?),
+            "--bench" => arguments.bench = true,
+            "--warmup" => arguments.bench_options.warmup = parse_count(arg, args.next())?,
+            "--iterations" => arguments.bench_options.iterations = parse_number(arg, args.next(), u32::MAX)?,
+            "--output" => {
+                let output = args.next().ok_or("--output requires a value")?;
+                arguments.output = Some(PathBuf::from(output));
+            }
+            _ => return Err(format!("Unknown argument '{}'", arg))
+        }
+    }
+
+    if arguments.all && arguments.day.is_some() {
+        return Err("--all and --day cannot be combined".to_string());
+    }
+    if arguments.all && arguments.input.is_some() {
+        return Err("--input can only be used together with --day".to_string());
+    }
+    let has_params = arguments.params != ParamArgs::default() || arguments.list_params;
+    if has_params && arguments.day.is_none() {
+        return Err("--param, --config and --params can only be used together with --day".to_string());
+    }
+    if !arguments.all && !arguments.bench && arguments.day.is_none() {
+        return Err("Either --day or --all is required".to_string());
+    }
+    if arguments.bench && arguments.day.is_none() && arguments.input.is_some() {
+        return Err("--input can only be used together with --day".to_string());
+    }
+    if arguments.bench && arguments.jobs.is_some() {
+        return Err("--jobs cannot be used together with --bench, which times one run at a time".to_string());
+    }
+    if !arguments.bench && arguments.output.is_some() {
+        return Err("--output can only be used together with --bench".to_string());
+    }
+    if arguments.stream && (arguments.day.is_none() || arguments.bench || arguments.jobs.is_some() || arguments.format == Format::Json) {
+        return Err("--stream can only be used together with --day, and not with --bench, --jobs or --format json".to_string());
+    }
+    if let Some(day) = arguments.day.filter(|day| arguments.stream && !get_day(*day).unwrap().can_stream()) {
+        let days = DAYS.iter().filter(|day| day.can_stream()).map(|day| day.number.to_string()).collect::<Vec<String>>();
+        return Err(format!("Day {} cannot stream its input, only days {} can", day, days.join(", ")));
+    }
+    if !arguments.visualize && (arguments.animation_options != AnimationOptions::default() || arguments.export.is_some()) {
+        return Err("--fps, --viewport, --max-frames and --export can only be used together with --visualize".to_string());
+    }
+    if arguments.visualize && (arguments.day.is_none() || arguments.bench || arguments.jobs.is_some() || arguments.stream || arguments.format == Format::Json) {
+        return Err("--visualize can only be used together with --day, and not with --bench, --jobs, --stream or --format json".to_string());
+    }
+    if let Some(day) = arguments.day.filter(|day| arguments.visualize && !get_day(*day).unwrap().can_visualize()) {
+        let days = DAYS.iter().filter(|day| day.can_visualize()).map(|day| day.number.to_string()).collect::<Vec<String>>();
+        return Err(format!("Day {} cannot visualize its steps, only days {} can", day, days.join(", ")));
+    }
+    return Ok(arguments);
+}
+
+// The arguments of the generate, stress and diff commands: the day, and the inputs to generate
+fn parse_generator_arguments(command: &str, args: &[String]) -> Result<(u32, StressOptions), String> {
+    let day = parse_number(command, args.first(), DAYS.len() as u32)?;
+    let mut options = match command {
+        "stress" => StressOptions::default(),
+        // Reference solutions are slow, and small disagreements are easier to debug
+        "diff" => StressOptions { sizes: vec![2, 3, 5, 10], seed: 0, count: 20 },
+        _ => StressOptions { sizes: vec![100], ..StressOptions::default() },
+    };
+
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        match (command, arg.as_str()) {
+            ("generate", "--size") => options.sizes = vec![parse_number(arg, args.next(), u32::MAX)? as usize],
+            ("stress" | "diff", "--sizes") => {
+                let sizes = args.next().ok_or("--sizes requires a value")?;
+                options.sizes = sizes.split(',')
+                    .map(|size| parse_number(arg, Some(&size.trim().to_string()), u32::MAX).map(|size| size as usize))
+                    .collect::<Result<Vec<usize>, String>>()?;
+            }
+            ("stress" | "diff", "--count") => options.count = parse_number(arg, args.next(), u32::MAX)?,
+            (_, "--seed") => {
+                let seed = args.next().ok_or("--seed requires a value")?;
+                options.seed = seed.parse().map_err(|_| format!("--seed must be a number, got '{}'", seed))?;
+            }
+            _ => return Err(format!("Unknown argument '{}' for {}", arg, command)),
+        }
+    }
+    return Ok((day, options));
+}
+
+fn generate_input(day: &Day, options: &StressOptions) {
+    let input = day.generate(options.seed, options.sizes[0]);
+    print!("{}", input.text);
+    if !input.params.is_empty() {
+        let params = input.params.iter().map(|(name, value)| format!("--param {}={}", name, value)).collect::<Vec<String>>();
+        eprintln!("Solve it with {}", params.join(" "));
+    }
+}
+
+fn stress_day(day: &'static Day, options: &StressOptions) -> bool {
+    let results = stress(day, options);
+    print!("{}", stress_table(&results));
+
+    let mut failed_inputs = results.iter()
+        .filter(|result| result.outcome.is_err())
+        .map(|result| (result.size, result.seed))
+        .collect::<Vec<(usize, u64)>>();
+    failed_inputs.dedup();
+    println!("\n{} of {} parts failed", results.iter().filter(|result| result.outcome.is_err()).count(), results.len());
+    for (size, seed) in &failed_inputs {
+        println!("Reproduce with: cargo run -- generate {} --size {} --seed {}", day.number, size, seed);
+    }
+    return failed_inputs.is_empty();
+}
+
+fn diff_day(day: &Day, options: &StressOptions) -> bool {
+    // Panics of either solution are reported as their answer
+    panic::set_hook(Box::new(|_| {}));
+    let disagreement = day.find_smallest_disagreement(&options.sizes, options.seed, options.count);
+    let _ = panic::take_hook();
+
+    let disagreement = match disagreement {
+        Some(disagreement) => disagreement,
+        None => {
+            println!("The solution agrees with the reference solution on {} inputs of every size", options.count);
+            return true;
+        }
+    };
+    println!("{}\n\nInput:\n{}", disagreement, disagreement.input.text);
+    if !disagreement.input.params.is_empty() {
+        let params = disagreement.input.params.iter().map(|(name, value)| format!("{}={}", name, value)).collect::<Vec<String>>();
+        println!("Parameters: {}", params.join(" "));
+    }
+    println!("Reproduce with: cargo run -- generate {} --size {} --seed {}", day.number, disagreement.size, disagreement.seed);
+    return false;
+}
+
+// The input is the day's input.txt, an input of the day by name such as "test", or a file. The parameters
+// come from the day's config.toml and the input's own .toml file, unless --config is given.
+fn day_input(day: &Day, arguments: &Arguments) -> (InputSource, ParamArgs) {
+    let name = arguments.input.as_ref().map(|input| input.to_string_lossy().to_string());
+    let (source, default_params) = day.named_input(name.as_deref());
+
+    let mut params = arguments.params.clone();
+    if params.config_files.is_empty() {
+        params.config_files = default_params.config_files;
+    }
+    return (source, params);
+}
+
+fn list_params(day: &Day, arguments: &Arguments) -> Result<(), String> {
+    let (_, params) = day_input(day, arguments);
+    let infos = day.params(&params).map_err(|error| error.to_string())?;
+    if infos.is_empty() {
+        println!("Day {:02} has no parameters", day.number);
+    }
+    for info in infos {
+        println!("{}", info);
+    }
+    return Ok(());
+}
+
+fn run_day(day: &Day, arguments: &Arguments) -> Result<(), String> {
+    let (source, params) = day_input(day, arguments);
+    if arguments.format == Format::Json {
+        let reports = day.report(&source, arguments.part, &params).map_err(|error| error.to_string())?;
+        for report in reports {
+            println!("{}", report.to_json());
+        }
+        return Ok(());
+    }
+    if arguments.visualize {
+        return visualize_day(day, arguments, &source, &params);
+    }
+    if arguments.stream {
+        return day.solve_stream(&source, arguments.part, &params).map_err(|error| error.to_string());
+    }
+    return day.solve(&source, arguments.part, &params).map_err(|error| error.to_string());
+}
+
+// Draw the frames in the terminal, or write them to the --export file
+fn visualize_day(day: &Day, arguments: &Arguments, source: &InputSource, params: &ParamArgs) -> Result<(), String> {
+    let part = arguments.part.unwrap_or(1);
+    let options = arguments.animation_options.clone();
+    let Some(export) = &arguments.export else {
+        let mut stdout = io::stdout();
+        let mut animation = Animation::new(&mut stdout, FrameStyle::Terminal, options);
+        day.visualize(source, part, params, &mut animation).map_err(|error| error.to_string())?;
+        animation.finish().map_err(|error| format!("Could not draw the frames: {}", error))?;
+        return Ok(());
+    };
+
+    let file = File::create(export).map_err(|error| format!("Could not write {}: {}", export.display(), error))?;
+    let mut writer = BufWriter::new(file);
+    let mut animation = Animation::new(&mut writer, FrameStyle::Text, options);
+    day.visualize(source, part, params, &mut animation).map_err(|error| error.to_string())?;
+    let frame_count = animation.finish().map_err(|error| format!("Could not write {}: {}", export.display(), error))?;
+    println!("Wrote {} frames to {}", frame_count, export.display());
+    return Ok(());
+}
+
+fn run_parallel(days: &[&'static Day], arguments: &Arguments, worker_count: usize) -> bool {
+    let mut jobs = Vec::new();
+    for day in days {
+        let (source, params) = day_input(day, arguments);
+        for part in [1, 2] {
+            if arguments.part.is_none() || arguments.part == Some(part) {
+                jobs.push(Job { day, part, source: source.clone(), params: params.clone() });
+            }
+        }
+    }
+
+    let now = Instant::now();
+    let results = run_jobs(jobs, worker_count);
+    let elapsed = now.elapsed();
+
+    match arguments.format {
+        Format::Text => {
+            print!("{}", summary_table(&results));
+            println!("\n{} parts on {} threads in {:.1} ms", results.len(), worker_count, elapsed.as_secs_f64() * 1000.0);
+        }
+        Format::Json => {
+            for result in &results {
+                match &result.outcome {
+                    Ok(report) => println!("{}", report.to_json()),
+                    Err(message) => eprintln!("Day {:02} part {}: {}", result.day, result.part, message),
+                }
+            }
+        }
+    }
+    return results.iter().all(|result| result.outcome.is_ok());
+}
+
+fn bench_days(days: &[&Day], arguments: &Arguments) -> Result<(), String> {
+    let mut results = Vec::new();
+    for day in days {
+        eprintln!("Benchmarking day {:02}", day.number);
+        let (source, params) = day_input(day, arguments);
+        let measurements = day.bench(&source, arguments.part, &arguments.bench_options, &params)
+            .map_err(|error| error.to_string())?;
+        results.push(DayResult { day: day.number, measurements });
+    }
+    benchmark::print_table(&results);
+
+    let output = match &arguments.output {
+        Some(output) => output.to_owned(),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("target").join("benchmarks.csv"),
+    };
+    benchmark::append_csv(&output, &arguments.bench_options, &results)
+        .map_err(|error| format!("Could not write {}: {}", output.display(), error))?;
+    println!("\nResults appended to {}", output.display());
+    return Ok(());
+}
+
 fn main() {
-    println!("Hello, Advent of Code 2022!");
+    let args = env::args().skip(1).collect::<Vec<String>>();
+    if args.iter().any(|arg| arg == "--help" || arg == "-h") {
+        println!("{}", USAGE);
+        return;
+    }
+
+    if args.first().map(|arg| arg.as_str()) == Some("scaffold") {
+        let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let created = parse_number("scaffold", args.get(1), 25)
+            .and_then(|day| scaffold(workspace_dir, &workspace_dir.join("template"), day));
+        match created {
+            Ok(day_dir) => {
+                println!("Created {}", day_dir.display());
+                println!("Add it to DAYS in src/lib.rs and to [dependencies] to run it through this runner");
+            }
+            Err(message) => {
+                eprintln!("{}", message);
+                exit(1);
+            }
+        }
+        return;
+    }
+
+    if let Some(command) = args.first().filter(|command| ["generate", "stress", "diff"].contains(&command.as_str())) {
+        let (day, options) = match parse_generator_arguments(command, &args[1..]) {
+            Ok(arguments) => arguments,
+            Err(message) => {
+                eprintln!("{}\n\n{}", message, USAGE);
+                exit(2);
+            }
+        };
+        let day = get_day(day).unwrap();
+        if command == "diff" && !day.has_reference() {
+            let days = DAYS.iter().filter(|day| day.has_reference()).map(|day| day.number.to_string()).collect::<Vec<String>>();
+            eprintln!("Day {} has no reference solution, only days {} have one", day.number, days.join(", "));
+            exit(2);
+        }
+        let succeeded = match command.as_str() {
+            "generate" => {
+                generate_input(day, &options);
+                true
+            }
+            "stress" => stress_day(day, &options),
+            _ => diff_day(day, &options),
+        };
+        if !succeeded {
+            exit(1);
+        }
+        return;
+    }
+
+    let arguments = match parse_arguments(&args) {
+        Ok(arguments) => arguments,
+        Err(message) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            exit(2);
+        }
+    };
+
+    let days = match arguments.day {
+        Some(day) => vec![get_day(day).unwrap()],
+        None => DAYS.iter().collect::<Vec<&'static Day>>(),
+    };
+
+    if arguments.list_params {
+        if let Err(message) = list_params(days[0], &arguments) {
+            eprintln!("{}", message);
+            exit(1);
+        }
+        return;
+    }
+
+    if let Some(jobs) = arguments.jobs {
+        if !run_parallel(&days, &arguments, jobs as usize) {
+            exit(1);
+        }
+        return;
+    }
+
+    if arguments.bench {
+        if let Err(message) = bench_days(&days, &arguments) {
+            eprintln!("{}", message);
+            exit(1);
+        }
+        return;
+    }
+
+    let mut failed_days = Vec::new();
+    let print_headers = arguments.all && arguments.format == Format::Text;
+    for day in days {
+        if print_headers {
+            println!("=== Day {:02} ===", day.number);
+        }
+        if let Err(message) = run_day(day, &arguments) {
+            eprintln!("{}", message);
+            failed_days.push(day.number);
+        }
+        if print_headers {
+            println!();
+        }
+    }
+
+    if !failed_days.is_empty() {
+        exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use aoc_common::bench::BenchOptions;
+    use aoc_common::params::ParamArgs;
+    use aoc_common::visualize::AnimationOptions;
+
+    use advent_of_code_2022::stress::StressOptions;
+
+    use crate::{parse_arguments, parse_generator_arguments, Arguments, Format};
+
+    fn to_args(line: &str) -> Vec<String> {
+        return line.split_whitespace().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    }
+
+    #[test]
+    fn test_single_day() {
+        let arguments = parse_arguments(&to_args("--day 16 --part 2 --input path/to/file")).unwrap();
+        assert_eq!(arguments, Arguments {
+            day: Some(16),
+            part: Some(2),
+            input: Some(PathBuf::from("path/to/file")),
+            params: ParamArgs::default(),
+            list_params: false,
+            stream: false,
+            visualize: false,
+            animation_options: AnimationOptions::default(),
+            export: None,
+            all: false,
+            format: Format::Text,
+            jobs: None,
+            bench: false,
+            bench_options: BenchOptions::default(),
+            output: None,
+        });
+    }
+
+    #[test]
+    fn test_format() {
+        assert_eq!(parse_arguments(&to_args("--all --format json")).unwrap().format, Format::Json);
+        assert!(parse_arguments(&to_args("--all --format yaml")).is_err());
+        assert_eq!(parse_arguments(&to_args("--all --jobs 4")).unwrap().jobs, Some(4));
+        assert!(parse_arguments(&to_args("--all --jobs 0")).is_err());
+    }
+
+    #[test]
+    fn test_stream() {
+        assert!(parse_arguments(&to_args("--day 6 --stream --input -")).unwrap().stream);
+        assert!(parse_arguments(&to_args("--day 5 --stream")).is_err());
+        assert!(parse_arguments(&to_args("--all --stream")).is_err());
+        assert!(parse_arguments(&to_args("--day 6 --stream --format json")).is_err());
+    }
+
+    #[test]
+    fn test_visualize() {
+        let arguments = parse_arguments(&to_args("--day 14 --visualize --fps 0 --viewport 40x10 --max-frames 50 --export frames.txt")).unwrap();
+        assert!(arguments.visualize);
+        assert_eq!(arguments.animation_options, AnimationOptions { fps: 0.0, viewport: (40, 10), max_frames: Some(50) });
+        assert_eq!(arguments.export, Some(PathBuf::from("frames.txt")));
+        assert!(parse_arguments(&to_args("--day 14 --visualize --viewport 40")).is_err());
+        assert!(parse_arguments(&to_args("--day 14 --fps 5")).is_err());
+        assert!(parse_arguments(&to_args("--day 1 --visualize")).is_err());
+        assert!(parse_arguments(&to_args("--all --visualize")).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let arguments = parse_arguments(&to_args("--bench --warmup 0 --iterations 3 --output out.csv")).unwrap();
+        assert!(arguments.bench && arguments.day.is_none());
+        assert_eq!(arguments.bench_options, BenchOptions { warmup: 0, iterations: 3 });
+        assert_eq!(arguments.output, Some(PathBuf::from("out.csv")));
+        assert!(parse_arguments(&to_args("--bench --iterations 0")).is_err());
+        assert!(parse_arguments(&to_args("--day 3 --output out.csv")).is_err());
+    }
+
+    #[test]
+    fn test_params() {
+        let arguments = parse_arguments(&to_args("--day 11 --param rounds_1=30 --param rounds_2=5 --config variant.toml")).unwrap();
+        assert_eq!(arguments.params, ParamArgs {
+            config_files: vec![PathBuf::from("variant.toml")],
+            overrides: vec![("rounds_1".to_string(), "30".to_string()), ("rounds_2".to_string(), "5".to_string())],
+        });
+        assert!(parse_arguments(&to_args("--day 11 --param rounds_1")).is_err());
+        assert!(parse_arguments(&to_args("--all --param rounds_1=30")).is_err());
+        assert!(parse_arguments(&to_args("--day 11 --params")).unwrap().list_params);
+    }
+
+    #[test]
+    fn test_generator_arguments() {
+        let (day, options) = parse_generator_arguments("generate", &to_args("7 --size 30 --seed 5")).unwrap();
+        assert_eq!((day, options.sizes, options.seed), (7, vec![30], 5));
+        let (_, options) = parse_generator_arguments("stress", &to_args("7 --sizes 10,20 --count 4")).unwrap();
+        assert_eq!(options, StressOptions { sizes: vec![10, 20], seed: 0, count: 4 });
+        assert!(parse_generator_arguments("generate", &to_args("7 --sizes 10,20")).is_err());
+        assert!(parse_generator_arguments("stress", &to_args("7 --sizes 10,x")).is_err());
+        assert!(parse_generator_arguments("stress", &to_args("22")).is_err());
+        let (_, options) = parse_generator_arguments("diff", &to_args("16 --seed 3")).unwrap();
+        assert_eq!(options, StressOptions { sizes: vec![2, 3, 5, 10], seed: 3, count: 20 });
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(parse_arguments(&to_args("--day 22")).is_err());
+        assert!(parse_arguments(&to_args("--day 1 --part 3")).is_err());
+        assert!(parse_arguments(&to_args("--all --input file.txt")).is_err());
+        assert!(parse_arguments(&to_args("--part 1")).is_err());
+    }
 }
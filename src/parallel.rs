@@ -0,0 +1,136 @@
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use aoc_common::error::panic_message;
+use aoc_common::input::InputSource;
+use aoc_common::params::ParamArgs;
+use aoc_common::report::PartReport;
+
+use crate::Day;
+
+/// One part of one day, solved on its own so that the slow parts can run next to each other.
+#[derive(Clone)]
+pub struct Job {
+    pub day: &'static Day,
+    pub part: u32,
+    pub source: InputSource,
+    pub params: ParamArgs,
+}
+
+/// The outcome of a job. A panic or an invalid input only fails the job it happened in.
+#[derive(Debug)]
+pub struct JobResult {
+    pub day: u32,
+    pub part: u32,
+    pub outcome: Result<PartReport, String>,
+}
+
+pub(crate) fn run_job(job: &Job) -> JobResult {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| job.day.report(&job.source, Some(job.part), &job.params)));
+    let outcome = match result {
+        Ok(Ok(mut reports)) => reports.pop().ok_or("no answer was computed".to_string()),
+        Ok(Err(error)) => Err(error.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    };
+    return JobResult { day: job.day.number, part: job.part, outcome };
+}
+
+/// Run the jobs on `worker_count` threads and return the results sorted by day and part.
+pub fn run_jobs(jobs: Vec<Job>, worker_count: usize) -> Vec<JobResult> {
+    let queue = Mutex::new(jobs.into_iter().collect::<VecDeque<Job>>());
+    let (sender, receiver) = mpsc::channel();
+
+    // Panics are reported in the results, so the default message would only garble the output
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    thread::scope(|scope| {
+        for _ in 0..worker_count.max(1) {
+            let sender = sender.clone();
+            let queue = &queue;
+            scope.spawn(move || {
+                loop {
+                    let job = queue.lock().unwrap().pop_front();
+                    match job {
+                        Some(job) => sender.send(run_job(&job)).unwrap(),
+                        None => break,
+                    }
+                }
+            });
+        }
+    });
+    panic::set_hook(default_hook);
+    drop(sender);
+
+    let mut results = receiver.into_iter().collect::<Vec<JobResult>>();
+    results.sort_by_key(|result| (result.day, result.part));
+    return results;
+}
+
+pub(crate) fn millis(duration: Duration) -> String {
+    return format!("{:.1}", duration.as_secs_f64() * 1000.0);
+}
+
+/// Format the results as a table. Answers spanning several lines continue below their row.
+pub fn summary_table(results: &[JobResult]) -> String {
+    // The widths of the columns before the answer
+    let answer_indent = " ".repeat(5 + 6 + 8 + 11 + 2 + 11 + 2);
+    let mut table = format!("{:<5}{:<6}{:<8}{:>11}  {:>11}  {}\n", "Day", "Part", "Status", "Parse (ms)", "Solve (ms)", "Answer");
+    for result in results {
+        let (status, parse_time, solve_time, answer) = match &result.outcome {
+            Ok(report) => ("ok", millis(report.parse_time), millis(report.solve_time), report.answer.to_string()),
+            Err(message) => ("FAILED", "-".to_string(), "-".to_string(), message.to_owned()),
+        };
+        let mut answer_lines = answer.trim_start_matches('\n').lines();
+        table.push_str(&format!("{:<5}{:<6}{:<8}{:>11}  {:>11}  {}\n",
+                                format!("{:02}", result.day), result.part, status, parse_time, solve_time,
+                                answer_lines.next().unwrap_or("")));
+        for line in answer_lines {
+            table.push_str(&format!("{}{}\n", answer_indent, line));
+        }
+    }
+    return table;
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::input::InputSource;
+    use aoc_common::params::ParamArgs;
+
+    use crate::parallel::{run_jobs, summary_table, Job};
+    use crate::get_day;
+
+    #[test]
+    fn test_run_jobs() {
+        let day05 = get_day(5).unwrap();
+        let test_input = InputSource::File(day05.crate_dir().join("input").join("test.txt"));
+        let jobs = vec![
+            Job { day: day05, part: 2, source: test_input.clone(), params: ParamArgs::default() },
+            Job { day: get_day(1).unwrap(), part: 1, source: InputSource::Text("1000\nx".to_string()), params: ParamArgs::default() },
+            Job { day: day05, part: 1, source: test_input, params: ParamArgs::default() },
+        ];
+        let results = run_jobs(jobs, 2);
+        let order = results.iter().map(|result| (result.day, result.part)).collect::<Vec<(u32, u32)>>();
+        assert_eq!(order, vec![(1, 1), (5, 1), (5, 2)]);
+        assert!(results[0].outcome.as_ref().unwrap_err().contains("line 2, column 1"));
+        assert_eq!(results[2].outcome.as_ref().unwrap().answer.to_string(), "MCD");
+
+        let table = summary_table(&results);
+        assert!(table.lines().nth(1).unwrap().starts_with("01   1     FAILED"));
+        assert!(table.lines().nth(3).unwrap().ends_with("  MCD"));
+    }
+
+    #[test]
+    fn test_panic_isolation() {
+        // Day 15 panics when a sensor covers the whole search area, so there is no place for the beacon
+        let source = InputSource::Text("Sensor at x=2, y=2: closest beacon is at x=2, y=12\n".to_string());
+        let mut params = ParamArgs::default();
+        params.add_override("search_max=4").unwrap();
+        let jobs = vec![Job { day: get_day(15).unwrap(), part: 2, source, params }];
+        let results = run_jobs(jobs, 1);
+        assert!(results[0].outcome.as_ref().unwrap_err().starts_with("panicked: "));
+    }
+}
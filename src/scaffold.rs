@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Add `member` to the `[workspace] members` list, keeping the day crates in order.
+pub fn add_member(manifest: &str, member: &str) -> Result<String, String> {
+    let list_start = manifest.find("members = [").ok_or("No [workspace] members list in Cargo.toml")?;
+    let list_end = list_start + manifest[list_start..].find(']').ok_or("The members list in Cargo.toml is not closed")?;
+
+    let mut members = manifest[list_start + "members = [".len()..list_end]
+        .split(',')
+        .map(|member| member.trim().trim_matches('"').to_string())
+        .filter(|member| !member.is_empty())
+        .collect::<Vec<String>>();
+    if members.iter().any(|existing| existing == member) {
+        return Err(format!("{} is already a workspace member", member));
+    }
+    // New days go after the earlier days, before other members such as the template
+    let later_day = members.iter().position(|existing| existing.starts_with("day") && existing.as_str() > member);
+    let last_day = members.iter().rposition(|existing| existing.starts_with("day"));
+    let position = later_day.or(last_day.map(|last_day| last_day + 1)).unwrap_or(members.len());
+    members.insert(position, member.to_string());
+
+    let list = members.iter().map(|member| format!("    \"{}\"", member)).collect::<Vec<String>>().join(",\n");
+    return Ok(format!("{}members = [\n{}\n{}", &manifest[..list_start], list, &manifest[list_end..]));
+}
+
+/// Replace the names of the template crate and its solution with the ones of the new day.
+pub fn fill_template(text: &str, day: u32) -> String {
+    return text
+        .replace("name = \"template\"", &format!("name = \"day{:02}\"", day))
+        .replace("use template::", &format!("use day{:02}::", day))
+        .replace("const DAY: u32 = 0;", &format!("const DAY: u32 = {};", day))
+        .replace("Template", &format!("Day{:02}", day));
+}
+
+fn copy_template(template_dir: &Path, day_dir: &Path, day: u32) -> Result<(), String> {
+    let to_error = |path: &Path, error: std::io::Error| format!("Could not copy {}: {}", path.display(), error);
+
+    fs::create_dir_all(day_dir).map_err(|error| to_error(day_dir, error))?;
+    let entries = fs::read_dir(template_dir).map_err(|error| to_error(template_dir, error))?;
+    for entry in entries {
+        let path = entry.map_err(|error| to_error(template_dir, error))?.path();
+        let target = day_dir.join(path.file_name().unwrap());
+        if path.file_name() == Some("input".as_ref()) || path.file_name() == Some("target".as_ref()) {
+            continue;
+        }
+        if path.is_dir() {
+            copy_template(&path, &target, day)?;
+        } else {
+            let text = fs::read_to_string(&path).map_err(|error| to_error(&path, error))?;
+            fs::write(&target, fill_template(&text, day)).map_err(|error| to_error(&target, error))?;
+        }
+    }
+    return Ok(());
+}
+
+/// Create `dayNN/` from the template with empty input files, and add it to the workspace.
+pub fn scaffold(workspace_dir: &Path, template_dir: &Path, day: u32) -> Result<PathBuf, String> {
+    let name = format!("day{:02}", day);
+    let day_dir = workspace_dir.join(&name);
+    if day_dir.exists() {
+        return Err(format!("{} already exists, refusing to overwrite it", day_dir.display()));
+    }
+
+    // Update the manifest in memory first, so that nothing is created if it cannot be changed
+    let manifest_path = workspace_dir.join("Cargo.toml");
+    let manifest = fs::read_to_string(&manifest_path)
+        .map_err(|error| format!("Could not read {}: {}", manifest_path.display(), error))?;
+    let manifest = add_member(&manifest, &name)?;
+
+    copy_template(template_dir, &day_dir, day)?;
+    let input_dir = day_dir.join("input");
+    for input_file in ["input.txt", "test.txt"] {
+        let path = input_dir.join(input_file);
+        fs::create_dir_all(&input_dir)
+            .and_then(|_| fs::write(&path, ""))
+            .map_err(|error| format!("Could not create {}: {}", path.display(), error))?;
+    }
+
+    fs::write(&manifest_path, manifest)
+        .map_err(|error| format!("Could not write {}: {}", manifest_path.display(), error))?;
+    return Ok(day_dir);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::Path;
+
+    use crate::scaffold::{add_member, fill_template, scaffold};
+
+    const MANIFEST: &str = "[workspace]\nmembers = [\n    \"aoc-common\",\n    \"day01\",\n    \"day03\",\n    \"template\"\n]\n\n[lints]\n";
+
+    #[test]
+    fn test_add_member() {
+        let manifest = add_member(MANIFEST, "day02").unwrap();
+        assert_eq!(manifest, MANIFEST.replace("\"day01\",\n", "\"day01\",\n    \"day02\",\n"));
+        let manifest = add_member(MANIFEST, "day22").unwrap();
+        assert!(manifest.contains("\"day03\",\n    \"day22\",\n    \"template\"\n]"));
+        assert!(add_member(MANIFEST, "day03").is_err());
+        assert_eq!(fill_template("use template::Template;", 22), "use day22::Day22;");
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let workspace_dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(&workspace_dir).unwrap();
+        fs::write(workspace_dir.join("Cargo.toml"), MANIFEST).unwrap();
+        let template_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("template");
+
+        let day_dir = scaffold(&workspace_dir, &template_dir, 22).unwrap();
+        let cargo_toml = fs::read_to_string(day_dir.join("Cargo.toml")).unwrap();
+        assert!(cargo_toml.contains("name = \"day22\""));
+        assert!(fs::read_to_string(day_dir.join("src").join("main.rs")).unwrap().contains("cli::run::<Day22>"));
+        assert!(fs::read_to_string(day_dir.join("src").join("lib.rs")).unwrap().contains("const DAY: u32 = 22;"));
+        assert_eq!(fs::read_to_string(day_dir.join("input").join("test.txt")).unwrap(), "");
+        assert!(fs::read_to_string(workspace_dir.join("Cargo.toml")).unwrap().contains("\"day22\""));
+        assert!(scaffold(&workspace_dir, &template_dir, 22).is_err());
+
+        fs::remove_dir_all(&workspace_dir).unwrap();
+    }
+}
@@ -0,0 +1,501 @@
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{mpsc, Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_common::error::{panic_message, Error};
+use aoc_common::input::InputSource;
+use aoc_common::json::JsonObject;
+use aoc_common::params::ParamArgs;
+
+use crate::{get_day, Day, DAYS};
+
+/// The longest request line or header line accepted, in bytes
+pub const MAX_LINE_LENGTH: usize = 8 * 1024;
+/// The most bytes accepted for the request line and all headers together
+pub const MAX_HEAD_SIZE: usize = 64 * 1024;
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct ServerOptions {
+    /// The time to read a request and solve it in, after which the client gets a timeout response
+    pub timeout: Duration,
+    /// The largest puzzle input accepted, in bytes
+    pub max_body_size: usize,
+    /// The number of parts that can be solved at the same time
+    pub workers: usize,
+    /// The number of connections that are answered at the same time, each on its own thread
+    pub max_connections: usize,
+}
+
+impl Default for ServerOptions {
+    fn default() -> ServerOptions {
+        return ServerOptions { timeout: Duration::from_secs(10), max_body_size: 1024 * 1024, workers: 4, max_connections: 64 };
+    }
+}
+
+// A count of things in use, such as busy workers or open connections, that cannot go above `max`
+#[derive(Clone)]
+struct Limit {
+    in_use: Arc<Mutex<usize>>,
+    max: usize,
+}
+
+// One of the things counted by a `Limit`, which is given back when the guard is dropped
+struct LimitGuard {
+    in_use: Arc<Mutex<usize>>,
+}
+
+impl Limit {
+    fn new(max: usize) -> Limit {
+        return Limit { in_use: Arc::new(Mutex::new(0)), max };
+    }
+
+    // Take one, or return `None` if all are in use
+    fn acquire(&self) -> Option<LimitGuard> {
+        let mut in_use = self.in_use.lock().unwrap();
+        if *in_use >= self.max {
+            return None;
+        }
+        *in_use += 1;
+        return Some(LimitGuard { in_use: Arc::clone(&self.in_use) });
+    }
+}
+
+impl Drop for LimitGuard {
+    fn drop(&mut self) {
+        *self.in_use.lock().unwrap() -= 1;
+    }
+}
+
+// A task is given the guard of its worker, to release the worker as soon as the work is done
+type Task = Box<dyn FnOnce(LimitGuard) + Send>;
+
+/// A fixed number of threads to solve parts on. Rust cannot stop a thread, so a solve that times out keeps
+/// its worker busy until it is done. Slow requests therefore never run more solves than there are workers.
+pub struct WorkerPool {
+    tasks: mpsc::Sender<(Task, LimitGuard)>,
+    busy: Limit,
+}
+
+impl WorkerPool {
+    pub fn new(worker_count: usize) -> WorkerPool {
+        let (sender, receiver) = mpsc::channel::<(Task, LimitGuard)>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        for _ in 0..worker_count {
+            let receiver = Arc::clone(&receiver);
+            // The workers stop when the pool is dropped and the channel closes
+            thread::spawn(move || loop {
+                let next = receiver.lock().unwrap().recv();
+                let Ok((task, busy)) = next else {
+                    return;
+                };
+                let _ = panic::catch_unwind(AssertUnwindSafe(|| task(busy)));
+            });
+        }
+        return WorkerPool { tasks: sender, busy: Limit::new(worker_count) };
+    }
+
+    /// Run `work` on an idle worker and pass its result to `done`, or return false without running it when
+    /// every worker is busy. The worker counts as idle again before `done` is called, so whoever waits for
+    /// the result can start the next task right away.
+    pub fn try_execute<T: 'static>(&self, work: impl FnOnce() -> T + Send + 'static, done: impl FnOnce(T) + Send + 'static) -> bool {
+        let Some(busy) = self.busy.acquire() else {
+            return false;
+        };
+        let task = move |busy: LimitGuard| {
+            let result = work();
+            drop(busy);
+            done(result);
+        };
+        return self.tasks.send((Box::new(task), busy)).is_ok();
+    }
+}
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Request {
+    pub method: String,
+    /// The path without the query, e.g. `/day/11/part/2`
+    pub path: String,
+    /// The `name=value` pairs of the query, e.g. `rounds_2=500` for `?rounds_2=500`
+    pub query: Vec<(String, String)>,
+    pub body: String,
+}
+
+/// A response with a JSON body.
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    /// An error response: `{"error":"<kind>","message":"<message>"}`.
+    pub fn error(status: u16, kind: &str, message: &str) -> Response {
+        let mut object = JsonObject::new();
+        object.string("error", kind).string("message", message);
+        return Response { status, body: object.to_string() };
+    }
+
+    // The response to an error of the runner, with the position of invalid input
+    fn from_error(error: &Error) -> Response {
+        return match error {
+            Error::Parse(_, parse_error) => {
+                let mut object = JsonObject::new();
+                object.string("error", "invalid_input")
+                    .string("message", &parse_error.to_string())
+                    .number("line", parse_error.line)
+                    .number("column", parse_error.column)
+                    .string("expected", &parse_error.expected)
+                    .string("found", &parse_error.found);
+                Response { status: 422, body: object.to_string() }
+            }
+            Error::Param(param_error) => Response::error(400, "invalid_parameter", &param_error.to_string()),
+            Error::Input(input_error) => Response::error(500, "internal", &input_error.to_string()),
+        };
+    }
+
+    fn reason(&self) -> &'static str {
+        return match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            431 => "Request Header Fields Too Large",
+            503 => "Service Unavailable",
+            _ => "Internal Server Error",
+        };
+    }
+
+    /// Write the response, ending the body with a newline like the JSON output of the runner.
+    pub fn write_to(&self, writer: &mut impl Write) -> io::Result<()> {
+        let body = format!("{}\n", self.body);
+        write!(writer, "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+               self.status, self.reason(), body.len(), body)?;
+        return writer.flush();
+    }
+}
+
+fn decode_query_value(value: &str) -> String {
+    let mut bytes = Vec::new();
+    let mut chars = value.bytes();
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [chars.next().unwrap_or(b'0'), chars.next().unwrap_or(b'0')];
+                bytes.push(u8::from_str_radix(&String::from_utf8_lossy(&hex), 16).unwrap_or(b'?'));
+            }
+            byte => bytes.push(byte),
+        }
+    }
+    return String::from_utf8_lossy(&bytes).to_string();
+}
+
+fn is_timeout(error: &io::Error) -> bool {
+    return matches!(error.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut);
+}
+
+// Read a line of at most `limit` bytes, without reading any further. Returns `None` if the line is longer.
+fn read_limited_line(reader: &mut impl BufRead, limit: usize) -> io::Result<Option<String>> {
+    let mut line = String::new();
+    reader.by_ref().take(limit as u64 + 1).read_line(&mut line)?;
+    return Ok(if line.len() > limit { None } else { Some(line) });
+}
+
+/// Read a request, rejecting a body larger than `max_body_size` before reading it. The request line and
+/// every header line can be at most `MAX_LINE_LENGTH` bytes long, and all of them together `MAX_HEAD_SIZE`.
+pub fn read_request(reader: &mut impl BufRead, max_body_size: usize) -> Result<Request, Response> {
+    let to_response = |error: io::Error| if is_timeout(&error) {
+        Response::error(408, "timeout", "The request was not received in time")
+    } else {
+        Response::error(400, "bad_request", &format!("Could not read the request: {}", error))
+    };
+
+    let request_line = read_limited_line(reader, MAX_LINE_LENGTH).map_err(to_response)?
+        .ok_or_else(|| Response::error(400, "bad_request", &format!("The request line is longer than {} bytes", MAX_LINE_LENGTH)))?;
+    let mut head_size = request_line.len();
+    let parts = request_line.split_whitespace().collect::<Vec<&str>>();
+    if parts.len() != 3 || !parts[2].starts_with("HTTP/") {
+        return Err(Response::error(400, "bad_request", &format!("Invalid request line '{}'", request_line.trim_end())));
+    }
+    let (path, query) = parts[1].split_once('?').unwrap_or((parts[1], ""));
+    let query = query.split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            (decode_query_value(name), decode_query_value(value))
+        })
+        .collect();
+
+    let mut content_length = None;
+    loop {
+        let header = read_limited_line(reader, MAX_LINE_LENGTH).map_err(to_response)?
+            .ok_or_else(|| Response::error(431, "header_too_large", &format!("A header line is longer than {} bytes", MAX_LINE_LENGTH)))?;
+        head_size += header.len();
+        if head_size > MAX_HEAD_SIZE {
+            return Err(Response::error(431, "header_too_large", &format!("The headers are longer than {} bytes", MAX_HEAD_SIZE)));
+        }
+        if header.trim_end().is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            if name.trim().eq_ignore_ascii_case("content-length") {
+                let length = value.trim().parse::<usize>()
+                    .map_err(|_| Response::error(400, "bad_request", &format!("Invalid Content-Length '{}'", value.trim())))?;
+                content_length = Some(length);
+            }
+        }
+    }
+
+    let mut body = Vec::new();
+    match content_length {
+        Some(length) if length > max_body_size => {
+            let message = format!("The input has {} bytes, but at most {} are accepted", length, max_body_size);
+            return Err(Response::error(413, "payload_too_large", &message));
+        }
+        Some(length) => {
+            body.resize(length, 0);
+            reader.read_exact(&mut body).map_err(to_response)?;
+        }
+        None if parts[0] == "POST" => return Err(Response::error(411, "length_required", "A POST request needs a Content-Length")),
+        None => (),
+    }
+    let body = String::from_utf8(body).map_err(|_| Response::error(400, "bad_request", "The input is not valid UTF-8"))?;
+    return Ok(Request { method: parts[0].to_string(), path: path.to_string(), query, body });
+}
+
+// Solve the part on a worker, so that the answer can be given up on when it takes too long
+fn solve(day: &'static Day, part: u32, input: String, params: ParamArgs, pool: &WorkerPool, timeout: Duration) -> Response {
+    let (sender, receiver) = mpsc::channel();
+    let solve_part = move || panic::catch_unwind(AssertUnwindSafe(|| day.report(&InputSource::Text(input), Some(part), &params)));
+    let started = pool.try_execute(solve_part, move |result| {
+        let _ = sender.send(result);
+    });
+    if !started {
+        return Response::error(503, "busy", "Every worker is solving another input, try again later");
+    }
+
+    return match receiver.recv_timeout(timeout) {
+        Ok(Ok(Ok(reports))) => match reports.first() {
+            Some(report) => Response { status: 200, body: report.to_json() },
+            None => Response::error(500, "internal", "No answer was computed"),
+        },
+        Ok(Ok(Err(error))) => Response::from_error(&error),
+        Ok(Err(payload)) => Response::error(500, "panicked", &panic_message(payload.as_ref())),
+        Err(_) => Response::error(503, "timeout", &format!("Day {} part {} was not solved in time", day.number, part)),
+    };
+}
+
+/// Answer a request. `POST /day/{n}/part/{p}` solves a part of a day for the input in the body, with the
+/// parameters from the day's config.toml and the query, e.g. `?rounds_2=500`, on a worker of the pool.
+pub fn handle(request: Request, pool: &WorkerPool, timeout: Duration) -> Response {
+    let segments = request.path.trim_matches('/').split('/').collect::<Vec<&str>>();
+    let (day, part) = match segments.as_slice() {
+        ["day", day, "part", part] => (day.parse::<u32>().ok().and_then(get_day), part.parse::<u32>().ok()),
+        _ => return Response::error(404, "not_found", &format!("There is nothing at {}, use POST /day/{{n}}/part/{{p}}", request.path)),
+    };
+    let day = match day {
+        Some(day) => day,
+        None => return Response::error(404, "not_found", &format!("There is no day {}, only days 1 to {}", segments[1], DAYS.len())),
+    };
+    let part = match part {
+        Some(part) if part == 1 || part == 2 => part,
+        _ => return Response::error(404, "not_found", &format!("There is no part {}, only parts 1 and 2", segments[3])),
+    };
+    if request.method != "POST" {
+        return Response::error(405, "method_not_allowed", "Post the puzzle input to solve it");
+    }
+
+    let mut params = day.default_params();
+    for (name, value) in &request.query {
+        if let Err(error) = params.add_override(&format!("{}={}", name, value)) {
+            return Response::error(400, "invalid_parameter", &error.to_string());
+        }
+    }
+    return solve(day, part, request.body, params, pool, timeout);
+}
+
+fn handle_connection(stream: TcpStream, pool: &WorkerPool, options: &ServerOptions) -> io::Result<()> {
+    let started = Instant::now();
+    stream.set_read_timeout(Some(options.timeout))?;
+    stream.set_write_timeout(Some(options.timeout))?;
+    let mut reader = BufReader::new(stream.try_clone()?);
+
+    let (description, response) = match read_request(&mut reader, options.max_body_size) {
+        Ok(request) => {
+            let description = format!("{} {}", request.method, request.path);
+            (description, handle(request, pool, options.timeout.saturating_sub(started.elapsed())))
+        }
+        Err(response) => ("<invalid request>".to_string(), response),
+    };
+    eprintln!("{} {} {:.1} ms", description, response.status, started.elapsed().as_secs_f64() * 1000.0);
+    return response.write_to(&mut &stream);
+}
+
+// Answer a connection over the limit right away, without reading the request or starting a thread
+fn reject_connection(stream: TcpStream) -> io::Result<()> {
+    stream.set_write_timeout(Some(Duration::from_secs(1)))?;
+    let response = Response::error(503, "too_many_connections", "The server is answering too many connections, try again later");
+    eprintln!("<rejected connection> {}", response.status);
+    return response.write_to(&mut &stream);
+}
+
+/// Answer the connections to the listener, each on its own thread, with at most `options.max_connections`
+/// at the same time. Parts are solved on `options.workers` threads.
+pub fn serve(listener: TcpListener, options: ServerOptions) {
+    let pool = Arc::new(WorkerPool::new(options.workers));
+    let connections = Limit::new(options.max_connections);
+    for stream in listener.incoming() {
+        let stream = match stream {
+            Ok(stream) => stream,
+            Err(error) => {
+                // E.g. too many open files, which may pass when connections are closed
+                eprintln!("Could not accept a connection: {}", error);
+                thread::sleep(Duration::from_millis(10));
+                continue;
+            }
+        };
+        let Some(connection) = connections.acquire() else {
+            if let Err(error) = reject_connection(stream) {
+                eprintln!("Could not reject a connection: {}", error);
+            }
+            continue;
+        };
+        let options = options.clone();
+        let pool = Arc::clone(&pool);
+        thread::spawn(move || {
+            if let Err(error) = handle_connection(stream, &pool, &options) {
+                eprintln!("Could not answer a request: {}", error);
+            }
+            drop(connection);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Read, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use crate::server::{handle, read_request, serve, Request, ServerOptions, WorkerPool, MAX_LINE_LENGTH};
+
+    fn post(path: &str, body: &str) -> Request {
+        let request = format!("POST {} HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}", path, body.len(), body);
+        return read_request(&mut request.as_bytes(), 10_000).unwrap();
+    }
+
+    #[test]
+    fn test_handle() {
+        let timeout = Duration::from_secs(60);
+        let pool = WorkerPool::new(2);
+        let response = handle(post("/day/1/part/2", "1\n\n2\n\n3\n\n4\n"), &pool, timeout);
+        assert_eq!(response.status, 200);
+        assert!(response.body.starts_with("{\"day\":1,\"part\":2,\"answer\":\"9\",\"answer_type\":\"number\",\"parse_us\":"));
+
+        let response = handle(post("/day/4/part/1", "2-4,6-8\n2-4;6-8\n"), &pool, timeout);
+        assert_eq!((response.status, response.body.as_str()), (422, "{\"error\":\"invalid_input\",\
+            \"message\":\"line 2, column 2: expected ',', found '-4;6-8'\",\"line\":2,\"column\":2,\"expected\":\"','\",\"found\":\"-4;6-8\"}"));
+
+        let input = include_str!("../day11/input/test.txt");
+        let response = handle(post("/day/11/part/1?rounds_1=1", input), &pool, timeout);
+        assert!(response.body.starts_with("{\"day\":11,\"part\":1,\"answer\":\"20\","));
+        assert_eq!(handle(post("/day/11/part/1?rounds=1", input), &pool, timeout).status, 400);
+        let response = handle(post("/day/11/part/2?rounds_2=100000", input), &pool, Duration::from_millis(1));
+        assert_eq!((response.status, response.body.as_str()), (503, "{\"error\":\"timeout\",\"message\":\"Day 11 part 2 was not solved in time\"}"));
+        assert_eq!(handle(post("/day/22/part/1", ""), &pool, timeout).status, 404);
+        assert_eq!(handle(post("/day/1/part/3", ""), &pool, timeout).status, 404);
+        assert_eq!(handle(post("/days", ""), &pool, timeout).status, 404);
+        assert_eq!(read_request(&mut "POST /day/1/part/1 HTTP/1.1\r\nContent-Length: 1001\r\n\r\n".as_bytes(), 1000).unwrap_err().status, 413);
+        assert_eq!(read_request(&mut "POST /day/1/part/1 HTTP/1.1\r\n\r\n".as_bytes(), 1000).unwrap_err().status, 411);
+
+        let long_path = format!("POST /day/1/part/1?{} HTTP/1.1\r\n\r\n", "a".repeat(MAX_LINE_LENGTH));
+        assert_eq!(read_request(&mut long_path.as_bytes(), 1000).unwrap_err().status, 400);
+        let long_header = format!("POST /day/1/part/1 HTTP/1.1\r\nCookie: {}\r\n\r\n", "a".repeat(MAX_LINE_LENGTH));
+        assert_eq!(read_request(&mut long_header.as_bytes(), 1000).unwrap_err().status, 431);
+        let many_headers = format!("POST /day/1/part/1 HTTP/1.1\r\n{}\r\n", "Cookie: a\r\n".repeat(10_000));
+        let response = read_request(&mut many_headers.as_bytes(), 1000).unwrap_err();
+        assert_eq!((response.status, response.body.as_str()), (431, "{\"error\":\"header_too_large\",\"message\":\"The headers are longer than 65536 bytes\"}"));
+    }
+
+    #[test]
+    fn test_serve() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let options = ServerOptions { timeout: Duration::from_millis(500), max_body_size: 100, workers: 2, max_connections: 4 };
+        thread::spawn(move || serve(listener, options));
+
+        let send = |request: &str| {
+            let mut stream = TcpStream::connect(address).unwrap();
+            stream.write_all(request.as_bytes()).unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            return response;
+        };
+        let response = send("POST /day/6/part/1 HTTP/1.1\r\nContent-Length: 31\r\n\r\nmjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.contains("\r\n\r\n{\"day\":6,\"part\":1,\"answer\":\"7\","));
+        assert!(send("GET /day/6/part/1 HTTP/1.1\r\n\r\n").starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+        assert!(send("POST /day/6/part/1 HTTP/1.1\r\nContent-Length: 101\r\n\r\n").starts_with("HTTP/1.1 413 Payload Too Large\r\n"));
+
+        // The body never arrives
+        assert!(send("POST /day/6/part/1 HTTP/1.1\r\nContent-Length: 50\r\n\r\nmjqj").starts_with("HTTP/1.1 408 Request Timeout\r\n"));
+    }
+
+    #[test]
+    fn test_connection_limit() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let options = ServerOptions { max_connections: 1, ..ServerOptions::default() };
+        thread::spawn(move || serve(listener, options));
+
+        // Another connection is rejected while the only one allowed is open
+        let mut open = TcpStream::connect(address).unwrap();
+        let mut rejected = String::new();
+        TcpStream::connect(address).unwrap().read_to_string(&mut rejected).unwrap();
+        assert!(rejected.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+        assert!(rejected.ends_with("\r\n\r\n{\"error\":\"too_many_connections\",\"message\":\"The server is answering too many connections, try again later\"}\n"));
+        let mut response = String::new();
+        open.write_all(b"GET /day/6/part/1 HTTP/1.1\r\n\r\n").unwrap();
+        open.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 405 Method Not Allowed\r\n"));
+    }
+
+    #[test]
+    fn test_worker_pool() {
+        let pool = WorkerPool::new(1);
+        let (release, blocked) = mpsc::channel::<()>();
+        assert!(pool.try_execute(move || blocked.recv(), |_| ()));
+        assert!(!pool.try_execute(|| (), |_| ()));
+        let response = handle(post("/day/1/part/1", "1\n"), &pool, Duration::from_secs(60));
+        assert_eq!((response.status, response.body.as_str()), (503, "{\"error\":\"busy\",\"message\":\"Every worker is solving another input, try again later\"}"));
+
+        // The worker takes the next task once the first is done
+        drop(release);
+        let started = Instant::now();
+        while !pool.try_execute(|| (), |_| ()) {
+            assert!(started.elapsed() < Duration::from_secs(10), "The worker never became idle");
+            thread::sleep(Duration::from_millis(1));
+        }
+
+        // A worker is idle again before its answer is received, so requests one after another never get a 503
+        let pool = WorkerPool::new(1);
+        for _ in 0..100 {
+            assert_eq!(handle(post("/day/1/part/1", "1\n"), &pool, Duration::from_secs(60)).status, 200);
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use aoc_common::input::InputSource;
+use aoc_common::params::ParamArgs;
+use aoc_common::report::PartReport;
+
+use crate::parallel::{millis, run_job, Job};
+use crate::Day;
+
+#[derive(Debug)]
+#[derive(Clone)]
+#[derive(PartialEq)]
+pub struct StressOptions {
+    /// The sizes of the generated inputs, from small to large
+    pub sizes: Vec<usize>,
+    /// The seed of the first input of every size
+    pub seed: u64,
+    /// The number of inputs per size
+    pub count: u32,
+}
+
+impl Default for StressOptions {
+    fn default() -> StressOptions {
+        return StressOptions { sizes: vec![10, 100, 1000], seed: 0, count: 3 };
+    }
+}
+
+/// The outcome of one part on one generated input.
+#[derive(Debug)]
+pub struct StressResult {
+    pub size: usize,
+    pub seed: u64,
+    pub part: u32,
+    pub outcome: Result<PartReport, String>,
+}
+
+/// Solve both parts of generated inputs of every size, one after the other so that the timings can be
+/// compared. Panics and invalid inputs fail only the input they happened on.
+pub fn stress(day: &'static Day, options: &StressOptions) -> Vec<StressResult> {
+    let mut results = Vec::new();
+    for size in &options.sizes {
+        eprintln!("Stressing day {:02} with size {}", day.number, size);
+        for seed in options.seed..options.seed + options.count as u64 {
+            let input = day.generate(seed, *size);
+            let params = ParamArgs { config_files: Vec::new(), overrides: input.params };
+            let source = InputSource::Text(input.text);
+            for part in [1, 2] {
+                let result = run_job(&Job { day, part, source: source.clone(), params: params.clone() });
+                results.push(StressResult { size: *size, seed, part, outcome: result.outcome });
+            }
+        }
+    }
+    return results;
+}
+
+/// Format the results as a table, with the first line of every answer or error.
+pub fn stress_table(results: &[StressResult]) -> String {
+    let mut table = format!("{:>8}{:>8}{:>6}  {:<8}{:>11}  {:>11}  {}\n", "Size", "Seed", "Part", "Status", "Parse (ms)", "Solve (ms)", "Answer");
+    for result in results {
+        let (status, parse_time, solve_time, answer) = match &result.outcome {
+            Ok(report) => ("ok", millis(report.parse_time), millis(report.solve_time), report.answer.to_string()),
+            Err(message) => ("FAILED", "-".to_string(), "-".to_string(), message.to_owned()),
+        };
+        let answer = answer.trim_start_matches('\n').lines().next().unwrap_or("").to_string();
+        table.push_str(&format!("{:>8}{:>8}{:>6}  {:<8}{:>11}  {:>11}  {}\n",
+                                result.size, result.seed, result.part, status, parse_time, solve_time, answer));
+    }
+    return table;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::get_day;
+    use crate::stress::{stress, stress_table, StressOptions};
+
+    #[test]
+    fn test_stress() {
+        let options = StressOptions { sizes: vec![5, 20], seed: 7, count: 2 };
+        let results = stress(get_day(4).unwrap(), &options);
+        let runs = results.iter().map(|result| (result.size, result.seed, result.part)).collect::<Vec<(usize, u64, u32)>>();
+        assert_eq!(runs, vec![(5, 7, 1), (5, 7, 2), (5, 8, 1), (5, 8, 2), (20, 7, 1), (20, 7, 2), (20, 8, 1), (20, 8, 2)]);
+        assert!(results.iter().all(|result| result.outcome.is_ok()));
+
+        let table = stress_table(&results);
+        assert_eq!(table.lines().count(), 9);
+        assert!(table.lines().nth(1).unwrap().starts_with("       5       7     1  ok"));
+    }
+}
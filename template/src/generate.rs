@@ -0,0 +1,11 @@
+use aoc_common::generate::{Generator, Rng};
+
+use crate::Template;
+
+impl Generator for Template {
+    /// `size` random words. Replace them with inputs in the format of the puzzle, for which both parts have an answer.
+    fn generate(rng: &mut Rng, size: usize) -> (String, ()) {
+        let words = (0..size).map(|_| rng.word(1..=10) + "\n").collect::<String>();
+        return (words, ());
+    }
+}
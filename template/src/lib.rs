@@ -0,0 +1,62 @@
+mod generate;
+
+use aoc_common::input;
+use aoc_common::parse::ParseError;
+use aoc_common::solution::Solution;
+
+pub fn parse(lines: &Vec<String>) -> Result<Vec<String>, ParseError> {
+    // Report invalid input with Line::error, e.g. `line.parse_word::<u32>(0, "a number")?`
+    return Ok(lines.clone());
+}
+
+pub fn solve_1(data: &Vec<String>) -> i32 {
+    println!("{:?}", data);
+    return 0;
+}
+
+pub fn solve_2(data: &Vec<String>) -> i32 {
+    println!("{:?}", data);
+    return 0;
+}
+
+pub struct Template;
+
+impl Solution for Template {
+    const DAY: u32 = 0;
+    type Input = Vec<String>;
+    type Config = ();
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return parse(&input::lines(input));
+    }
+
+    fn part1(data: &Vec<String>, _: &()) -> i32 {
+        return solve_1(data);
+    }
+
+    fn part2(data: &Vec<String>, _: &()) -> i32 {
+        return solve_2(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::day_input;
+    use aoc_common::solution::load;
+
+    use crate::{Template, solve_1, solve_2};
+
+    #[test]
+    fn test_1() {
+        let data = load::<Template>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_1(&data), 0);
+    }
+
+    #[test]
+    fn test_2() {
+        let data = load::<Template>(&day_input!("test.txt")).unwrap();
+        assert_eq!(solve_2(&data), 0);
+    }
+}
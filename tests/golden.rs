@@ -0,0 +1,142 @@
+//! Checks the answers to every puzzle input in the `input` directories against the days' `answers.toml`.
+//! Every input file is a test case named e.g. `day06/examples/1`, so adding a case is just adding a file.
+//! Run a subset with e.g. `cargo test --test golden -- day05 day06/test`.
+
+use std::env;
+use std::panic;
+use std::process::exit;
+use std::thread;
+
+use advent_of_code_2022::{Day, DAYS};
+use aoc_common::answers::ExpectedAnswers;
+use aoc_common::error::panic_message;
+use aoc_common::input::NamedInput;
+
+#[derive(Debug)]
+enum Outcome {
+    Passed,
+    Failed(String),
+    Skipped(String),
+}
+
+/// One input file of a day with the answers expected for it.
+struct Case {
+    day: &'static Day,
+    name: String,
+    input: Option<NamedInput>,
+    expected: ExpectedAnswers,
+}
+
+// Pair every input of a day with its section in answers.toml, and every section with its input
+fn find_cases(day: &'static Day) -> Result<Vec<Case>, String> {
+    let mut expected_answers = day.expected_answers().map_err(|error| error.to_string())?;
+    let mut cases = Vec::new();
+    for input in day.inputs().map_err(|error| error.to_string())? {
+        let expected = expected_answers.remove(&input.name).unwrap_or_default();
+        cases.push(Case { day, name: input.name.to_owned(), input: Some(input), expected });
+    }
+    for (name, expected) in expected_answers {
+        cases.push(Case { day, name, input: None, expected });
+    }
+    cases.sort_by(|first, second| first.name.cmp(&second.name));
+    return Ok(cases);
+}
+
+fn check_case(case: &Case) -> Vec<(u32, Outcome)> {
+    let skip_all = |reason: &str| (1..=2).map(|part| (part, Outcome::Skipped(reason.to_string()))).collect();
+    let fail_all = |reason: &str| (1..=2).map(|part| (part, Outcome::Failed(reason.to_string()))).collect();
+
+    let input = match &case.input {
+        Some(input) => input,
+        None => return fail_all(&format!("there is no input/{}.txt for the answers", case.name)),
+    };
+    let expected = &case.expected;
+    if expected.part1.is_none() && expected.part2.is_none() {
+        return skip_all("no answers in answers.toml");
+    }
+
+    let part = match (&expected.part1, &expected.part2) {
+        (Some(_), None) => Some(1),
+        (None, Some(_)) => Some(2),
+        _ => None,
+    };
+    let answers = match case.day.answers(&input.source(), part, &case.day.input_params(input)) {
+        Ok(answers) => answers,
+        Err(error) => return fail_all(&error.to_string()),
+    };
+
+    return (1..=2).map(|part| {
+        let outcome = match (expected.get(part), answers.iter().find(|(answer_part, _)| *answer_part == part)) {
+            (None, _) => Outcome::Skipped("no answer in answers.toml".to_string()),
+            (Some(expected), Some((_, answer))) if answer == expected => Outcome::Passed,
+            (Some(expected), Some((_, answer))) => Outcome::Failed(format!("expected {}, got {}", expected, answer)),
+            (Some(_), None) => Outcome::Failed("no answer was computed".to_string()),
+        };
+        (part, outcome)
+    }).collect();
+}
+
+fn main() {
+    let filters = env::args().skip(1).filter(|arg| !arg.starts_with('-')).collect::<Vec<String>>();
+    let mut cases = Vec::new();
+    let mut results = Vec::new();
+    for day in DAYS.iter() {
+        match find_cases(day) {
+            Ok(day_cases) => cases.extend(day_cases),
+            Err(message) => {
+                let case = Case { day, name: "*".to_string(), input: None, expected: ExpectedAnswers::default() };
+                results.push((case, (1..=2).map(|part| (part, Outcome::Failed(message.clone()))).collect()));
+            }
+        }
+    }
+    let cases = cases.into_iter()
+        .filter(|case| {
+            let name = format!("day{:02}/{}", case.day.number, case.name);
+            filters.is_empty() || filters.iter().any(|filter| name.contains(filter.as_str()))
+        })
+        .collect::<Vec<Case>>();
+
+    // The slow days dominate in debug builds, so every case is checked on its own thread
+    panic::set_hook(Box::new(|_| {}));
+    let outcomes = thread::scope(|scope| {
+        let handles = cases.iter().map(|case| scope.spawn(|| check_case(case))).collect::<Vec<_>>();
+        return handles.into_iter().map(|handle| match handle.join() {
+            Ok(outcomes) => outcomes,
+            Err(payload) => {
+                let message = format!("panicked: {}", panic_message(payload.as_ref()));
+                (1..=2).map(|part| (part, Outcome::Failed(message.clone()))).collect()
+            }
+        }).collect::<Vec<Vec<(u32, Outcome)>>>();
+    });
+    let _ = panic::take_hook();
+    results.extend(cases.into_iter().zip(outcomes));
+    results.sort_by_key(|(case, _)| (case.day.number, case.name.to_owned()));
+
+    println!("\nrunning {} golden tests", results.len() * 2);
+    let (mut passed, mut failed, mut skipped) = (0, 0, 0);
+    for (case, outcomes) in &results {
+        for (part, outcome) in outcomes {
+            let status = match outcome {
+                Outcome::Passed => {
+                    passed += 1;
+                    "ok".to_string()
+                }
+                Outcome::Failed(reason) => {
+                    failed += 1;
+                    format!("FAILED ({})", reason)
+                }
+                Outcome::Skipped(reason) => {
+                    skipped += 1;
+                    format!("skipped ({})", reason)
+                }
+            };
+            println!("test day{:02}/{} part {} ... {}", case.day.number, case.name, part, status);
+        }
+    }
+
+    let result = if failed == 0 { "ok" } else { "FAILED" };
+    println!("\ntest result: {}. {} passed; {} failed; {} skipped\n", result, passed, failed, skipped);
+    if failed > 0 {
+        exit(1);
+    }
+}